@@ -0,0 +1,47 @@
+//! Account abstraction capabilities.
+
+/// The account abstraction mechanisms available on a chain.
+///
+/// Returned by [`NamedChain::aa_support`](crate::NamedChain::aa_support).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct AaSupport {
+    /// Whether public [ERC-4337](https://eips.ethereum.org/EIPS/eip-4337) bundlers are
+    /// generally available for the canonical `EntryPoint` contracts.
+    pub erc4337_bundlers: bool,
+    /// Whether every account is a smart contract account at the protocol level (e.g. ZKsync
+    /// Era), so no `EntryPoint` is needed.
+    pub native: bool,
+    /// Whether [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702) set-code transactions are
+    /// activated, letting EOAs delegate to contract code.
+    pub eip7702: bool,
+}
+
+impl AaSupport {
+    /// Returns `true` if any account abstraction mechanism is available.
+    #[inline]
+    pub const fn any(&self) -> bool {
+        self.erc4337_bundlers || self.native || self.eip7702
+    }
+}
+
+/// A hosted [ERC-4337](https://eips.ethereum.org/EIPS/eip-4337) bundler and paymaster provider.
+///
+/// See [`NamedChain::bundler_rpc_slug`](crate::NamedChain::bundler_rpc_slug).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BundlerProvider {
+    /// [Pimlico](https://www.pimlico.io), e.g. `https://api.pimlico.io/v2/{slug}/rpc`.
+    Pimlico,
+    /// [Alchemy](https://www.alchemy.com), e.g. `https://{slug}.g.alchemy.com/v2/{API_KEY}`.
+    Alchemy,
+    /// [Stackup](https://www.stackup.sh).
+    ///
+    /// Stackup endpoints are identified by their API key alone, so no slugs are provided.
+    Stackup,
+}
+
+impl BundlerProvider {
+    /// All the providers.
+    pub const ALL: [Self; 3] = [Self::Pimlico, Self::Alchemy, Self::Stackup];
+}
@@ -0,0 +1,54 @@
+//! Address display conventions.
+
+use alloc::string::String;
+use alloy_primitives::Address;
+
+/// How addresses are checksummed when displayed on a chain.
+///
+/// Returned by [`NamedChain::address_format`](crate::NamedChain::address_format).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AddressFormat {
+    /// [EIP-55](https://eips.ethereum.org/EIPS/eip-55) mixed-case checksums.
+    #[default]
+    Eip55,
+    /// [EIP-1191](https://eips.ethereum.org/EIPS/eip-1191) checksums, which also hash the chain
+    /// ID so that addresses are not mistakenly used across chains (e.g. Rootstock).
+    Eip1191 {
+        /// The chain ID included in the checksum.
+        chain_id: u64,
+    },
+}
+
+impl AddressFormat {
+    /// Returns the chain ID included in the checksum, if any.
+    #[inline]
+    pub const fn checksum_chain_id(self) -> Option<u64> {
+        match self {
+            Self::Eip55 => None,
+            Self::Eip1191 { chain_id } => Some(chain_id),
+        }
+    }
+
+    /// Returns the checksummed, `0x`-prefixed representation of the address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// let address = address!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+    /// assert_eq!(
+    ///     NamedChain::Mainnet.address_format().format(&address),
+    ///     "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    /// );
+    /// assert_eq!(
+    ///     NamedChain::Rsk.address_format().format(&address),
+    ///     "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD"
+    /// );
+    /// ```
+    pub fn format(self, address: &Address) -> String {
+        address.to_checksum(self.checksum_chain_id())
+    }
+}
@@ -0,0 +1,108 @@
+//! Chain capabilities, from the static tables or observed at runtime.
+
+use crate::AaSupport;
+
+/// The capabilities of a chain that affect how transactions and contracts targeting it are built.
+///
+/// Each field is `None` if unknown. Returned by
+/// [`NamedChain::capabilities`](crate::NamedChain::capabilities) from the curated tables, and
+/// convertible from a runtime probe report with the `probe` feature. Use [`merge`](Self::merge)
+/// to combine the two.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ChainCapabilities {
+    /// Whether the `PUSH0` opcode is available.
+    pub push0: Option<bool>,
+    /// Whether the chain has an [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559) fee market.
+    pub eip1559: Option<bool>,
+    /// Whether [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844) blob-carrying transactions
+    /// are accepted.
+    pub blobs: Option<bool>,
+    /// The available account abstraction mechanisms.
+    pub aa: Option<AaSupport>,
+    /// Whether the `safe` and `finalized` block tags are supported.
+    pub block_tags: Option<bool>,
+}
+
+impl ChainCapabilities {
+    /// Creates capabilities that are all unknown.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { push0: None, eip1559: None, blobs: None, aa: None, block_tags: None }
+    }
+
+    /// Sets whether the `PUSH0` opcode is available.
+    #[inline]
+    pub const fn with_push0(mut self, push0: bool) -> Self {
+        self.push0 = Some(push0);
+        self
+    }
+
+    /// Sets whether the chain has an EIP-1559 fee market.
+    #[inline]
+    pub const fn with_eip1559(mut self, eip1559: bool) -> Self {
+        self.eip1559 = Some(eip1559);
+        self
+    }
+
+    /// Sets whether blob-carrying transactions are accepted.
+    #[inline]
+    pub const fn with_blobs(mut self, blobs: bool) -> Self {
+        self.blobs = Some(blobs);
+        self
+    }
+
+    /// Sets the available account abstraction mechanisms.
+    #[inline]
+    pub const fn with_aa(mut self, aa: AaSupport) -> Self {
+        self.aa = Some(aa);
+        self
+    }
+
+    /// Sets whether the `safe` and `finalized` block tags are supported.
+    #[inline]
+    pub const fn with_block_tags(mut self, block_tags: bool) -> Self {
+        self.block_tags = Some(block_tags);
+        self
+    }
+
+    /// Merges `observed` capabilities, e.g. from a runtime probe, into these.
+    ///
+    /// Observed values take precedence; fields unknown in `observed` are kept from `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{ChainCapabilities, NamedChain};
+    ///
+    /// let curated = NamedChain::Mainnet.capabilities();
+    /// let observed = ChainCapabilities::new().with_push0(false);
+    /// let merged = curated.merge(observed);
+    /// assert_eq!(merged.push0, Some(false));
+    /// assert_eq!(merged.eip1559, curated.eip1559);
+    /// ```
+    pub const fn merge(self, observed: Self) -> Self {
+        Self {
+            push0: or(observed.push0, self.push0),
+            eip1559: or(observed.eip1559, self.eip1559),
+            blobs: or(observed.blobs, self.blobs),
+            aa: or(observed.aa, self.aa),
+            block_tags: or(observed.block_tags, self.block_tags),
+        }
+    }
+}
+
+#[cfg(feature = "probe")]
+impl From<crate::probe::RpcReport> for ChainCapabilities {
+    fn from(report: crate::probe::RpcReport) -> Self {
+        Self::new().with_push0(report.push0).with_eip1559(report.eip1559)
+    }
+}
+
+/// [`Option::or`], in a `const` context.
+const fn or<T: Copy>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match a {
+        Some(_) => a,
+        None => b,
+    }
+}
@@ -1,5 +1,10 @@
-use crate::NamedChain;
-use core::{cmp::Ordering, fmt, str::FromStr, time::Duration};
+use crate::{
+    AaSupport, AddressFormat, ChainCapabilities, ChainDescriptor, ComplianceFlag, ExplorerInfo,
+    ExplorerKind, ForkProfile, GasRefundRule, GasSemantics, MempoolProfile, MetadataError,
+    MevEndpoint, NamedChain, ProofSystem, RollupType, StrictParseError, SuperchainLevel,
+};
+use alloy_primitives::{Address, U256};
+use core::{cmp::Ordering, fmt, ops::RangeInclusive, str::FromStr, time::Duration};
 
 #[allow(unused_imports)]
 use alloc::string::String;
@@ -12,12 +17,67 @@ use proptest::{
 #[cfg(feature = "arbitrary")]
 use strum::{EnumCount, IntoEnumIterator};
 
+#[cfg(feature = "std")]
+pub(crate) mod unknown_chain_observer {
+    use std::{
+        collections::BTreeMap,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, RwLock,
+        },
+    };
+
+    type Observer = Arc<dyn Fn(u64) + Send + Sync>;
+
+    static OBSERVER: RwLock<Option<Observer>> = RwLock::new(None);
+    // Avoids taking the lock when creating a chain while no observer is set.
+    static IS_SET: AtomicBool = AtomicBool::new(false);
+    // The number of live registries that each custom chain ID is registered in.
+    static REGISTERED: RwLock<BTreeMap<u64, usize>> = RwLock::new(BTreeMap::new());
+
+    pub(super) fn set(observer: Option<Observer>) {
+        let mut guard = OBSERVER.write().unwrap_or_else(|err| err.into_inner());
+        IS_SET.store(observer.is_some(), Ordering::Release);
+        *guard = observer;
+    }
+
+    pub(crate) fn notify(id: u64) {
+        if !IS_SET.load(Ordering::Acquire) {
+            return;
+        }
+        if REGISTERED.read().unwrap_or_else(|err| err.into_inner()).contains_key(&id) {
+            return;
+        }
+        // Call the observer outside of the lock, so that it can parse chains or replace itself.
+        let observer = OBSERVER.read().unwrap_or_else(|err| err.into_inner()).clone();
+        if let Some(observer) = observer {
+            observer(id);
+        }
+    }
+
+    /// Stops reporting the ID until a matching [`unregister`] call.
+    pub(crate) fn register(id: u64) {
+        *REGISTERED.write().unwrap_or_else(|err| err.into_inner()).entry(id).or_default() += 1;
+    }
+
+    pub(crate) fn unregister(id: u64) {
+        let mut registered = REGISTERED.write().unwrap_or_else(|err| err.into_inner());
+        if let Some(count) = registered.get_mut(&id) {
+            *count -= 1;
+            if *count == 0 {
+                registered.remove(&id);
+            }
+        }
+    }
+}
+
 /// Either a known [`NamedChain`] or a EIP-155 chain ID.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Chain(ChainKind);
 
 /// The kind of chain. Returned by [`Chain::kind`]. Prefer using [`Chain`] instead.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ChainKind {
     /// Known chain.
     Named(NamedChain),
@@ -60,6 +120,28 @@ impl From<Chain> for u64 {
     }
 }
 
+impl From<Chain> for U256 {
+    #[inline]
+    fn from(chain: Chain) -> Self {
+        chain.to_u256()
+    }
+}
+
+macro_rules! impl_try_into_numeric {
+    ($($t:ty)+) => {$(
+        impl TryFrom<Chain> for $t {
+            type Error = core::num::TryFromIntError;
+
+            #[inline]
+            fn try_from(chain: Chain) -> Result<Self, Self::Error> {
+                chain.id().try_into()
+            }
+        }
+    )+};
+}
+
+impl_try_into_numeric!(u8 i8 u16 i16 u32 i32 i64 usize isize);
+
 impl TryFrom<Chain> for NamedChain {
     type Error = <NamedChain as TryFrom<u64>>::Error;
 
@@ -77,11 +159,9 @@ impl FromStr for Chain {
 
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(chain) = NamedChain::from_str(s) {
-            Ok(Self::from_named(chain))
-        } else {
-            s.parse::<u64>().map(Self::from_id)
-        }
+        let chain = Self::parse_unobserved(s)?;
+        chain.notify_if_unknown();
+        Ok(chain)
     }
 }
 
@@ -95,6 +175,16 @@ impl fmt::Display for Chain {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Chain {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self.kind() {
+            ChainKind::Named(chain) => chain.format(f),
+            ChainKind::Id(id) => defmt::write!(f, "{=u64}", id),
+        }
+    }
+}
+
 impl PartialEq<u64> for Chain {
     #[inline]
     fn eq(&self, other: &u64) -> bool {
@@ -102,6 +192,22 @@ impl PartialEq<u64> for Chain {
     }
 }
 
+/// Matches anything that parses to the chain, i.e. its name, aliases or decimal ID.
+impl PartialEq<str> for Chain {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        Self::parse_unobserved(other).is_ok_and(|chain| chain == *self)
+    }
+}
+
+/// Matches anything that parses to the chain, i.e. its name, aliases or decimal ID.
+impl PartialEq<&str> for Chain {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
 impl PartialOrd<u64> for Chain {
     #[inline]
     fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
@@ -152,6 +258,86 @@ impl<'de> serde::Deserialize<'de> for Chain {
     }
 }
 
+/// (De)serializes a [`Chain`] as a string, i.e. its name or its decimal ID if unnamed, for use
+/// with `#[serde(with = "alloy_chains::serde_string")]`.
+///
+/// Deserialization accepts the same inputs as [`Chain`]'s `Deserialize` implementation.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::Chain;
+///
+/// #[derive(serde::Serialize)]
+/// struct Config {
+///     #[serde(with = "alloy_chains::serde_string")]
+///     chain: Chain,
+/// }
+///
+/// let config = Config { chain: Chain::from_id(8453) };
+/// assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"chain":"base"}"#);
+/// let config = Config { chain: Chain::from_id(1234567) };
+/// assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"chain":"1234567"}"#);
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_string {
+    use super::Chain;
+
+    /// Serializes `chain` as a string.
+    pub fn serialize<S: serde::Serializer>(
+        chain: &Chain,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(chain)
+    }
+
+    /// Deserializes a chain from its name or ID.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Chain, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+}
+
+/// (De)serializes a [`Chain`] as its numeric ID, for use with
+/// `#[serde(with = "alloy_chains::serde_u64")]`.
+///
+/// Deserialization accepts the same inputs as [`Chain`]'s `Deserialize` implementation.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::Chain;
+///
+/// #[derive(serde::Serialize)]
+/// struct Config {
+///     #[serde(with = "alloy_chains::serde_u64")]
+///     chain: Chain,
+/// }
+///
+/// let config = Config { chain: Chain::mainnet() };
+/// assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"chain":1}"#);
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_u64 {
+    use super::Chain;
+
+    /// Serializes `chain` as its ID.
+    pub fn serialize<S: serde::Serializer>(
+        chain: &Chain,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(chain.id())
+    }
+
+    /// Deserializes a chain from its name or ID.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Chain, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+}
+
 #[cfg(feature = "rlp")]
 impl alloy_rlp::Encodable for Chain {
     #[inline]
@@ -173,6 +359,60 @@ impl alloy_rlp::Decodable for Chain {
     }
 }
 
+#[cfg(feature = "rlp")]
+impl TryFrom<&[u8]> for Chain {
+    type Error = DecodeChainError;
+
+    /// Decodes an RLP-encoded chain ID with [`Chain::decode_canonical`], rejecting trailing
+    /// bytes.
+    fn try_from(mut buf: &[u8]) -> Result<Self, Self::Error> {
+        let chain = Self::decode_canonical(&mut buf)?;
+        if !buf.is_empty() {
+            return Err(DecodeChainError::Rlp(alloy_rlp::Error::UnexpectedLength));
+        }
+        Ok(chain)
+    }
+}
+
+/// The error returned by [`Chain::decode_canonical`].
+#[cfg(feature = "rlp")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeChainError {
+    /// The input is not a canonical RLP-encoded `u64`.
+    Rlp(alloy_rlp::Error),
+    /// The chain ID is `0`, which is not a valid EIP-155 chain ID.
+    Zero,
+    /// The chain ID is above [`Chain::MAX_EIP155_ID`].
+    OutOfRange(u64),
+}
+
+#[cfg(feature = "rlp")]
+impl fmt::Display for DecodeChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rlp(err) => write!(f, "invalid chain ID encoding: {err}"),
+            Self::Zero => f.write_str("chain ID 0 is not a valid EIP-155 chain ID"),
+            Self::OutOfRange(id) => write!(
+                f,
+                "chain ID {id} exceeds the maximum EIP-155 chain ID {}",
+                Chain::MAX_EIP155_ID
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "rlp")]
+impl core::error::Error for DecodeChainError {}
+
+#[cfg(feature = "rlp")]
+impl From<alloy_rlp::Error> for DecodeChainError {
+    #[inline]
+    fn from(err: alloy_rlp::Error) -> Self {
+        Self::Rlp(err)
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<'a> arbitrary::Arbitrary<'a> for Chain {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
@@ -182,7 +422,7 @@ impl<'a> arbitrary::Arbitrary<'a> for Chain {
             return Ok(Self::from_named(NamedChain::iter().nth(chain).expect("in range")));
         }
 
-        Ok(Self::from_id(u64::arbitrary(u)?))
+        Ok(Self::from_id_unobserved(u64::arbitrary(u)?))
     }
 }
 
@@ -198,7 +438,7 @@ impl proptest::arbitrary::Arbitrary for Chain {
         use proptest::prelude::*;
         prop_oneof![
             any::<Selector>().prop_map(move |sel| Self::from_named(sel.select(NamedChain::iter()))),
-            any::<u64>().prop_map(Self::from_id),
+            any::<u64>().prop_map(Self::from_id_unobserved),
         ]
     }
 }
@@ -227,8 +467,20 @@ impl Chain {
     }
 
     /// Creates a new [`Chain`] by wrapping a [`NamedChain`].
+    ///
+    /// If the ID is not a [`NamedChain`], the
+    /// [unknown chain observer](Self::set_unknown_chain_observer) is notified.
     #[inline]
     pub fn from_id(id: u64) -> Self {
+        let chain = Self::from_id_unobserved(id);
+        chain.notify_if_unknown();
+        chain
+    }
+
+    /// Creates a new [`Chain`] like [`from_id`](Self::from_id), without notifying the unknown chain
+    /// observer. Used for the crate's own conversions, which are not user requests.
+    #[inline]
+    pub(crate) fn from_id_unobserved(id: u64) -> Self {
         if let Ok(named) = NamedChain::try_from(id) {
             Self::from_named(named)
         } else {
@@ -236,6 +488,67 @@ impl Chain {
         }
     }
 
+    /// Parses a chain name, alias or decimal ID, without notifying the unknown chain observer.
+    fn parse_unobserved(s: &str) -> Result<Self, core::num::ParseIntError> {
+        if let Ok(chain) = NamedChain::from_str(s) {
+            Ok(Self::from_named(chain))
+        } else {
+            s.parse::<u64>().map(Self::from_id_unobserved)
+        }
+    }
+
+    /// Notifies the [unknown chain observer](Self::set_unknown_chain_observer) if the chain is not
+    /// a [`NamedChain`].
+    #[cfg(feature = "std")]
+    fn notify_if_unknown(self) {
+        if let ChainKind::Id(id) = self.0 {
+            unknown_chain_observer::notify(id);
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    const fn notify_if_unknown(self) {}
+
+    /// Sets a callback invoked with the ID whenever [`from_id`](Self::from_id), and thus parsing
+    /// or deserializing a chain, encounters an ID that is neither a [`NamedChain`] nor registered
+    /// in a live [`ChainRegistry`](crate::spec::ChainRegistry), replacing the previous one.
+    ///
+    /// Applications can use this to record which chains their users request, e.g. to report the
+    /// ones worth adding to this crate. [`ChainRegistry::resolve`](crate::spec::Chains::resolve)
+    /// reports the chain IDs it cannot resolve. Comparing a chain to a string and the crate's own
+    /// conversions, e.g. iterating over a [`ChainSet`](crate::ChainSet), do not report anything.
+    ///
+    /// The observer is called without holding any lock, so it may itself create chains or replace
+    /// the observer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::Chain;
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    ///
+    /// static LAST_UNKNOWN: AtomicU64 = AtomicU64::new(0);
+    /// Chain::set_unknown_chain_observer(|id| LAST_UNKNOWN.store(id, Ordering::Relaxed));
+    ///
+    /// Chain::from_id(1);
+    /// assert_eq!(LAST_UNKNOWN.load(Ordering::Relaxed), 0);
+    /// let _: Chain = "1234567".parse().unwrap();
+    /// assert_eq!(LAST_UNKNOWN.load(Ordering::Relaxed), 1234567);
+    ///
+    /// Chain::clear_unknown_chain_observer();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn set_unknown_chain_observer(observer: impl Fn(u64) + Send + Sync + 'static) {
+        unknown_chain_observer::set(Some(std::sync::Arc::new(observer)));
+    }
+
+    /// Removes the callback set with [`set_unknown_chain_observer`](Self::set_unknown_chain_observer).
+    #[cfg(feature = "std")]
+    pub fn clear_unknown_chain_observer() {
+        unknown_chain_observer::set(None);
+    }
+
     /// Creates a new [`Chain`] from the given ID, without checking if an associated [`NamedChain`]
     /// exists.
     ///
@@ -264,6 +577,12 @@ impl Chain {
         Self::from_named(NamedChain::Holesky)
     }
 
+    /// Returns the hoodi chain.
+    #[inline]
+    pub const fn hoodi() -> Self {
+        Self::from_named(NamedChain::Hoodi)
+    }
+
     /// Returns the sepolia chain.
     #[inline]
     pub const fn sepolia() -> Self {
@@ -599,6 +918,190 @@ impl Chain {
             ChainKind::Id(id) => id,
         }
     }
+
+    /// Returns the ID of the chain as `T`, or `None` if it does not fit.
+    ///
+    /// Prefer this over `as` casts when passing chain IDs to APIs or schemas using narrower integer
+    /// types, which would silently truncate large IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::Chain;
+    ///
+    /// assert_eq!(Chain::mainnet().checked_id::<u16>(), Some(1));
+    /// assert_eq!(Chain::from_id(u64::MAX).checked_id::<i64>(), None);
+    /// ```
+    #[inline]
+    pub fn checked_id<T: TryFrom<u64>>(self) -> Option<T> {
+        T::try_from(self.id()).ok()
+    }
+
+    /// Returns `true` if the ID of the chain can be represented as `T` without truncation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{Chain, NamedChain};
+    ///
+    /// assert!(Chain::mainnet().fits_in::<u8>());
+    /// assert!(!Chain::from_named(NamedChain::Sepolia).fits_in::<u16>());
+    /// assert!(Chain::from_named(NamedChain::Sepolia).fits_in::<u32>());
+    /// ```
+    #[inline]
+    pub fn fits_in<T: TryFrom<u64>>(self) -> bool {
+        self.checked_id::<T>().is_some()
+    }
+
+    /// The largest chain ID for which an EIP-155 `v` value (`id * 2 + 36`) fits in a `u64`, as
+    /// proposed in [EIP-2294](https://eips.ethereum.org/EIPS/eip-2294).
+    pub const MAX_EIP155_ID: u64 = u64::MAX / 2 - 36;
+
+    /// Decodes an RLP-encoded chain ID, checking that it is a valid EIP-155 chain ID.
+    ///
+    /// Unlike the [`Decodable`](alloy_rlp::Decodable) implementation, which accepts any `u64`,
+    /// this rejects `0` and IDs above [`MAX_EIP155_ID`](Self::MAX_EIP155_ID).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{Chain, DecodeChainError};
+    ///
+    /// let buf = alloy_rlp::encode(Chain::mainnet());
+    /// assert_eq!(Chain::decode_canonical(&mut &buf[..]), Ok(Chain::mainnet()));
+    ///
+    /// let buf = alloy_rlp::encode(u64::MAX);
+    /// assert_eq!(Chain::decode_canonical(&mut &buf[..]), Err(DecodeChainError::OutOfRange(u64::MAX)));
+    /// ```
+    #[cfg(feature = "rlp")]
+    pub fn decode_canonical(buf: &mut &[u8]) -> Result<Self, DecodeChainError> {
+        let id = <u64 as alloy_rlp::Decodable>::decode(buf)?;
+        match id {
+            0 => Err(DecodeChainError::Zero),
+            id if id > Self::MAX_EIP155_ID => Err(DecodeChainError::OutOfRange(id)),
+            id => Ok(Self::from_id(id)),
+        }
+    }
+
+    /// Returns the ID of the chain as a [`U256`], as carried in some RPC types.
+    #[inline]
+    pub const fn to_u256(self) -> U256 {
+        U256::from_limbs([self.id(), 0, 0, 0])
+    }
+
+    /// Creates a new [`Chain`] from an ID carried as a [`U256`], returning `None` if it does not
+    /// fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::Chain;
+    /// use alloy_primitives::U256;
+    ///
+    /// assert_eq!(Chain::from_u256(U256::from(1)), Some(Chain::mainnet()));
+    /// assert_eq!(Chain::from_u256(U256::MAX), None);
+    /// assert_eq!(Chain::mainnet().to_u256(), U256::from(1));
+    /// ```
+    #[inline]
+    pub fn from_u256(id: U256) -> Option<Self> {
+        match u64::try_from(id) {
+            Ok(id) => Some(Self::from_id(id)),
+            Err(_) => None,
+        }
+    }
+
+    /// Parses the `0x`-prefixed hexadecimal chain ID returned by the `eth_chainId` RPC method.
+    ///
+    /// Returns `None` if the prefix is missing, there are no digits, or the ID does not fit in a
+    /// `u64`. Leading zeros are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::Chain;
+    ///
+    /// assert_eq!(Chain::from_rpc_chain_id_hex("0x1"), Some(Chain::mainnet()));
+    /// assert_eq!(Chain::from_rpc_chain_id_hex("0x2105"), Some(Chain::base_mainnet()));
+    /// assert_eq!(Chain::from_rpc_chain_id_hex("1"), None);
+    /// ```
+    pub fn from_rpc_chain_id_hex(hex: &str) -> Option<Self> {
+        parse_hex_id(hex).map(Self::from_id)
+    }
+
+    /// Parses a chain command-line argument the way [Foundry](https://getfoundry.sh)'s `--chain`
+    /// does: a chain name or alias, a decimal chain ID, or a `0x`-prefixed hexadecimal chain ID.
+    ///
+    /// If `strict` is set, [deprecated](NamedChain::is_deprecated) networks are rejected, whether
+    /// given by name or by ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{Chain, NamedChain, StrictParseError};
+    ///
+    /// assert_eq!(Chain::parse_foundry_chain_arg("base", true), Ok(Chain::base_mainnet()));
+    /// assert_eq!(Chain::parse_foundry_chain_arg("0x2105", true), Ok(Chain::base_mainnet()));
+    /// assert_eq!(Chain::parse_foundry_chain_arg("1234567", true), Ok(Chain::from_id(1234567)));
+    /// assert_eq!(Chain::parse_foundry_chain_arg("goerli", false), Ok(Chain::goerli()));
+    /// assert_eq!(
+    ///     Chain::parse_foundry_chain_arg("5", true),
+    ///     Err(StrictParseError::Deprecated(NamedChain::Goerli))
+    /// );
+    /// assert_eq!(Chain::parse_foundry_chain_arg("unknown", false), Err(StrictParseError::Unknown));
+    /// ```
+    pub fn parse_foundry_chain_arg(arg: &str, strict: bool) -> Result<Self, StrictParseError> {
+        let arg = arg.trim();
+        let chain = if let Ok(named) = arg.parse::<NamedChain>() {
+            Self::from_named(named)
+        } else if let Ok(id) = arg.parse::<u64>() {
+            Self::from_id(id)
+        } else {
+            Self::from_rpc_chain_id_hex(arg).ok_or(StrictParseError::Unknown)?
+        };
+        match chain.named() {
+            Some(named) if strict && named.is_deprecated() => {
+                Err(StrictParseError::Deprecated(named))
+            }
+            _ => Ok(chain),
+        }
+    }
+
+    /// Returns the chain ID as a `0x`-prefixed hexadecimal string, as returned by the
+    /// `eth_chainId` RPC method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::Chain;
+    ///
+    /// assert_eq!(Chain::mainnet().to_hex_id(), "0x1");
+    /// assert_eq!(Chain::base_mainnet().to_hex_id(), "0x2105");
+    /// ```
+    pub fn to_hex_id(self) -> String {
+        format!("{:#x}", self.id())
+    }
+
+    /// Returns `true` if both chains have the same ID, regardless of whether they are represented
+    /// as a [`NamedChain`] or a bare ID.
+    ///
+    /// Unlike `==`, this also matches chains created with
+    /// [`from_id_unchecked`](Self::from_id_unchecked). See
+    /// [`ChainRegistry::is_same_network`](crate::spec::ChainRegistry::is_same_network) to also
+    /// match runtime-registered chains mirroring one another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::Chain;
+    ///
+    /// assert_ne!(Chain::mainnet(), Chain::from_id_unchecked(1));
+    /// assert!(Chain::mainnet().is_same_network(&Chain::from_id_unchecked(1)));
+    /// assert!(!Chain::mainnet().is_same_network(&Chain::sepolia()));
+    /// ```
+    #[inline]
+    pub const fn is_same_network(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
 }
 
 /// Methods delegated to `NamedChain`. Note that [`ChainKind::Id`] won't be converted because it was
@@ -614,6 +1117,68 @@ impl Chain {
         }
     }
 
+    /// Returns the chain's average blocktime, or why it is missing.
+    ///
+    /// See [`NamedChain::try_average_blocktime_hint`] for more info.
+    pub const fn try_average_blocktime_hint(self) -> Result<Duration, MetadataError> {
+        match self.kind() {
+            ChainKind::Named(named) => named.try_average_blocktime_hint(),
+            ChainKind::Id(_) => Err(MetadataError::Unknown),
+        }
+    }
+
+    /// Returns a hint for the chain's time to finality, if applicable.
+    ///
+    /// See [`NamedChain::time_to_finality_hint`] for more info.
+    pub const fn time_to_finality_hint(self) -> Option<Duration> {
+        match self.kind() {
+            ChainKind::Named(named) => named.time_to_finality_hint(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the account abstraction mechanisms available on the chain.
+    ///
+    /// See [`NamedChain::aa_support`] for more info.
+    pub const fn aa_support(self) -> AaSupport {
+        match self.kind() {
+            ChainKind::Named(named) => named.aa_support(),
+            ChainKind::Id(_) => {
+                AaSupport { erc4337_bundlers: false, native: false, eip7702: false }
+            }
+        }
+    }
+
+    /// Returns the chain's capabilities, as per the curated tables.
+    ///
+    /// See [`NamedChain::capabilities`] for more info.
+    pub const fn capabilities(self) -> ChainCapabilities {
+        match self.kind() {
+            ChainKind::Named(named) => named.capabilities(),
+            ChainKind::Id(_) => ChainCapabilities::new(),
+        }
+    }
+
+    /// Returns the structural properties of the chain relevant to compliance policies.
+    ///
+    /// See [`NamedChain::compliance_flags`] for more info.
+    pub const fn compliance_flags(self) -> &'static [ComplianceFlag] {
+        match self.kind() {
+            ChainKind::Named(named) => named.compliance_flags(),
+            ChainKind::Id(_) => &[],
+        }
+    }
+
+    /// Returns the challenge period of this optimistic rollup, if applicable.
+    ///
+    /// See [`NamedChain::challenge_period`] for more info.
+    pub const fn challenge_period(self) -> Option<Duration> {
+        match self.kind() {
+            ChainKind::Named(named) => named.challenge_period(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
     /// Returns whether the chain implements EIP-1559 (with the type 2 EIP-2718 transaction type).
     ///
     /// See [`NamedChain::is_legacy`] for more info.
@@ -652,6 +1217,290 @@ impl Chain {
         }
     }
 
+    /// Returns the chain's blockchain explorer and its API URLs, or why they are missing.
+    ///
+    /// See [`NamedChain::try_etherscan_urls`] for more info.
+    pub const fn try_etherscan_urls(self) -> Result<(&'static str, &'static str), MetadataError> {
+        match self.kind() {
+            ChainKind::Named(named) => named.try_etherscan_urls(),
+            ChainKind::Id(_) => Err(MetadataError::Unknown),
+        }
+    }
+
+    /// Returns the family of the chain's primary block explorer.
+    ///
+    /// See [`NamedChain::explorer_family`] for more info.
+    pub const fn explorer_family(self) -> Option<ExplorerKind> {
+        match self.kind() {
+            ChainKind::Named(named) => named.explorer_family(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns all known block explorer instances for the chain, ordered by priority.
+    ///
+    /// See [`NamedChain::explorers`] for more info.
+    pub const fn explorers(self) -> &'static [ExplorerInfo] {
+        match self.kind() {
+            ChainKind::Named(named) => named.explorers(),
+            ChainKind::Id(_) => &[],
+        }
+    }
+
+    /// Returns the known private transaction and MEV relay endpoints for the chain.
+    ///
+    /// See [`NamedChain::mev_endpoints`] for more info.
+    pub const fn mev_endpoints(self) -> &'static [MevEndpoint] {
+        match self.kind() {
+            ChainKind::Named(named) => named.mev_endpoints(),
+            ChainKind::Id(_) => &[],
+        }
+    }
+
+    /// Returns whether the chain has a proof-of-stake consensus layer (beacon chain).
+    ///
+    /// See [`NamedChain::has_beacon_chain`] for more info.
+    pub const fn has_beacon_chain(self) -> bool {
+        match self.kind() {
+            ChainKind::Named(named) => named.has_beacon_chain(),
+            ChainKind::Id(_) => false,
+        }
+    }
+
+    /// Returns a well-known public beacon API endpoint for the chain's consensus layer.
+    ///
+    /// See [`NamedChain::beacon_api_url`] for more info.
+    pub const fn beacon_api_url(self) -> Option<&'static str> {
+        match self.kind() {
+            ChainKind::Named(named) => named.beacon_api_url(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the facts a local fork needs to emulate the chain, if it is a known chain.
+    ///
+    /// See [`NamedChain::fork_profile`] for more info.
+    pub const fn fork_profile(self) -> Option<ForkProfile> {
+        match self.kind() {
+            ChainKind::Named(named) => Some(named.fork_profile()),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns a hint for the maximum size in bytes of a non-blob transaction accepted by the
+    /// chain's mempool or sequencer.
+    ///
+    /// See [`NamedChain::max_tx_size_hint`] for more info.
+    pub const fn max_tx_size_hint(self) -> Option<u64> {
+        match self.kind() {
+            ChainKind::Named(named) => named.max_tx_size_hint(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns a hint for the maximum size in bytes of a transaction's calldata.
+    ///
+    /// See [`NamedChain::max_calldata_hint`] for more info.
+    pub const fn max_calldata_hint(self) -> Option<u64> {
+        match self.kind() {
+            ChainKind::Named(named) => named.max_calldata_hint(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns `true` if the chain implements the secp256r1 signature verification precompile.
+    ///
+    /// See [`NamedChain::supports_p256_precompile`] for more info.
+    pub const fn supports_p256_precompile(self) -> bool {
+        match self.kind() {
+            ChainKind::Named(named) => named.supports_p256_precompile(),
+            ChainKind::Id(_) => false,
+        }
+    }
+
+    /// Returns the solc `evm_version` to compile contracts deployed to this chain with.
+    ///
+    /// See [`NamedChain::recommended_evm_version`] for more info.
+    pub const fn recommended_evm_version(self) -> &'static str {
+        match self.kind() {
+            ChainKind::Named(named) => named.recommended_evm_version(),
+            ChainKind::Id(_) => "paris",
+        }
+    }
+
+    /// Returns a hint for the number of API requests per second allowed on the free tier of the
+    /// chain's primary explorer.
+    ///
+    /// See [`NamedChain::etherscan_rate_limit_hint`] for more info.
+    pub const fn etherscan_rate_limit_hint(self) -> Option<u32> {
+        match self.kind() {
+            ChainKind::Named(named) => named.etherscan_rate_limit_hint(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the chain's rollup security model, if it is a known rollup.
+    ///
+    /// See [`NamedChain::rollup_type`] for more info.
+    pub const fn rollup_type(self) -> Option<RollupType> {
+        match self.kind() {
+            ChainKind::Named(named) => named.rollup_type(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns how the chain accounts for gas.
+    ///
+    /// See [`NamedChain::gas_semantics`] for more info.
+    pub const fn gas_semantics(self) -> GasSemantics {
+        match self.kind() {
+            ChainKind::Named(named) => named.gas_semantics(),
+            ChainKind::Id(_) => GasSemantics::Standard,
+        }
+    }
+
+    /// Returns how the chain's mempool handles pending transactions, if known.
+    ///
+    /// See [`NamedChain::mempool_profile`] for more info.
+    pub const fn mempool_profile(self) -> Option<MempoolProfile> {
+        match self.kind() {
+            ChainKind::Named(named) => named.mempool_profile(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns how addresses are checksummed when displayed on the chain.
+    ///
+    /// See [`NamedChain::address_format`] for more info.
+    pub const fn address_format(self) -> AddressFormat {
+        match self.kind() {
+            ChainKind::Named(named) => named.address_format(),
+            ChainKind::Id(_) => AddressFormat::Eip55,
+        }
+    }
+
+    /// Returns the minimum fee increase, in percent, to replace a pending transaction, if known.
+    ///
+    /// See [`NamedChain::replacement_fee_bump_percent`] for more info.
+    pub const fn replacement_fee_bump_percent(self) -> Option<u8> {
+        match self.kind() {
+            ChainKind::Named(named) => named.replacement_fee_bump_percent(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the chain's gas refund rules, if known.
+    ///
+    /// See [`NamedChain::gas_refund_rule`] for more info.
+    pub const fn gas_refund_rule(self) -> Option<GasRefundRule> {
+        match self.kind() {
+            ChainKind::Named(named) => named.gas_refund_rule(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the proof system of a validity rollup, if known.
+    ///
+    /// See [`NamedChain::proof_system`] for more info.
+    pub const fn proof_system(self) -> Option<ProofSystem> {
+        match self.kind() {
+            ChainKind::Named(named) => named.proof_system(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the superchain registry level of an OP-stack chain, if known.
+    ///
+    /// See [`NamedChain::superchain_level`] for more info.
+    pub const fn superchain_level(self) -> Option<SuperchainLevel> {
+        match self.kind() {
+            ChainKind::Named(named) => named.superchain_level(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the chain a rollup settles on.
+    ///
+    /// See [`NamedChain::settlement_chain`] for more info.
+    pub const fn settlement_chain(self) -> Option<NamedChain> {
+        match self.kind() {
+            ChainKind::Named(named) => named.settlement_chain(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the average blocktime of the chain a rollup settles on, if applicable.
+    ///
+    /// See [`NamedChain::settlement_block_time`] for more info.
+    pub const fn settlement_block_time(self) -> Option<Duration> {
+        match self.kind() {
+            ChainKind::Named(named) => named.settlement_block_time(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the chain's brand color as a `#RRGGBB` hex string.
+    ///
+    /// See [`NamedChain::brand_color`] for more info.
+    #[cfg(feature = "ui-metadata")]
+    pub const fn brand_color(self) -> Option<&'static str> {
+        match self.kind() {
+            ChainKind::Named(named) => named.brand_color(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the chain's ABI-encodable descriptor, for referencing it in calldata.
+    ///
+    /// See [`ChainDescriptor`] for more info.
+    #[inline]
+    pub fn abi_descriptor(self) -> ChainDescriptor {
+        ChainDescriptor::new(self)
+    }
+
+    /// Returns `true` if `address` is a precompiled contract on this chain.
+    ///
+    /// See [`NamedChain::is_precompile`] for more info.
+    pub const fn is_precompile(self, address: Address) -> bool {
+        match self.kind() {
+            ChainKind::Named(named) => named.is_precompile(address),
+            ChainKind::Id(_) => {
+                matches!(crate::named::low_address(address), Some(0x01..=0x0a))
+            }
+        }
+    }
+
+    /// Returns the address ranges reserved for the chain's system contracts.
+    ///
+    /// See [`NamedChain::reserved_system_address_ranges`] for more info.
+    pub const fn reserved_system_address_ranges(self) -> &'static [RangeInclusive<Address>] {
+        match self.kind() {
+            ChainKind::Named(named) => named.reserved_system_address_ranges(),
+            ChainKind::Id(_) => &[],
+        }
+    }
+
+    /// Returns the URL of a canonical token list covering the chain.
+    ///
+    /// See [`NamedChain::token_list_url`] for more info.
+    pub const fn token_list_url(self) -> Option<&'static str> {
+        match self.kind() {
+            ChainKind::Named(named) => named.token_list_url(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the chain's sequencer RPC URL, for rollups whose sequencer accepts raw transactions
+    /// directly.
+    ///
+    /// See [`NamedChain::sequencer_rpc_url`] for more info.
+    pub const fn sequencer_rpc_url(self) -> Option<&'static str> {
+        match self.kind() {
+            ChainKind::Named(named) => named.sequencer_rpc_url(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
     /// Returns the chain's blockchain explorer's API key environment variable's default name.
     ///
     /// See [`NamedChain::etherscan_api_key_name`] for more info.
@@ -674,6 +1523,38 @@ impl Chain {
         }
     }
 
+    /// Returns the chain's devp2p network ID.
+    ///
+    /// For unknown chains, this assumes the network ID is equal to the chain ID.
+    ///
+    /// See [`NamedChain::network_id`] for more info.
+    pub const fn network_id(self) -> u64 {
+        match self.kind() {
+            ChainKind::Named(named) => named.network_id(),
+            ChainKind::Id(id) => *id,
+        }
+    }
+
+    /// Returns the enode URLs of the chain's public devp2p bootnodes.
+    ///
+    /// See [`NamedChain::bootnodes`] for more info.
+    pub const fn bootnodes(self) -> Option<&'static [&'static str]> {
+        match self.kind() {
+            ChainKind::Named(named) => named.bootnodes(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
+    /// Returns the public key and domain of the chain's DNS node discovery tree.
+    ///
+    /// See [`NamedChain::dns_discovery_tree`] for more info.
+    pub const fn dns_discovery_tree(self) -> Option<(&'static str, &'static str)> {
+        match self.kind() {
+            ChainKind::Named(named) => named.dns_discovery_tree(),
+            ChainKind::Id(_) => None,
+        }
+    }
+
     /// Returns the address of the public DNS node list for the given chain.
     ///
     /// See [`NamedChain::public_dns_network_protocol`] for more info.
@@ -685,6 +1566,15 @@ impl Chain {
     }
 }
 
+/// Parses a `0x`-prefixed hexadecimal chain ID, as in [`Chain::from_rpc_chain_id_hex`].
+pub(crate) fn parse_hex_id(hex: &str) -> Option<u64> {
+    let digits = hex.strip_prefix("0x").or_else(|| hex.strip_prefix("0X"))?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u64::from_str_radix(digits, 16).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -732,6 +1622,17 @@ mod tests {
         assert_eq!(result.unwrap(), expected);
     }
 
+    #[test]
+    fn test_rpc_chain_id_hex() {
+        for chain in [Chain::mainnet(), Chain::from_id(0), Chain::from_id(u64::MAX)] {
+            assert_eq!(Chain::from_rpc_chain_id_hex(&chain.to_hex_id()), Some(chain));
+        }
+        assert_eq!(Chain::from_rpc_chain_id_hex("0X0a"), Some(Chain::from_id(10)));
+        for invalid in ["", "0x", "0x+1", "0xg", " 0x1", "0x10000000000000000"] {
+            assert_eq!(Chain::from_rpc_chain_id_hex(invalid), None, "{invalid}");
+        }
+    }
+
     #[test]
     fn test_default() {
         let default = Chain::default();
@@ -748,6 +1649,24 @@ mod tests {
         assert_eq!(chain.length(), 3);
     }
 
+    #[cfg(feature = "rlp")]
+    #[test]
+    fn test_decode_canonical_bounds() {
+        let max = alloy_rlp::encode(Chain::MAX_EIP155_ID);
+        assert_eq!(Chain::try_from(&max[..]), Ok(Chain::from_id(Chain::MAX_EIP155_ID)));
+
+        let over = alloy_rlp::encode(Chain::MAX_EIP155_ID + 1);
+        assert_eq!(
+            Chain::try_from(&over[..]),
+            Err(DecodeChainError::OutOfRange(Chain::MAX_EIP155_ID + 1))
+        );
+        assert_eq!(Chain::try_from(&alloy_rlp::encode(0u64)[..]), Err(DecodeChainError::Zero));
+
+        let mut trailing = alloy_rlp::encode(Chain::mainnet());
+        trailing.push(0);
+        assert!(matches!(Chain::try_from(&trailing[..]), Err(DecodeChainError::Rlp(_))));
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_serde() {
@@ -763,4 +1682,93 @@ mod tests {
         assert_eq!(serde_json::from_str::<alloc::vec::Vec<Chain>>(chains).unwrap(), expected);
         assert_eq!(serde_json::to_string(&expected).unwrap(), re);
     }
+
+    #[test]
+    fn test_abi_descriptor() {
+        let unnamed = Chain::from_id(1234567).abi_descriptor();
+        assert_eq!(unnamed.name_hash, alloy_primitives::B256::ZERO);
+        assert_eq!(unnamed.abi_encode()[..24], [0; 24]);
+        assert_eq!(
+            Chain::mainnet().abi_descriptor().name_hash,
+            alloy_primitives::keccak256("mainnet")
+        );
+    }
+
+    #[test]
+    fn test_eq_str() {
+        assert_eq!(Chain::base_mainnet(), "base");
+        assert_eq!(Chain::base_mainnet(), "8453");
+        assert_eq!(Chain::from_id(1234567), "1234567");
+        assert_ne!(Chain::from_id(1234567), "base");
+        assert_ne!(Chain::mainnet(), "");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_unknown_chain_observer() {
+        use crate::spec::{Chain as ChainSpec, ChainRegistry};
+        use std::sync::{Arc, Mutex};
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        Chain::set_unknown_chain_observer(move |id| {
+            // Creating chains from within the observer must not deadlock.
+            let _ = Chain::from_id(1);
+            sink.lock().unwrap().push(id);
+        });
+        let _ = Chain::from_id(987654321);
+        let _: Chain = "987654322".parse().unwrap();
+        let _ = Chain::from_rpc_chain_id_hex("0x3ade68b3");
+        let _ = Chain::from_id(1);
+        // Comparisons and internal conversions are not requests for a chain.
+        assert_ne!(Chain::mainnet(), "987654324");
+        let _ = crate::ChainSet::from_iter([Chain::from_id_unchecked(987654325)]).iter().count();
+
+        let mut registry = ChainRegistry::new();
+        let _ = registry.resolve("987654326");
+        let _ = registry.resolve("0x3ade68b7");
+        registry.register(987654328, ChainSpec::custom("devnet"));
+        let _ = Chain::from_id(987654328);
+        let _ = registry.resolve("987654328");
+        drop(registry);
+        let _ = Chain::from_id(987654329);
+        Chain::clear_unknown_chain_observer();
+        let _ = Chain::from_id(987654330);
+
+        let seen = seen.lock().unwrap();
+        for id in [987654321, 987654322, 0x3ade68b3, 987654326, 0x3ade68b7, 987654329] {
+            assert!(seen.contains(&id), "{id}");
+        }
+        for id in [1, 987654324, 987654325, 987654328, 987654330] {
+            assert!(!seen.contains(&id), "{id}");
+        }
+    }
+
+    #[test]
+    fn test_try_into_numeric() {
+        assert_eq!(u16::try_from(Chain::mainnet()), Ok(1));
+        assert_eq!(u32::try_from(Chain::from_named(NamedChain::Sepolia)), Ok(11155111));
+        assert!(u16::try_from(Chain::from_named(NamedChain::Sepolia)).is_err());
+        assert!(i64::try_from(Chain::from_id(u64::MAX)).is_err());
+        assert_eq!(i64::try_from(Chain::from_id(i64::MAX as u64)), Ok(i64::MAX));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_with() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Chains {
+            #[serde(with = "super::serde_string")]
+            string: Chain,
+            #[serde(with = "super::serde_u64")]
+            id: Chain,
+        }
+
+        let chains = Chains { string: Chain::from_id(80002), id: Chain::from_id(80002) };
+        let json = r#"{"string":"amoy","id":80002}"#;
+        assert_eq!(serde_json::to_string(&chains).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Chains>(json).unwrap(), chains);
+        let swapped = r#"{"string":80002,"id":"amoy"}"#;
+        assert_eq!(serde_json::from_str::<Chains>(swapped).unwrap(), chains);
+    }
 }
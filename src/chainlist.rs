@@ -0,0 +1,64 @@
+//! Interop with the [chainid.network](https://chainid.network) dataset, which backs
+//! [chainlist.org](https://chainlist.org).
+
+use crate::NamedChain;
+use alloc::string::String;
+use core::str::FromStr;
+
+/// The identifying fields of an entry of the
+/// [chainid.network dataset](https://chainid.network/chains.json).
+///
+/// Other fields are ignored when deserializing.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ChainlistEntry {
+    /// The chain ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u64>,
+    /// The short name, e.g. `arb1`. See [`NamedChain::chainlist_short_name`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_name: Option<String>,
+    /// The name of the chain family, e.g. `ETH` or `FTM`. Shared by most Ethereum L2s and
+    /// testnets, so it only identifies a chain on its own for a few networks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+}
+
+impl ChainlistEntry {
+    /// Resolves the entry to a [`NamedChain`], if possible.
+    ///
+    /// The chain ID is tried first, then the short name, then the `chain` field, which only
+    /// resolves if it is one of the chain's [names or aliases](NamedChain::names_and_aliases),
+    /// ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{chainlist::ChainlistEntry, NamedChain};
+    ///
+    /// let entry: ChainlistEntry =
+    ///     serde_json::from_str(r#"{"name": "Arbitrum One", "shortName": "arb1", "chain": "ETH"}"#)
+    ///         .unwrap();
+    /// assert_eq!(entry.resolve(), Some(NamedChain::Arbitrum));
+    ///
+    /// let entry: ChainlistEntry = serde_json::from_str(r#"{"chain": "FTM"}"#).unwrap();
+    /// assert_eq!(entry.resolve(), Some(NamedChain::Fantom));
+    ///
+    /// let entry: ChainlistEntry = serde_json::from_str(r#"{"chain": "ETH"}"#).unwrap();
+    /// assert_eq!(entry.resolve(), None);
+    /// ```
+    pub fn resolve(&self) -> Option<NamedChain> {
+        if let Some(chain) = self.chain_id.and_then(|id| NamedChain::try_from(id).ok()) {
+            return Some(chain);
+        }
+        if let Some(chain) =
+            self.short_name.as_deref().and_then(NamedChain::from_chainlist_short_name)
+        {
+            return Some(chain);
+        }
+        self.chain
+            .as_deref()
+            .and_then(|chain| NamedChain::from_str(&chain.to_ascii_lowercase()).ok())
+    }
+}
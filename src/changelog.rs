@@ -0,0 +1,143 @@
+//! Changes to the chain dataset across releases of this crate.
+//!
+//! Each release embeds a snapshot of its chains, with a fingerprint of each chain's metadata in
+//! `assets/chains.toml`, so that downstream services can learn which chains to invalidate cached
+//! data for after upgrading.
+
+use crate::Chain;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt;
+
+/// The dataset snapshot of each release, oldest first.
+///
+/// The snapshot of the current version is kept up to date by this crate's tests.
+const SNAPSHOTS: &[(&str, &str)] = &[("0.1.55", include_str!("../assets/changelog/0.1.55.txt"))];
+
+/// Returns the versions with a dataset snapshot, oldest first.
+///
+/// # Examples
+///
+/// ```
+/// assert!(alloy_chains::changelog::versions().any(|version| version == "0.1.55"));
+/// ```
+pub fn versions() -> impl DoubleEndedIterator<Item = &'static str> + ExactSizeIterator {
+    SNAPSHOTS.iter().map(|&(version, _)| version)
+}
+
+/// A change to a chain between two versions of the dataset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChainChange {
+    /// The chain was added.
+    Added(Chain),
+    /// The chain was removed.
+    Removed(Chain),
+    /// The chain's name or metadata changed.
+    Updated(Chain),
+}
+
+impl ChainChange {
+    /// Returns the chain that changed.
+    #[inline]
+    pub const fn chain(&self) -> Chain {
+        match *self {
+            Self::Added(chain) | Self::Removed(chain) | Self::Updated(chain) => chain,
+        }
+    }
+}
+
+/// The error returned by [`diff`] for a version without a dataset snapshot.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct UnknownVersion {
+    /// The unknown version.
+    pub version: String,
+}
+
+impl fmt::Display for UnknownVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no dataset snapshot for version {}", self.version)
+    }
+}
+
+impl core::error::Error for UnknownVersion {}
+
+/// Returns the chains that changed between the `from` and `to` versions of this crate, ordered by
+/// chain ID.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::changelog;
+///
+/// assert_eq!(changelog::diff("0.1.55", "0.1.55"), Ok(vec![]));
+/// assert!(changelog::diff("0.0.1", "0.1.55").is_err());
+/// ```
+pub fn diff(from: &str, to: &str) -> Result<Vec<ChainChange>, UnknownVersion> {
+    Ok(diff_snapshots(snapshot(from)?, snapshot(to)?))
+}
+
+fn snapshot(version: &str) -> Result<&'static str, UnknownVersion> {
+    SNAPSHOTS
+        .iter()
+        .find(|&&(v, _)| v == version)
+        .map(|&(_, snapshot)| snapshot)
+        .ok_or_else(|| UnknownVersion { version: version.into() })
+}
+
+/// Parses a snapshot's `<id> <name> <fingerprint>` lines, skipping `#` comments.
+fn parse(snapshot: &str) -> BTreeMap<u64, (&str, &str)> {
+    snapshot
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let id = fields.next()?.parse().ok()?;
+            Some((id, (fields.next()?, fields.next()?)))
+        })
+        .collect()
+}
+
+fn diff_snapshots(from: &str, to: &str) -> Vec<ChainChange> {
+    let (from, to) = (parse(from), parse(to));
+    let mut changes: Vec<_> = from
+        .iter()
+        .filter_map(|(&id, old)| match to.get(&id) {
+            None => Some(ChainChange::Removed(Chain::from_id_unobserved(id))),
+            Some(new) if new != old => Some(ChainChange::Updated(Chain::from_id_unobserved(id))),
+            Some(_) => None,
+        })
+        .chain(
+            to.keys()
+                .filter(|id| !from.contains_key(id))
+                .map(|&id| ChainChange::Added(Chain::from_id_unobserved(id))),
+        )
+        .collect();
+    changes.sort_unstable_by_key(|change| change.chain().id());
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_version_has_snapshot() {
+        assert_eq!(versions().next_back(), Some(env!("CARGO_PKG_VERSION")));
+    }
+
+    #[test]
+    fn diffs() {
+        let from = "# comment\n1 mainnet 00\n10 optimism 00\n56 bsc 00\n";
+        let to = "1 mainnet 00\n10 optimism 01\n8453 base 00\n";
+        assert_eq!(
+            diff_snapshots(from, to),
+            [
+                ChainChange::Updated(Chain::from_id(10)),
+                ChainChange::Removed(Chain::from_id(56)),
+                ChainChange::Added(Chain::from_id(8453)),
+            ]
+        );
+        assert_eq!(diff_snapshots(to, to), []);
+    }
+}
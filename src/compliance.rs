@@ -0,0 +1,19 @@
+//! Structural chain properties relevant to compliance policies.
+
+/// A neutral, structural property of how a chain produces blocks, which institutional
+/// integrators may want to apply policy decisions to.
+///
+/// These flags describe facts about a chain's operation only; they make no assessment of its
+/// legal status in any jurisdiction.
+///
+/// Returned by [`NamedChain::compliance_flags`](crate::NamedChain::compliance_flags).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ComplianceFlag {
+    /// Blocks are produced by a permissioned set of validators or authorities (proof of
+    /// authority), rather than by an open validator set.
+    PermissionedValidators,
+    /// Transactions are ordered by a single sequencer run by the chain operator, which can delay
+    /// or exclude transactions until they are forced through L1.
+    CentralizedSequencer,
+}
@@ -0,0 +1,47 @@
+//! L1 protocol contract address sets for rollups.
+
+use crate::NamedChain;
+use alloy_primitives::Address;
+
+/// L1-side protocol contracts of an OP-stack chain, as listed in the [superchain registry].
+///
+/// Returned by [`NamedChain::op_l1_contracts`](crate::NamedChain::op_l1_contracts).
+///
+/// [superchain registry]: https://github.com/ethereum-optimism/superchain-registry
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct OpL1Contracts {
+    /// The `OptimismPortal` proxy, used to deposit and to prove and finalize withdrawals.
+    pub optimism_portal: Address,
+    /// The `SystemConfig` proxy.
+    pub system_config: Address,
+    /// The `DisputeGameFactory` proxy, if the chain runs fault proofs.
+    pub dispute_game_factory: Option<Address>,
+    /// The legacy `L2OutputOracle` proxy, if the chain used output proposals before fault proofs.
+    pub l2_output_oracle: Option<Address>,
+}
+
+/// Parent-chain core contracts of an Arbitrum (Nitro) chain, as listed in the [Arbitrum docs].
+///
+/// For Arbitrum One, Nova and Sepolia, the parent chain is Ethereum or its testnet; Orbit chains
+/// may instead settle on an Arbitrum chain.
+///
+/// Returned by [`NamedChain::arbitrum_l1_contracts`](crate::NamedChain::arbitrum_l1_contracts).
+///
+/// [Arbitrum docs]: https://docs.arbitrum.io/build-decentralized-apps/reference/contract-addresses
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ArbitrumL1Contracts {
+    /// The chain the contracts are deployed on.
+    pub parent_chain: NamedChain,
+    /// The `Rollup` proxy, which tracks assertions and the challenge protocol.
+    pub rollup: Address,
+    /// The `Bridge`, which holds the chain's escrowed ETH and relays messages.
+    pub bridge: Address,
+    /// The delayed `Inbox`, used to submit retryable tickets and delayed messages.
+    pub inbox: Address,
+    /// The `SequencerInbox`, which receives the sequencer's batches.
+    pub sequencer_inbox: Address,
+    /// The `Outbox`, used to execute L2-to-L1 messages.
+    pub outbox: Address,
+}
@@ -0,0 +1,62 @@
+//! Native currency metadata.
+
+use alloy_primitives::Address;
+
+/// A chain's native currency, as used in [EIP-3085] `wallet_addEthereumChain` requests.
+///
+/// Returned by [`NamedChain::native_currency`](crate::NamedChain::native_currency).
+///
+/// [EIP-3085]: https://eips.ethereum.org/EIPS/eip-3085
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct NativeCurrency {
+    /// The currency's full name (e.g. `Ether`).
+    pub name: &'static str,
+    /// The currency's symbol (e.g. `ETH`).
+    pub symbol: &'static str,
+    /// The number of decimals of the currency's base unit.
+    pub decimals: u8,
+}
+
+/// The asset a chain's native currency is pegged to, for chains paying gas in a stablecoin.
+///
+/// Returned by [`NamedChain::gas_token_peg`](crate::NamedChain::gas_token_peg).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct GasTokenPeg {
+    /// The symbol of the pegged asset (e.g. `DAI`).
+    pub asset: &'static str,
+    /// The expected value of one whole native token in USD, with
+    /// [`USD_DECIMALS`](Self::USD_DECIMALS) decimals.
+    pub usd_value: u64,
+}
+
+impl GasTokenPeg {
+    /// The number of decimals of [`usd_value`](Self::usd_value), as used by Chainlink USD feeds.
+    pub const USD_DECIMALS: u8 = 8;
+
+    /// One US dollar, with [`USD_DECIMALS`](Self::USD_DECIMALS) decimals.
+    pub const ONE_USD: u64 = 100_000_000;
+}
+
+/// A chain's wrapped native token (e.g. WETH).
+///
+/// Wrapped native tokens are not uniform across chains: most are [WETH9] clones, while some
+/// also implement [EIP-2612] `permit`.
+///
+/// Returned by [`NamedChain::wrapped_native_token_info`](crate::NamedChain::wrapped_native_token_info).
+///
+/// [WETH9]: https://etherscan.io/address/0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2#code
+/// [EIP-2612]: https://eips.ethereum.org/EIPS/eip-2612
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WrappedNativeToken {
+    /// The token's address.
+    pub address: Address,
+    /// The token's symbol (e.g. `WETH`).
+    pub symbol: &'static str,
+    /// The token's decimals.
+    pub decimals: u8,
+    /// Whether the token supports [EIP-2612](https://eips.ethereum.org/EIPS/eip-2612) `permit`.
+    pub supports_permit: bool,
+}
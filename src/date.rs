@@ -0,0 +1,38 @@
+//! Calendar dates.
+
+use core::fmt;
+
+/// A calendar date, used to timestamp chain metadata.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::Date;
+///
+/// let date = Date::new(2024, 3, 13);
+/// assert!(date < Date::new(2024, 12, 1));
+/// assert_eq!(date.to_string(), "2024-03-13");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// The year.
+    pub year: u16,
+    /// The month, from 1 to 12.
+    pub month: u8,
+    /// The day of the month, from 1 to 31.
+    pub day: u8,
+}
+
+impl Date {
+    /// Creates a new date.
+    #[inline]
+    pub const fn new(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
@@ -0,0 +1,67 @@
+//! ABI-encodable chain descriptors.
+
+use crate::{Chain, ChainKind};
+use alloy_primitives::{keccak256, B256};
+
+/// A fixed-size reference to a chain for on-chain registries and cross-chain message payloads.
+///
+/// Its ABI encoding matches the Solidity struct:
+///
+/// ```solidity
+/// struct ChainDescriptor {
+///     uint64 id;
+///     bytes32 nameHash;
+/// }
+/// ```
+///
+/// Returned by [`Chain::abi_descriptor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ChainDescriptor {
+    /// The chain ID.
+    pub id: u64,
+    /// The `keccak256` hash of the chain's canonical [name](crate::NamedChain::as_str), or zero
+    /// if the chain is unnamed.
+    pub name_hash: B256,
+}
+
+impl ChainDescriptor {
+    /// The length of the ABI encoding, in bytes.
+    pub const ENCODED_LEN: usize = 64;
+
+    /// Creates the descriptor of `chain`.
+    pub fn new(chain: Chain) -> Self {
+        let name_hash = match chain.kind() {
+            ChainKind::Named(named) => keccak256(named.as_str()),
+            ChainKind::Id(_) => B256::ZERO,
+        };
+        Self { id: chain.id(), name_hash }
+    }
+
+    /// Returns the ABI encoding of the descriptor, i.e. the ID as a left-padded 32-byte word,
+    /// followed by the name hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::Chain;
+    /// use alloy_primitives::keccak256;
+    ///
+    /// let encoded = Chain::base_mainnet().abi_descriptor().abi_encode();
+    /// assert_eq!(encoded[24..32], 8453u64.to_be_bytes());
+    /// assert_eq!(encoded[32..], keccak256("base")[..]);
+    /// ```
+    pub fn abi_encode(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut out = [0; Self::ENCODED_LEN];
+        out[24..32].copy_from_slice(&self.id.to_be_bytes());
+        out[32..].copy_from_slice(self.name_hash.as_slice());
+        out
+    }
+}
+
+impl From<Chain> for ChainDescriptor {
+    #[inline]
+    fn from(chain: Chain) -> Self {
+        Self::new(chain)
+    }
+}
@@ -0,0 +1,59 @@
+//! Block explorer metadata.
+
+/// The family of a block explorer, which determines its API flavor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ExplorerKind {
+    /// An [Etherscan](https://etherscan.io) instance, or one of its white-label deployments.
+    Etherscan,
+    /// A [Blockscout](https://www.blockscout.com) instance.
+    Blockscout,
+    /// A [Routescan](https://routescan.io) instance.
+    Routescan,
+    /// An [OKLink](https://www.oklink.com) instance.
+    OkLink,
+    /// Any other explorer. The API may or may not be Etherscan-compatible.
+    Custom,
+}
+
+impl ExplorerKind {
+    /// Returns a conservative hint for the number of API requests per second allowed on the
+    /// family's free tier, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::ExplorerKind;
+    ///
+    /// assert_eq!(ExplorerKind::Etherscan.default_rate_limit_hint(), Some(3));
+    /// assert_eq!(ExplorerKind::Custom.default_rate_limit_hint(), None);
+    /// ```
+    pub const fn default_rate_limit_hint(self) -> Option<u32> {
+        match self {
+            Self::Etherscan => Some(3),
+            Self::Blockscout => Some(5),
+            Self::Routescan => Some(2),
+            Self::OkLink | Self::Custom => None,
+        }
+    }
+}
+
+/// A block explorer instance for a chain.
+///
+/// Returned by [`NamedChain::explorers`](crate::NamedChain::explorers).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ExplorerInfo {
+    /// The explorer's family.
+    pub kind: ExplorerKind,
+    /// The explorer's API URL (e.g. `https://api.etherscan.io/api`), without a trailing `/`.
+    pub api_url: &'static str,
+    /// The explorer's base URL (e.g. `https://etherscan.io`), without a trailing `/`.
+    pub base_url: &'static str,
+    /// The explorer's priority. Lower values are preferred; `0` is the chain's primary explorer.
+    pub priority: u8,
+    /// A hint for the number of API requests per second allowed on the free tier. Defaults to
+    /// the [family's](ExplorerKind::default_rate_limit_hint) unless the instance is known to
+    /// differ.
+    pub rate_limit_hint: Option<u32>,
+}
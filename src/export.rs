@@ -0,0 +1,112 @@
+//! Tabular exports of the known chains and their metadata.
+//!
+//! Each row describes one [`NamedChain`], ordered by chain ID, with the columns in [`COLUMNS`].
+//! Missing metadata is rendered as an empty cell.
+
+use crate::NamedChain;
+use alloc::string::String;
+use core::fmt::Write;
+
+/// The column headers of the exported tables.
+pub const COLUMNS: [&str; 6] =
+    ["Chain", "ID", "Testnet", "Native currency", "Block time (ms)", "Explorer"];
+
+/// Returns a Markdown table of all the known chains.
+///
+/// # Examples
+///
+/// ```
+/// let table = alloy_chains::export::to_markdown();
+/// assert!(table.starts_with("| Chain | ID |"));
+/// # #[cfg(not(feature = "slim"))]
+/// assert!(table.contains("\n| mainnet | 1 | no | ETH | 12000 | https://etherscan.io |\n"));
+/// ```
+pub fn to_markdown() -> String {
+    let mut out = String::new();
+    write_row(&mut out, COLUMNS.map(String::from), "| ", " | ", " |");
+    write_row(&mut out, COLUMNS.map(|_| String::from("---")), "| ", " | ", " |");
+    for chain in NamedChain::iter_sorted_by_id() {
+        write_row(&mut out, row(chain).map(|cell| cell.replace('|', "\\|")), "| ", " | ", " |");
+    }
+    out
+}
+
+/// Returns a CSV table of all the known chains, with a header row.
+///
+/// Fields containing a comma, quote or line break are quoted as per
+/// [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180).
+///
+/// # Examples
+///
+/// ```
+/// let table = alloy_chains::export::to_csv();
+/// assert!(table.starts_with("Chain,ID,Testnet,Native currency,Block time (ms),Explorer\n"));
+/// # #[cfg(not(feature = "slim"))]
+/// assert!(table.contains("\nmainnet,1,no,ETH,12000,https://etherscan.io\n"));
+/// ```
+pub fn to_csv() -> String {
+    let mut out = String::new();
+    write_row(&mut out, COLUMNS.map(String::from), "", ",", "");
+    for chain in NamedChain::iter_sorted_by_id() {
+        write_row(&mut out, row(chain).map(|cell| csv_field(&cell)), "", ",", "");
+    }
+    out
+}
+
+fn row(chain: NamedChain) -> [String; 6] {
+    let mut row = [const { String::new() }; 6];
+    row[0].push_str(chain.as_str());
+    let _ = write!(row[1], "{}", chain as u64);
+    row[2].push_str(if chain.is_testnet() { "yes" } else { "no" });
+    row[3].push_str(chain.native_currency_symbol().unwrap_or_default());
+    if let Some(block_time) = chain.average_blocktime_hint() {
+        let _ = write!(row[4], "{}", block_time.as_millis());
+    }
+    if let Some((_, base_url)) = chain.etherscan_urls() {
+        row[5].push_str(base_url);
+    }
+    row
+}
+
+fn write_row(out: &mut String, cells: [String; 6], start: &str, sep: &str, end: &str) {
+    out.push_str(start);
+    for (i, cell) in cells.iter().enumerate() {
+        if i > 0 {
+            out.push_str(sep);
+        }
+        out.push_str(cell);
+    }
+    out.push_str(end);
+    out.push('\n');
+}
+
+fn csv_field(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        String::from(cell)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_row_per_chain() {
+        use strum::EnumCount;
+
+        assert_eq!(to_markdown().lines().count(), NamedChain::COUNT + 2);
+        assert_eq!(to_csv().lines().count(), NamedChain::COUNT + 1);
+        for line in to_csv().lines() {
+            assert_eq!(line.matches(',').count(), COLUMNS.len() - 1, "{line}");
+        }
+    }
+
+    #[test]
+    fn csv_quoting() {
+        assert_eq!(csv_field("a"), "a");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("a\"b"), "\"a\"\"b\"");
+    }
+}
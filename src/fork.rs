@@ -0,0 +1,49 @@
+//! Local fork emulation profiles.
+
+use crate::NamedChain;
+use alloy_primitives::Address;
+use core::time::Duration;
+
+/// How a chain prices transactions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FeeModel {
+    /// Legacy gas pricing, without an EIP-1559 base fee.
+    Legacy,
+    /// [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559) base fee and priority fee.
+    Eip1559,
+}
+
+/// The facts a local fork (e.g. anvil or hardhat) needs to emulate a chain.
+///
+/// Returned by [`NamedChain::fork_profile`].
+///
+/// **Note:** the block time and gas limit are hints, see
+/// [`NamedChain::average_blocktime_hint`] and [`NamedChain::block_gas_limit_hint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ForkProfile {
+    /// The chain's ID.
+    pub chain_id: u64,
+    /// The chain's fee model.
+    pub fee_model: FeeModel,
+    /// The chain's average block time, if known.
+    pub block_time: Option<Duration>,
+    /// The chain's block gas limit, if known.
+    pub gas_limit: Option<u64>,
+    /// The predeployed system contracts and precompiles the fork must keep.
+    pub predeploys: &'static [Address],
+}
+
+impl ForkProfile {
+    /// Returns the fork profile of the given chain.
+    pub(crate) const fn new(chain: NamedChain) -> Self {
+        Self {
+            chain_id: chain as u64,
+            fee_model: if chain.is_legacy() { FeeModel::Legacy } else { FeeModel::Eip1559 },
+            block_time: chain.average_blocktime_hint(),
+            gas_limit: chain.block_gas_limit_hint(),
+            predeploys: chain.predeploys(),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Gas accounting semantics.
+
+/// How a chain accounts for gas, which determines which fields of `eth_estimateGas` and receipt
+/// responses are meaningful.
+///
+/// Returned by [`NamedChain::gas_semantics`](crate::NamedChain::gas_semantics).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GasSemantics {
+    /// Ethereum semantics: gas only measures execution, and `gasUsed * effectiveGasPrice` is the
+    /// full transaction fee.
+    #[default]
+    Standard,
+    /// Gas only measures L2 execution. The cost of posting the transaction to L1 is charged
+    /// separately in wei and reported in the receipt's `l1Fee` field (e.g. OP-stack chains and
+    /// Scroll), so `eth_estimateGas` does not cover it.
+    SeparateL1Fee,
+    /// Gas units include the cost of posting the transaction to L1, scaled by the L1 base fee;
+    /// the receipt's `gasUsedForL1` field reports that share (e.g. Arbitrum). Estimates therefore
+    /// fluctuate with L1 prices even for the same call.
+    L1InclusiveGas,
+    /// Gas includes the cost of publishing the transaction's state diffs (pubdata), bounded by
+    /// the transaction's `gasPerPubdata` limit (e.g. ZKsync Era). Use the chain's own fee
+    /// estimation methods (e.g. `zks_estimateFee`) rather than `eth_estimateGas` alone.
+    PubdataGas,
+}
+
+/// The gas refund rules of a chain, which static analyzers need to price storage clearing
+/// correctly.
+///
+/// Returned by [`NamedChain::gas_refund_rule`](crate::NamedChain::gas_refund_rule).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GasRefundRule {
+    /// Pre-London rules: clearing storage and `SELFDESTRUCT` are refunded, up to half of the gas
+    /// used by the transaction.
+    Eip2200,
+    /// [EIP-3529](https://eips.ethereum.org/EIPS/eip-3529): `SELFDESTRUCT` is no longer refunded
+    /// and storage clearing refunds are reduced, up to a fifth of the gas used by the transaction.
+    Eip3529,
+    /// Refunds are computed by the operator rather than by the EVM rules, e.g. by ZKsync Era's
+    /// bootloader, which also refunds unused pubdata.
+    Operator,
+}
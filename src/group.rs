@@ -0,0 +1,21 @@
+//! Chain grouping for user interfaces.
+
+/// A family of related chains, for grouping chains in pickers and menus.
+///
+/// Returned by [`NamedChain::parent_group`](crate::NamedChain::parent_group).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ChainGroup {
+    /// Ethereum and its testnets.
+    Ethereum,
+    /// OP-stack chains, including the members of the Optimism Superchain.
+    OpStack,
+    /// Arbitrum One, Arbitrum Nova and their testnets.
+    Arbitrum,
+    /// Polygon PoS, Polygon zkEVM and their testnets.
+    Polygon,
+    /// BNB Smart Chain and its testnet.
+    Bnb,
+    /// ZKsync Era and its testnet.
+    ZkSync,
+}
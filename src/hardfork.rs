@@ -0,0 +1,157 @@
+//! Network upgrades.
+
+use core::fmt;
+
+/// An Ethereum or OP-stack network upgrade.
+///
+/// Variants are ordered by activation within each family: Ethereum upgrades come first, followed
+/// by the OP-stack upgrades starting with [`Bedrock`](Self::Bedrock). Comparisons across the two
+/// families are not meaningful; an OP-stack chain activates Ethereum execution-layer upgrades
+/// alongside its own (e.g. Canyon enables Shanghai).
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::hardfork::Hardfork;
+///
+/// assert!(Hardfork::Shanghai < Hardfork::Cancun);
+/// assert!(Hardfork::Ecotone < Hardfork::Granite);
+/// assert_eq!(Hardfork::SpuriousDragon.to_string(), "spurious-dragon");
+/// assert_eq!("Prague".parse::<Hardfork>().unwrap(), Hardfork::Prague);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(strum::IntoStaticStr)] // Into<&'static str>, AsRef<str>, fmt::Display and serde::Serialize
+#[derive(strum::VariantArray)] // Hardfork::VARIANTS
+#[derive(strum::EnumString)] // FromStr, TryFrom<&str>
+#[derive(strum::EnumIter)] // Hardfork::iter
+#[derive(strum::EnumCount)] // Hardfork::COUNT
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "kebab-case", ascii_case_insensitive)]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[non_exhaustive]
+pub enum Hardfork {
+    /// Frontier: the genesis protocol.
+    Frontier,
+    /// Homestead: [EIP-606](https://eips.ethereum.org/EIPS/eip-606).
+    Homestead,
+    /// The DAO fork: [EIP-779](https://eips.ethereum.org/EIPS/eip-779).
+    Dao,
+    /// Tangerine Whistle: [EIP-608](https://eips.ethereum.org/EIPS/eip-608).
+    Tangerine,
+    /// Spurious Dragon: [EIP-607](https://eips.ethereum.org/EIPS/eip-607).
+    SpuriousDragon,
+    /// Byzantium: [EIP-609](https://eips.ethereum.org/EIPS/eip-609).
+    Byzantium,
+    /// Constantinople: [EIP-1013](https://eips.ethereum.org/EIPS/eip-1013).
+    Constantinople,
+    /// Petersburg: [EIP-1716](https://eips.ethereum.org/EIPS/eip-1716).
+    Petersburg,
+    /// Istanbul: [EIP-1679](https://eips.ethereum.org/EIPS/eip-1679).
+    Istanbul,
+    /// Muir Glacier: [EIP-2387](https://eips.ethereum.org/EIPS/eip-2387).
+    MuirGlacier,
+    /// Berlin: [EIP-2070](https://eips.ethereum.org/EIPS/eip-2070).
+    Berlin,
+    /// London: introduces the EIP-1559 fee market.
+    London,
+    /// Arrow Glacier: [EIP-4345](https://eips.ethereum.org/EIPS/eip-4345).
+    ArrowGlacier,
+    /// Gray Glacier: [EIP-5133](https://eips.ethereum.org/EIPS/eip-5133).
+    GrayGlacier,
+    /// Paris (The Merge): [EIP-3675](https://eips.ethereum.org/EIPS/eip-3675).
+    Paris,
+    /// Shanghai: enables withdrawals and the `PUSH0` opcode.
+    Shanghai,
+    /// Cancun: [EIP-7569](https://eips.ethereum.org/EIPS/eip-7569) (blobs).
+    Cancun,
+    /// Prague: [EIP-7600](https://eips.ethereum.org/EIPS/eip-7600) (Pectra).
+    Prague,
+    /// Osaka: [EIP-7607](https://eips.ethereum.org/EIPS/eip-7607) (Fusaka).
+    Osaka,
+
+    /// Bedrock: the initial OP-stack protocol.
+    Bedrock,
+    /// Regolith: fixes to deposit transaction processing.
+    Regolith,
+    /// Canyon: activates Shanghai on L2.
+    Canyon,
+    /// Delta: span batches.
+    Delta,
+    /// Ecotone: activates Cancun on L2 and posts batches as blobs.
+    Ecotone,
+    /// Fjord: switches the L1 data fee to a FastLZ compression estimate.
+    Fjord,
+    /// Granite: limits on the fault proof `bn256Pairing` precompile and channel timeout.
+    Granite,
+    /// Holocene: strict batch derivation and configurable EIP-1559 parameters.
+    Holocene,
+    /// Isthmus: activates Prague on L2 and adds operator fees.
+    Isthmus,
+}
+
+impl fmt::Display for Hardfork {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl AsRef<str> for Hardfork {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Hardfork {
+    /// Returns the string representation of the hardfork.
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        self.into()
+    }
+
+    /// Returns `true` if this is an OP-stack network upgrade.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::hardfork::Hardfork;
+    ///
+    /// assert!(Hardfork::Fjord.is_optimism());
+    /// assert!(!Hardfork::Cancun.is_optimism());
+    /// ```
+    #[inline]
+    pub const fn is_optimism(self) -> bool {
+        self as u8 >= Self::Bedrock as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[allow(unused_imports)]
+    use alloc::string::ToString;
+
+    #[test]
+    fn roundtrip_string() {
+        for fork in Hardfork::iter() {
+            let s = fork.to_string();
+            assert_eq!(s.parse::<Hardfork>().unwrap(), fork);
+            assert_eq!(s.to_uppercase().parse::<Hardfork>().unwrap(), fork);
+            #[cfg(feature = "serde")]
+            assert_eq!(serde_json::to_string(&fork).unwrap(), format!("\"{s}\""));
+        }
+    }
+
+    #[test]
+    fn families_are_contiguous() {
+        let forks: alloc::vec::Vec<_> = Hardfork::iter().collect();
+        assert!(forks.windows(2).all(|w| w[0] < w[1]));
+        let split = forks.iter().position(|f| f.is_optimism()).unwrap();
+        assert_eq!(forks[split], Hardfork::Bedrock);
+        assert!(forks[split..].iter().all(|f| f.is_optimism()));
+    }
+}
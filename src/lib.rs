@@ -20,10 +20,107 @@
 #[macro_use]
 extern crate alloc;
 
+mod account_abstraction;
+pub use account_abstraction::{AaSupport, BundlerProvider};
+
+#[cfg(feature = "changelog")]
+pub mod changelog;
+
+mod address_format;
+pub use address_format::AddressFormat;
+
+mod capabilities;
+pub use capabilities::ChainCapabilities;
+
 mod chain;
+#[cfg(feature = "rlp")]
+pub use chain::DecodeChainError;
+#[cfg(feature = "serde")]
+pub use chain::{serde_string, serde_u64};
 pub use chain::{Chain, ChainKind};
 
+#[cfg(feature = "serde")]
+pub mod chainlist;
+
+mod compliance;
+pub use compliance::ComplianceFlag;
+
+mod contracts;
+pub use contracts::{ArbitrumL1Contracts, OpL1Contracts};
+
+mod currency;
+pub use currency::{GasTokenPeg, NativeCurrency, WrappedNativeToken};
+
+mod date;
+pub use date::Date;
+
+mod descriptor;
+pub use descriptor::ChainDescriptor;
+
+mod explorer;
+pub use explorer::{ExplorerInfo, ExplorerKind};
+
+pub mod export;
+
+mod fork;
+pub use fork::{FeeModel, ForkProfile};
+
+mod gas;
+pub use gas::{GasRefundRule, GasSemantics};
+
+mod group;
+pub use group::ChainGroup;
+
+pub mod hardfork;
+
+mod mempool;
+pub use mempool::MempoolProfile;
+
+mod metadata;
+pub use metadata::{ChainMetadata, MetadataError};
+
+mod mev;
+pub use mev::{MevEndpoint, MevEndpointKind};
+
 mod named;
-pub use named::{NamedChain, NamedChainIter};
+pub use named::{NamedChain, NamedChainIter, StrictParseError};
+
+mod opcode;
+pub use opcode::{Opcode, OpcodeSupport};
+
+mod precompile;
+pub use precompile::Precompile;
+
+#[cfg(feature = "probe")]
+pub mod probe;
+
+mod profile;
+pub use profile::ChainProfile;
+
+mod provider;
+pub use provider::{RpcProvider, RpcUrlTemplate};
+
+mod rollup;
+pub use rollup::{ProofSystem, RollupType, SuperchainLevel};
+
+#[cfg(feature = "schema")]
+pub mod schema;
+
+mod selector;
+pub use selector::{ChainSelector, SelectorParseError};
+
+mod set;
+pub use set::ChainSet;
 
 pub mod spec;
+
+#[cfg(all(test, feature = "std"))]
+mod test_utils;
+
+mod typed;
+pub use typed::TypedChain;
+
+pub mod units;
+
+mod verification;
+pub use verification::{SourceFormat, VerificationTarget, VerificationTargetBuilder, VerifierType};
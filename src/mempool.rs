@@ -0,0 +1,31 @@
+//! Mempool behavior.
+
+/// How a chain's mempool handles pending transactions, which transaction managers need to decide
+/// how to replace stuck transactions and how many to submit ahead.
+///
+/// Returned by [`NamedChain::mempool_profile`](crate::NamedChain::mempool_profile).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct MempoolProfile {
+    /// Whether pending transactions are gossiped in a public mempool. Chains with a single
+    /// sequencer keep them private, so they cannot be observed or front-run before inclusion.
+    pub public: bool,
+    /// The minimum fee increase, in percent, for a transaction to replace a pending one with the
+    /// same nonce, or `None` if pending transactions cannot be replaced.
+    pub replacement_fee_bump: Option<u8>,
+    /// Whether transactions with a nonce gap are queued until the gap is filled. Otherwise they
+    /// are rejected, or only held briefly, so transactions must be submitted in nonce order.
+    pub queues_nonce_gaps: bool,
+}
+
+impl MempoolProfile {
+    /// The behavior of geth's transaction pool with its default settings.
+    pub const GETH: Self =
+        Self { public: true, replacement_fee_bump: Some(10), queues_nonce_gaps: true };
+
+    /// Returns `true` if pending transactions can be replaced.
+    #[inline]
+    pub const fn supports_replacement(&self) -> bool {
+        self.replacement_fee_bump.is_some()
+    }
+}
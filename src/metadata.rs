@@ -0,0 +1,133 @@
+//! Compile-time chain metadata.
+
+use crate::{ExplorerInfo, NamedChain, NativeCurrency};
+use core::{fmt, time::Duration};
+
+/// A snapshot of a named chain's metadata, usable in `const` contexts.
+///
+/// Built by [`NamedChain::metadata`] or the [`chain_metadata!`](crate::chain_metadata) macro.
+/// Each field mirrors the [`NamedChain`] method of the same name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ChainMetadata {
+    /// The chain.
+    pub chain: NamedChain,
+    /// The chain's ID.
+    pub id: u64,
+    /// The chain's canonical name, as returned by [`NamedChain::as_str`].
+    pub name: &'static str,
+    /// Whether the chain is a testnet.
+    pub is_testnet: bool,
+    /// The chain's native currency, if known.
+    pub native_currency: Option<NativeCurrency>,
+    /// The chain's average block time, if known.
+    pub average_blocktime: Option<Duration>,
+    /// The chain's primary explorer API and base URLs, if any.
+    pub etherscan_urls: Option<(&'static str, &'static str)>,
+    /// The name of the environment variable holding the primary explorer's API key, if any.
+    pub etherscan_api_key_name: Option<&'static str>,
+    /// The chain's block explorers, by priority.
+    pub explorers: &'static [ExplorerInfo],
+}
+
+impl ChainMetadata {
+    /// Returns the metadata of the given chain.
+    pub(crate) const fn new(chain: NamedChain) -> Self {
+        Self {
+            chain,
+            id: chain as u64,
+            name: chain.name(),
+            is_testnet: chain.is_testnet(),
+            native_currency: chain.native_currency(),
+            average_blocktime: chain.average_blocktime_hint(),
+            etherscan_urls: chain.etherscan_urls(),
+            etherscan_api_key_name: chain.etherscan_api_key_name(),
+            explorers: chain.explorers(),
+        }
+    }
+}
+
+/// Resolves a [`NamedChain`] to its [`ChainMetadata`] at compile time.
+///
+/// Expands to an inline `const` block, so the chain must be a constant expression.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::{chain_metadata, ChainMetadata, NamedChain};
+///
+/// static CHAINS: [ChainMetadata; 2] =
+///     [chain_metadata!(NamedChain::Mainnet), chain_metadata!(NamedChain::Base)];
+///
+/// assert_eq!(CHAINS[1].id, 8453);
+/// assert_eq!(CHAINS[1].name, "base");
+/// ```
+#[macro_export]
+macro_rules! chain_metadata {
+    ($chain:expr) => {
+        const { $crate::NamedChain::metadata($chain) }
+    };
+}
+
+/// Asserts at compile time that a [`NamedChain`] has the given ID.
+///
+/// Use this to check chain IDs hardcoded in `const` contexts, e.g. in a downstream crate's
+/// configuration, against this crate's table.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::{assert_chain_id, NamedChain};
+///
+/// assert_chain_id!(NamedChain::Base, 8453);
+/// ```
+///
+/// A mismatch fails to compile:
+///
+/// ```compile_fail
+/// use alloy_chains::{assert_chain_id, NamedChain};
+///
+/// assert_chain_id!(NamedChain::Base, 8454);
+/// ```
+#[macro_export]
+macro_rules! assert_chain_id {
+    ($chain:expr, $id:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            $crate::NamedChain::id_of($chain) == $id,
+            ::core::concat!(
+                "chain ID of `",
+                ::core::stringify!($chain),
+                "` is not `",
+                ::core::stringify!($id),
+                "`"
+            ),
+        );
+    };
+}
+
+/// The reason a metadata lookup returned no value.
+///
+/// Returned by the `try_` variants of the metadata methods, e.g.
+/// [`NamedChain::try_etherscan_urls`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetadataError {
+    /// The chain does not have this metadata, e.g. a local development chain has no explorer.
+    NotApplicable,
+    /// The metadata has not been curated for this chain, or the chain is not known.
+    Unknown,
+    /// The chain is [deprecated](NamedChain::is_deprecated), so its metadata is no longer curated.
+    Deprecated,
+}
+
+impl fmt::Display for MetadataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotApplicable => "metadata is not applicable to this chain",
+            Self::Unknown => "metadata is not known for this chain",
+            Self::Deprecated => "chain is deprecated",
+        })
+    }
+}
+
+impl core::error::Error for MetadataError {}
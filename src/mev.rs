@@ -0,0 +1,26 @@
+//! Private transaction and MEV relay endpoints.
+
+/// The kind of service exposed by a [`MevEndpoint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum MevEndpointKind {
+    /// An RPC that accepts `eth_sendRawTransaction` and keeps transactions out of the public
+    /// mempool (e.g. Flashbots Protect, MEV Blocker).
+    Protect,
+    /// A relay or builder endpoint that accepts bundles via `eth_sendBundle`.
+    Bundle,
+}
+
+/// A private transaction submission endpoint for a chain.
+///
+/// Returned by [`NamedChain::mev_endpoints`](crate::NamedChain::mev_endpoints).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct MevEndpoint {
+    /// The service's name (e.g. `Flashbots Protect`).
+    pub name: &'static str,
+    /// The kind of service.
+    pub kind: MevEndpointKind,
+    /// The endpoint's URL, without a trailing `/`.
+    pub url: &'static str,
+}
@@ -1,12 +1,20 @@
-use alloy_primitives::{address, Address};
-use core::{cmp::Ordering, fmt, time::Duration};
+use crate::{
+    hardfork::Hardfork, AaSupport, AddressFormat, ArbitrumL1Contracts, BundlerProvider,
+    ChainCapabilities, ChainGroup, ChainMetadata, ChainProfile, ComplianceFlag, Date, ExplorerInfo,
+    ExplorerKind, ForkProfile, GasRefundRule, GasSemantics, GasTokenPeg, MempoolProfile,
+    MetadataError, MevEndpoint, NativeCurrency, OpL1Contracts, Opcode, OpcodeSupport, Precompile,
+    ProofSystem, RollupType, RpcProvider, RpcUrlTemplate, SuperchainLevel, WrappedNativeToken,
+};
+use alloy_primitives::{address, Address, U256};
+use core::{cmp::Ordering, fmt, ops::RangeInclusive, time::Duration};
 use num_enum::TryFromPrimitiveError;
 
 #[allow(unused_imports)]
 use alloc::string::String;
 // When adding a new chain:
 //   1. add new variant to the NamedChain enum;
-//   2. add extra information in the last `impl` block (explorer URLs, block time) when applicable;
+//   2. add an entry to `assets/chains.toml` with the chain's metadata (explorer URLs, block time,
+//      native currency) when applicable, and extra information in the last `impl` block;
 //   3. (optional) add aliases:
 //     - Strum (in kebab-case): `#[strum(to_string = "<main>", serialize = "<aliasX>", ...)]`
 //      `to_string = "<main>"` must be present and will be used in `Display`, `Serialize`
@@ -16,7 +24,7 @@ use alloc::string::String;
 //      Aliases are appended to the `Deserialize` implementation.
 //      More info: <https://serde.rs/variant-attrs.html>
 //     - Add a test at the bottom of the file
-//   4. run `cargo test --all-features` to update the JSON bindings and schema.
+//   4. run `cargo test --features serde,schema` to update the JSON bindings and schema.
 
 // We don't derive Serialize because it is manually implemented using AsRef<str> and it would break
 // a lot of things since Serialize is `kebab-case` vs Deserialize `snake_case`. This means that the
@@ -31,6 +39,7 @@ use alloc::string::String;
 #[derive(strum::EnumString)] // FromStr, TryFrom<&str>
 #[derive(strum::EnumIter)] // NamedChain::iter
 #[derive(strum::EnumCount)] // NamedChain::COUNT
+#[derive(strum::EnumMessage)] // NamedChain::get_serializations
 #[derive(num_enum::TryFromPrimitive)] // TryFrom<u64>
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -49,6 +58,7 @@ pub enum NamedChain {
     Goerli = 5,
     Kovan = 42,
     Holesky = 17000,
+    Hoodi = 560048,
     Sepolia = 11155111,
 
     #[cfg_attr(feature = "serde", serde(alias = "odyssey"))]
@@ -140,7 +150,11 @@ pub enum NamedChain {
     #[cfg_attr(feature = "serde", serde(alias = "xdai", alias = "gnosis", alias = "gnosis-chain"))]
     Gnosis = 100,
 
+    #[strum(to_string = "polygon", serialize = "matic")]
+    #[cfg_attr(feature = "serde", serde(alias = "matic"))]
     Polygon = 137,
+    /// Polygon's former testnet, deprecated since April 2024 in favor of
+    /// [`PolygonAmoy`](Self::PolygonAmoy).
     #[strum(to_string = "mumbai", serialize = "polygon-mumbai")]
     #[cfg_attr(feature = "serde", serde(alias = "mumbai", alias = "polygon-mumbai"))]
     PolygonMumbai = 80001,
@@ -164,6 +178,8 @@ pub enum NamedChain {
     )]
     PolygonZkEvmTestnet = 1442,
 
+    #[strum(to_string = "fantom", serialize = "ftm")]
+    #[cfg_attr(feature = "serde", serde(alias = "ftm"))]
     Fantom = 250,
     FantomTestnet = 4002,
 
@@ -202,6 +218,8 @@ pub enum NamedChain {
     FilecoinMainnet = 314,
     FilecoinCalibrationTestnet = 314159,
 
+    #[strum(to_string = "avalanche", serialize = "avax")]
+    #[cfg_attr(feature = "serde", serde(alias = "avax"))]
     Avalanche = 43114,
     #[strum(to_string = "fuji", serialize = "avalanche-fuji")]
     #[cfg_attr(feature = "serde", serde(alias = "fuji"))]
@@ -390,6 +408,8 @@ pub enum NamedChain {
     Bitlayer = 200901,
     Vana = 1480,
     Zeta = 7000,
+    #[strum(to_string = "kaia", serialize = "klaytn", serialize = "klay")]
+    #[cfg_attr(feature = "serde", serde(alias = "klaytn", alias = "klay"))]
     Kaia = 8217,
 
     #[strum(to_string = "unichain-sepolia")]
@@ -472,6 +492,13 @@ macro_rules! impl_try_from_numeric {
 
 impl_try_from_numeric!(u8 i8 u16 i16 u32 i32 usize isize);
 
+impl From<NamedChain> for U256 {
+    #[inline]
+    fn from(chain: NamedChain) -> Self {
+        chain.to_u256()
+    }
+}
+
 impl fmt::Display for NamedChain {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -479,6 +506,13 @@ impl fmt::Display for NamedChain {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for NamedChain {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.as_str())
+    }
+}
+
 impl AsRef<str> for NamedChain {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -493,6 +527,22 @@ impl PartialEq<u64> for NamedChain {
     }
 }
 
+/// Matches the chain's name and [aliases](NamedChain::names_and_aliases).
+impl PartialEq<str> for NamedChain {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        other.parse::<Self>().is_ok_and(|chain| chain == *self)
+    }
+}
+
+/// Matches the chain's name and [aliases](NamedChain::names_and_aliases).
+impl PartialEq<&str> for NamedChain {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
 impl PartialOrd<u64> for NamedChain {
     #[inline]
     fn partial_cmp(&self, other: &u64) -> Option<Ordering> {
@@ -530,8 +580,33 @@ impl alloy_rlp::Decodable for NamedChain {
     }
 }
 
+/// The error returned by [`NamedChain::from_str_strict`], [`NamedChain::try_from_id_strict`] and
+/// [`Chain::parse_foundry_chain_arg`](crate::Chain::parse_foundry_chain_arg).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StrictParseError {
+    /// The input is not a known chain.
+    Unknown,
+    /// The input is a [deprecated](NamedChain::is_deprecated) network.
+    Deprecated(NamedChain),
+}
+
+impl fmt::Display for StrictParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown => f.write_str("unknown chain"),
+            Self::Deprecated(chain) => {
+                write!(f, "`{chain}` is a deprecated network and is no longer supported")
+            }
+        }
+    }
+}
+
+impl core::error::Error for StrictParseError {}
+
 // NB: all utility functions *should* be explicitly exhaustive (not use `_` matcher) so we don't
-//     forget to update them when adding a new `NamedChain` variant.
+//     forget to update them when adding a new `NamedChain` variant. The generated metadata tables
+//     in `data` are the exception; their completeness is checked against `assets/chains.toml`.
 #[allow(clippy::match_like_matches_macro)]
 #[deny(unreachable_patterns, unused_variables)]
 impl NamedChain {
@@ -541,11 +616,259 @@ impl NamedChain {
         self.into()
     }
 
+    /// Returns the string representation of the chain, in a `const` context.
+    ///
+    /// Equivalent to [`as_str`](Self::as_str).
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        data::name(self)
+    }
+
+    /// Returns the ID of the given chain, in a `const` context.
+    ///
+    /// Equivalent to `chain as u64`. See also the [`assert_chain_id!`](crate::assert_chain_id)
+    /// macro.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// const BASE_ID: u64 = NamedChain::id_of(NamedChain::Base);
+    /// assert_eq!(BASE_ID, 8453);
+    /// ```
+    #[inline]
+    pub const fn id_of(chain: Self) -> u64 {
+        chain as u64
+    }
+
+    /// Returns a snapshot of the chain's metadata, usable in `const` contexts.
+    ///
+    /// See also the [`chain_metadata!`](crate::chain_metadata) macro.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{ChainMetadata, NamedChain};
+    ///
+    /// const BASE: ChainMetadata = NamedChain::Base.metadata();
+    /// assert_eq!(BASE.id, 8453);
+    /// assert!(!BASE.is_testnet);
+    /// ```
+    #[inline]
+    pub const fn metadata(self) -> ChainMetadata {
+        ChainMetadata::new(self)
+    }
+
+    /// Returns all of the chain's metadata in a single struct, e.g. to cache it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{NamedChain, RollupType};
+    ///
+    /// let profile = NamedChain::Base.profile();
+    /// assert_eq!(profile.metadata.id, 8453);
+    /// assert_eq!(profile.rollup_type, Some(RollupType::Optimistic));
+    /// assert_eq!(profile.settlement_chain, Some(NamedChain::Mainnet));
+    /// ```
+    #[inline]
+    pub const fn profile(self) -> ChainProfile {
+        ChainProfile::new(self)
+    }
+
+    /// Returns the IDs of all the known chains, sorted in ascending order.
+    ///
+    /// Since the enum is `#[non_exhaustive]`, new IDs may be added in any release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// const IDS: &[u64] = NamedChain::all_ids();
+    /// assert_eq!(IDS[0], 1);
+    /// assert!(IDS.windows(2).all(|w| w[0] < w[1]));
+    /// ```
+    #[inline]
+    pub const fn all_ids() -> &'static [u64] {
+        data::IDS
+    }
+
+    /// Returns `true` if `id` is the ID of a known chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// const KNOWN: bool = NamedChain::is_known_id(8453);
+    /// assert!(KNOWN);
+    /// assert!(!NamedChain::is_known_id(0));
+    /// ```
+    pub const fn is_known_id(id: u64) -> bool {
+        let ids = data::IDS;
+        let (mut lo, mut hi) = (0, ids.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if ids[mid] == id {
+                return true;
+            } else if ids[mid] < id {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        false
+    }
+
+    /// Returns an iterator over all chains, sorted by chain ID.
+    ///
+    /// Unlike [`iter`](strum::IntoEnumIterator::iter), whose order follows the enum declaration
+    /// and may change between releases, this order is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let mut chains = NamedChain::iter_sorted_by_id();
+    /// assert_eq!(chains.next(), Some(NamedChain::Mainnet));
+    /// assert_eq!(chains.next(), Some(NamedChain::Morden));
+    /// ```
+    pub fn iter_sorted_by_id() -> alloc::vec::IntoIter<Self> {
+        let mut chains = <Self as strum::VariantArray>::VARIANTS.to_vec();
+        chains.sort_unstable_by_key(|&chain| chain as u64);
+        chains.into_iter()
+    }
+
+    /// Returns an iterator over all chains, sorted by name.
+    ///
+    /// Unlike [`iter`](strum::IntoEnumIterator::iter), whose order follows the enum declaration
+    /// and may change between releases, this order is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let mut chains = NamedChain::iter_sorted_by_name();
+    /// assert_eq!(chains.next(), Some(NamedChain::Acala));
+    /// ```
+    pub fn iter_sorted_by_name() -> alloc::vec::IntoIter<Self> {
+        let mut chains = <Self as strum::VariantArray>::VARIANTS.to_vec();
+        chains.sort_unstable_by_key(|chain| chain.as_str());
+        chains.into_iter()
+    }
+
+    /// Searches chains by name, for autocompletion.
+    ///
+    /// Matches the query case-insensitively against each chain's name, its aliases and its
+    /// internal (enum variant) name. Results are ranked by exact matches first, then prefix
+    /// matches, then substring matches; ties are broken by name length, then by name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let results = NamedChain::search("arb");
+    /// assert_eq!(results[0], NamedChain::Arbitrum);
+    /// assert!(results.contains(&NamedChain::ArbitrumSepolia));
+    ///
+    /// assert_eq!(NamedChain::search("bsc")[0], NamedChain::BinanceSmartChain);
+    /// ```
+    pub fn search(query: &str) -> alloc::vec::Vec<Self> {
+        use strum::{EnumMessage, IntoEnumIterator};
+
+        let query = query.to_ascii_lowercase();
+        if query.is_empty() {
+            return alloc::vec::Vec::new();
+        }
+
+        let rank = |name: &str| {
+            let name = name.to_ascii_lowercase();
+            if name == query {
+                Some(0)
+            } else if name.starts_with(&query) {
+                Some(1)
+            } else if name.contains(&query) {
+                Some(2)
+            } else {
+                None
+            }
+        };
+
+        let mut results: alloc::vec::Vec<_> = Self::iter()
+            .filter_map(|chain| {
+                let internal_id = format!("{chain:?}");
+                let rank = chain
+                    .get_serializations()
+                    .iter()
+                    .copied()
+                    .chain(core::iter::once(internal_id.as_str()))
+                    .filter_map(rank)
+                    .min()?;
+                Some((rank, chain))
+            })
+            .collect();
+        results.sort_unstable_by(|(a_rank, a), (b_rank, b)| {
+            (a_rank, a.as_str().len(), a.as_str()).cmp(&(b_rank, b.as_str().len(), b.as_str()))
+        });
+        results.into_iter().map(|(_, chain)| chain).collect()
+    }
+
+    /// Returns the chain ID as a [`U256`], as carried in some RPC types.
+    #[inline]
+    pub const fn to_u256(self) -> U256 {
+        U256::from_limbs([self as u64, 0, 0, 0])
+    }
+
+    /// Returns the named chain with the given ID carried as a [`U256`], if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::U256;
+    ///
+    /// assert_eq!(NamedChain::from_u256(U256::from(8453)), Some(NamedChain::Base));
+    /// assert_eq!(NamedChain::from_u256(U256::from(u64::MAX) + U256::from(1)), None);
+    /// assert_eq!(NamedChain::Base.to_u256(), U256::from(8453));
+    /// ```
+    #[inline]
+    pub fn from_u256(id: U256) -> Option<Self> {
+        Self::try_from(u64::try_from(id).ok()?).ok()
+    }
+
+    /// Returns every chain name and alias accepted by [`FromStr`](core::str::FromStr), paired
+    /// with the chain it parses to and sorted by name.
+    ///
+    /// Intended for generating shell completion scripts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let names = NamedChain::names_and_aliases();
+    /// assert!(names.contains(&("mainnet", NamedChain::Mainnet)));
+    /// assert!(names.contains(&("bsc", NamedChain::BinanceSmartChain)));
+    /// assert!(names.contains(&("binance-smart-chain", NamedChain::BinanceSmartChain)));
+    /// ```
+    #[inline]
+    pub const fn names_and_aliases() -> &'static [(&'static str, Self)] {
+        data::NAMES
+    }
+
     /// Returns `true` if this chain is Ethereum or an Ethereum testnet.
     pub const fn is_ethereum(&self) -> bool {
         use NamedChain::*;
 
-        matches!(self, Mainnet | Morden | Ropsten | Rinkeby | Goerli | Kovan | Holesky | Sepolia)
+        matches!(
+            self,
+            Mainnet | Morden | Ropsten | Rinkeby | Goerli | Kovan | Holesky | Hoodi | Sepolia
+        )
     }
 
     /// Returns true if the chain contains Optimism configuration.
@@ -591,1232 +914,7960 @@ impl NamedChain {
         matches!(self, Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumSepolia | ArbitrumNova)
     }
 
-    /// Returns the chain's average blocktime, if applicable.
-    ///
-    /// It can be beneficial to know the average blocktime to adjust the polling of an HTTP provider
-    /// for example.
-    ///
-    /// **Note:** this is not an accurate average, but is rather a sensible default derived from
-    /// blocktime charts such as [Etherscan's](https://etherscan.com/chart/blocktime)
-    /// or [Polygonscan's](https://polygonscan.com/chart/blocktime).
+    /// Returns the family of related chains this chain belongs to, for grouping chains in
+    /// pickers and menus.
     ///
     /// # Examples
     ///
     /// ```
-    /// use alloy_chains::NamedChain;
-    /// use std::time::Duration;
+    /// use alloy_chains::{ChainGroup, NamedChain};
     ///
-    /// assert_eq!(NamedChain::Mainnet.average_blocktime_hint(), Some(Duration::from_millis(12_000)),);
-    /// assert_eq!(NamedChain::Optimism.average_blocktime_hint(), Some(Duration::from_millis(2_000)),);
+    /// assert_eq!(NamedChain::Sepolia.parent_group(), Some(ChainGroup::Ethereum));
+    /// assert_eq!(NamedChain::Base.parent_group(), Some(ChainGroup::OpStack));
+    /// assert_eq!(NamedChain::Gnosis.parent_group(), None);
     /// ```
-    pub const fn average_blocktime_hint(self) -> Option<Duration> {
+    pub const fn parent_group(self) -> Option<ChainGroup> {
         use NamedChain::*;
 
-        Some(Duration::from_millis(match self {
-            Mainnet | Taiko | TaikoHekla => 12_000,
-
-            Arbitrum
-            | ArbitrumTestnet
-            | ArbitrumGoerli
-            | ArbitrumSepolia
+        Some(match self {
+            Mainnet | Morden | Ropsten | Rinkeby | Goerli | Kovan | Holesky | Hoodi | Sepolia => {
+                ChainGroup::Ethereum
+            }
+            Optimism | OptimismGoerli | OptimismKovan | OptimismSepolia | Base | BaseGoerli
+            | BaseSepolia | Fraxtal | FraxtalTestnet | Ink | InkSepolia | Mode | ModeSepolia
+            | Pgn | PgnSepolia | Zora | ZoraGoerli | ZoraSepolia | BlastSepolia | OpBNBMainnet
+            | OpBNBTestnet | SoneiumMinatoTestnet | Odyssey | World | WorldSepolia
+            | UnichainSepolia | HappychainTestnet => ChainGroup::OpStack,
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumSepolia | ArbitrumNova => {
+                ChainGroup::Arbitrum
+            }
+            Polygon | PolygonMumbai | PolygonAmoy | PolygonZkEvm | PolygonZkEvmTestnet => {
+                ChainGroup::Polygon
+            }
+            BinanceSmartChain | BinanceSmartChainTestnet => ChainGroup::Bnb,
+            ZkSync | ZkSyncTestnet => ChainGroup::ZkSync,
+            Bob
+            | BobSepolia
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
             | GravityAlphaMainnet
             | GravityAlphaTestnetSepolia
-            | Xai
-            | XaiSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
             | Syndr
             | SyndrSepolia
-            | ArbitrumNova
+            | Shimmer
+            | Blast
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | Viction
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
             | ApeChain
-            | Curtis => 260,
-
-            Optimism | OptimismGoerli | OptimismSepolia | Base | BaseGoerli | BaseSepolia
-            | Blast | BlastSepolia | Fraxtal | FraxtalTestnet | Zora | ZoraGoerli | ZoraSepolia
-            | Mantle | MantleSepolia | Mode | ModeSepolia | Pgn | PgnSepolia
-            | HappychainTestnet | SoneiumMinatoTestnet | Bob | BobSepolia => 2_000,
-
-            Ink | InkSepolia | Odyssey => 1_000,
-
-            Viction => 2_000,
-
-            Polygon | PolygonMumbai | PolygonAmoy => 2_100,
-
-            Acala | AcalaMandalaTestnet | AcalaTestnet | Karura | KaruraTestnet | Moonbeam
-            | Moonriver => 12_500,
-
-            BinanceSmartChain | BinanceSmartChainTestnet => 3_000,
-
-            Avalanche | AvalancheFuji => 2_000,
-
-            Fantom | FantomTestnet => 1_200,
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        })
+    }
 
-            Cronos | CronosTestnet | Canto | CantoTestnet => 5_700,
-
-            Evmos | EvmosTestnet => 1_900,
-
-            Aurora | AuroraTestnet => 1_100,
-
-            Oasis => 5_500,
-
-            Emerald | Darwinia | Crab | Koi => 6_000,
-
-            Dev | AnvilHardhat => 200,
-
-            Celo | CeloAlfajores | CeloBaklava => 5_000,
-
-            FilecoinCalibrationTestnet | FilecoinMainnet => 30_000,
-
-            Scroll | ScrollSepolia => 3_000,
-
-            Shimmer => 5_000,
-
-            Gnosis | Chiado => 5_000,
-
-            Elastos => 5_000,
-
-            Etherlink => 5_000,
-
-            EtherlinkTestnet => 5_000,
-
-            Degen => 600,
-
-            Cfx | CfxTestnet => 500,
-
-            OpBNBMainnet | OpBNBTestnet | AutonomysNovaTestnet => 1_000,
-
-            Ronin | RoninTestnet => 3_000,
-
-            Flare => 1_800,
-
-            FlareCoston2 => 2_500,
-
-            Pulsechain => 10000,
-            PulsechainTestnet => 10101,
-
-            Immutable | ImmutableTestnet => 2_000,
-
-            World | WorldSepolia => 2_000,
-
-            Iotex => 5_000,
-            Core => 3_000,
-            Merlin => 3_000,
-            Bitlayer => 3_000,
-            Vana => 6_000,
-            Zeta => 6_000,
-            Kaia => 1_000,
-
-            UnichainSepolia => 1_000,
+    /// Returns a weight for ordering chains in pickers and menus: lower weights should be shown
+    /// first, and ties broken by chain ID.
+    ///
+    /// The most used chains come first in a curated order, followed by the remaining chains.
+    /// Testnets always sort after mainnets, keeping the relative order of their mainnets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let mut chains = vec![NamedChain::Sepolia, NamedChain::Gnosis, NamedChain::Mainnet];
+    /// chains.sort_by_key(|&chain| (chain.ui_sort_weight(), chain as u64));
+    /// assert_eq!(chains, [NamedChain::Mainnet, NamedChain::Gnosis, NamedChain::Sepolia]);
+    /// ```
+    pub const fn ui_sort_weight(self) -> u16 {
+        use NamedChain::*;
 
-            BerachainBartio | BerachainArtio => 2_000,
+        const CURATED: &[NamedChain] = &[
+            Mainnet,
+            Sepolia,
+            Hoodi,
+            Holesky,
+            Arbitrum,
+            ArbitrumSepolia,
+            Optimism,
+            OptimismSepolia,
+            Base,
+            BaseSepolia,
+            Polygon,
+            PolygonAmoy,
+            BinanceSmartChain,
+            BinanceSmartChainTestnet,
+            Avalanche,
+            AvalancheFuji,
+            ZkSync,
+            ZkSyncTestnet,
+            Linea,
+            LineaSepolia,
+            Scroll,
+            ScrollSepolia,
+            Gnosis,
+            Chiado,
+            Blast,
+            BlastSepolia,
+            Mantle,
+            MantleSepolia,
+            ArbitrumNova,
+            Zora,
+            ZoraSepolia,
+            Celo,
+            CeloAlfajores,
+        ];
+        const UNLISTED: u16 = CURATED.len() as u16;
+        const TESTNET_OFFSET: u16 = 1_000;
+
+        let mut rank = UNLISTED;
+        let mut i = 0;
+        while i < CURATED.len() {
+            if CURATED[i] as u64 == self as u64 {
+                rank = i as u16;
+                break;
+            }
+            i += 1;
+        }
 
-            Morden | Ropsten | Rinkeby | Goerli | Kovan | Sepolia | Holesky | MantleTestnet
-            | Moonbase | MoonbeamDev | OptimismKovan | Poa | Sokol | Rsk | EmeraldTestnet
-            | Boba | ZkSync | ZkSyncTestnet | PolygonZkEvm | PolygonZkEvmTestnet | Metis
-            | Linea | LineaGoerli | LineaSepolia | KakarotSepolia | SonicTestnet | Treasure
-            | TreasureTopaz => return None,
-        }))
+        if self.is_testnet() {
+            rank + TESTNET_OFFSET
+        } else {
+            rank
+        }
     }
 
-    /// Returns whether the chain implements EIP-1559 (with the type 2 EIP-2718 transaction type).
+    /// Returns the chain's rollup security model, or `None` if it is not a rollup or has not been
+    /// classified.
     ///
     /// # Examples
     ///
     /// ```
-    /// use alloy_chains::NamedChain;
+    /// use alloy_chains::{NamedChain, RollupType};
     ///
-    /// assert!(!NamedChain::Mainnet.is_legacy());
-    /// assert!(NamedChain::Celo.is_legacy());
+    /// assert_eq!(NamedChain::Base.rollup_type(), Some(RollupType::Optimistic));
+    /// assert_eq!(NamedChain::ZkSync.rollup_type(), Some(RollupType::Validity));
+    /// assert_eq!(NamedChain::Mainnet.rollup_type(), None);
     /// ```
-    pub const fn is_legacy(self) -> bool {
+    pub const fn rollup_type(self) -> Option<RollupType> {
         use NamedChain::*;
 
-        match self {
-            // Known legacy chains / non EIP-1559 compliant.
-            Acala
-            | AcalaMandalaTestnet
-            | AcalaTestnet
-            | ArbitrumTestnet
-            | BinanceSmartChain
-            | BinanceSmartChainTestnet
-            | Boba
-            | Celo
-            | CeloAlfajores
-            | CeloBaklava
-            | Elastos
-            | Emerald
-            | EmeraldTestnet
-            | Fantom
-            | FantomTestnet
-            | Karura
-            | KaruraTestnet
-            | MantleTestnet
-            | Metis
-            | Oasis
-            | OptimismKovan
-            | PolygonZkEvm
-            | PolygonZkEvmTestnet
-            | Ronin
-            | RoninTestnet
-            | Rsk
-            | Shimmer
-            | Treasure
-            | TreasureTopaz
-            | Viction
-            | ZkSync
-            | ZkSyncTestnet => true,
+        Some(match self {
+            Optimism | OptimismGoerli | OptimismKovan | OptimismSepolia | Base | BaseGoerli
+            | BaseSepolia | Fraxtal | FraxtalTestnet | Ink | InkSepolia | Mode | ModeSepolia
+            | Pgn | PgnSepolia | Zora | ZoraGoerli | ZoraSepolia | BlastSepolia | OpBNBMainnet
+            | OpBNBTestnet | SoneiumMinatoTestnet | Odyssey | World | WorldSepolia
+            | UnichainSepolia | HappychainTestnet | Arbitrum | ArbitrumTestnet | ArbitrumGoerli
+            | ArbitrumSepolia | ArbitrumNova | Bob | BobSepolia | Blast | Boba => {
+                RollupType::Optimistic
+            }
+
+            ZkSync | ZkSyncTestnet | Linea | LineaGoerli | LineaSepolia | Scroll
+            | ScrollSepolia | PolygonZkEvm | PolygonZkEvmTestnet => RollupType::Validity,
 
-            // Known EIP-1559 chains.
             Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
             | Goerli
-            | Sepolia
+            | Kovan
             | Holesky
-            | Odyssey
-            | Base
-            | BaseGoerli
-            | BaseSepolia
-            | Blast
-            | BlastSepolia
-            | Fraxtal
-            | FraxtalTestnet
-            | Optimism
-            | OptimismGoerli
-            | OptimismSepolia
-            | Bob
-            | BobSepolia
+            | Hoodi
+            | Sepolia
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
             | Polygon
             | PolygonMumbai
             | PolygonAmoy
-            | Avalanche
-            | AvalancheFuji
-            | Arbitrum
-            | ArbitrumGoerli
-            | ArbitrumSepolia
-            | ArbitrumNova
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
             | GravityAlphaMainnet
             | GravityAlphaTestnetSepolia
-            | Xai
-            | XaiSepolia
-            | HappychainTestnet
-            | Syndr
-            | SyndrSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
             | FilecoinMainnet
-            | Linea
-            | LineaGoerli
-            | LineaSepolia
             | FilecoinCalibrationTestnet
-            | Gnosis
-            | Chiado
-            | Zora
-            | ZoraGoerli
-            | ZoraSepolia
-            | Ink
-            | InkSepolia
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
             | Mantle
+            | MantleTestnet
             | MantleSepolia
-            | Mode
-            | ModeSepolia
-            | Pgn
-            | PgnSepolia
+            | Xai
+            | XaiSepolia
+            | Viction
+            | Elastos
             | KakarotSepolia
             | Etherlink
             | EtherlinkTestnet
             | Degen
-            | OpBNBMainnet
-            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
             | Taiko
             | TaikoHekla
             | AutonomysNovaTestnet
             | Flare
             | FlareCoston2
-            | Scroll
-            | ScrollSepolia
-            | Darwinia
-            | Cfx
-            | CfxTestnet
-            | Crab
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
             | Pulsechain
             | PulsechainTestnet
-            | Koi
             | Immutable
             | ImmutableTestnet
-            | SoneiumMinatoTestnet
-            | World
-            | WorldSepolia
-            | UnichainSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
             | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
             | BerachainBartio
-            | BerachainArtio
-            | Curtis => false,
-
-            // Unknown / not applicable, default to false for backwards compatibility.
-            Dev | AnvilHardhat | Morden | Ropsten | Rinkeby | Cronos | CronosTestnet | Kovan
-            | Sokol | Poa | Moonbeam | MoonbeamDev | Moonriver | Moonbase | Evmos
-            | EvmosTestnet | Aurora | AuroraTestnet | Canto | CantoTestnet | Iotex | Core
-            | Merlin | Bitlayer | SonicTestnet | Vana | Zeta | Kaia => false,
-        }
+            | BerachainArtio => return None,
+        })
     }
 
-    /// Returns whether the chain supports the [Shanghai hardfork][ref].
+    /// Returns the chain a rollup settles on, i.e. posts its data and proofs to.
     ///
-    /// [ref]: https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/shanghai.md
-    pub const fn supports_shanghai(self) -> bool {
+    /// Always returns `None` for chains whose [`rollup_type`](Self::rollup_type) is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Base.settlement_chain(), Some(NamedChain::Mainnet));
+    /// assert_eq!(NamedChain::BaseSepolia.settlement_chain(), Some(NamedChain::Sepolia));
+    /// assert_eq!(NamedChain::OpBNBMainnet.settlement_chain(), Some(NamedChain::BinanceSmartChain));
+    /// assert_eq!(NamedChain::Mainnet.settlement_chain(), None);
+    /// ```
+    pub const fn settlement_chain(self) -> Option<Self> {
         use NamedChain::*;
 
-        matches!(
-            self,
+        Some(match self {
+            OpBNBMainnet => BinanceSmartChain,
+            OpBNBTestnet => BinanceSmartChainTestnet,
+
+            OptimismKovan => Kovan,
+            ArbitrumTestnet => Rinkeby,
+            OptimismGoerli | ArbitrumGoerli | BaseGoerli | ZoraGoerli | LineaGoerli
+            | PolygonZkEvmTestnet => Goerli,
+            FraxtalTestnet => Holesky,
+
+            Odyssey | OptimismSepolia | BobSepolia | ArbitrumSepolia | ScrollSepolia
+            | BaseSepolia | InkSepolia | BlastSepolia | LineaSepolia | ZkSyncTestnet
+            | HappychainTestnet | ZoraSepolia | PgnSepolia | ModeSepolia | SoneiumMinatoTestnet
+            | WorldSepolia | UnichainSepolia => Sepolia,
+
+            Optimism | Bob | Arbitrum | ArbitrumNova | Scroll | PolygonZkEvm | Boba | Base
+            | Ink | Fraxtal | Blast | Linea | ZkSync | Zora | Pgn | Mode | World => Mainnet,
+
             Mainnet
-                | Goerli
-                | Sepolia
-                | Holesky
-                | AnvilHardhat
-                | Optimism
-                | OptimismGoerli
-                | OptimismSepolia
-                | Bob
-                | BobSepolia
-                | Odyssey
-                | Base
-                | BaseGoerli
-                | BaseSepolia
-                | Blast
-                | BlastSepolia
-                | Fraxtal
-                | FraxtalTestnet
-                | Ink
-                | InkSepolia
-                | Gnosis
-                | Chiado
-                | ZoraSepolia
-                | Mantle
-                | MantleSepolia
-                | Mode
-                | ModeSepolia
-                | PolygonMumbai
-                | Polygon
-                | Arbitrum
-                | ArbitrumNova
-                | ArbitrumSepolia
-                | GravityAlphaMainnet
-                | GravityAlphaTestnetSepolia
-                | Xai
-                | XaiSepolia
-                | Syndr
-                | SyndrSepolia
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | Viction
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        })
+    }
+
+    /// Returns how the chain accounts for gas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{GasSemantics, NamedChain};
+    ///
+    /// assert_eq!(NamedChain::Mainnet.gas_semantics(), GasSemantics::Standard);
+    /// assert_eq!(NamedChain::Base.gas_semantics(), GasSemantics::SeparateL1Fee);
+    /// assert_eq!(NamedChain::Arbitrum.gas_semantics(), GasSemantics::L1InclusiveGas);
+    /// assert_eq!(NamedChain::ZkSync.gas_semantics(), GasSemantics::PubdataGas);
+    /// ```
+    pub const fn gas_semantics(self) -> GasSemantics {
+        use NamedChain::*;
+
+        match self {
+            Optimism | OptimismGoerli | OptimismKovan | OptimismSepolia | Base | BaseGoerli
+            | BaseSepolia | Fraxtal | FraxtalTestnet | Ink | InkSepolia | Mode | ModeSepolia
+            | Pgn | PgnSepolia | Zora | ZoraGoerli | ZoraSepolia | BlastSepolia | OpBNBMainnet
+            | OpBNBTestnet | SoneiumMinatoTestnet | Odyssey | World | WorldSepolia
+            | UnichainSepolia | HappychainTestnet | Bob | BobSepolia | Blast | Scroll
+            | ScrollSepolia => GasSemantics::SeparateL1Fee,
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumSepolia | ArbitrumNova => {
+                GasSemantics::L1InclusiveGas
+            }
+            ZkSync | ZkSyncTestnet => GasSemantics::PubdataGas,
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | Viction
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => GasSemantics::Standard,
+        }
+    }
+
+    /// Returns how the chain's mempool handles pending transactions, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{MempoolProfile, NamedChain};
+    ///
+    /// assert_eq!(NamedChain::Mainnet.mempool_profile(), Some(MempoolProfile::GETH));
+    ///
+    /// let base = NamedChain::Base.mempool_profile().unwrap();
+    /// assert!(!base.public && base.supports_replacement());
+    ///
+    /// let arbitrum = NamedChain::Arbitrum.mempool_profile().unwrap();
+    /// assert!(!arbitrum.supports_replacement() && !arbitrum.queues_nonce_gaps);
+    /// ```
+    pub const fn mempool_profile(self) -> Option<MempoolProfile> {
+        use NamedChain::*;
+
+        match self {
+            // OP-stack sequencers run op-geth's transaction pool, but do not gossip it.
+            Optimism | OptimismGoerli | OptimismKovan | OptimismSepolia | Base | BaseGoerli
+            | BaseSepolia | Fraxtal | FraxtalTestnet | Ink | InkSepolia | Mode | ModeSepolia
+            | Pgn | PgnSepolia | Zora | ZoraGoerli | ZoraSepolia | BlastSepolia | OpBNBMainnet
+            | OpBNBTestnet | SoneiumMinatoTestnet | Odyssey | World | WorldSepolia
+            | UnichainSepolia | HappychainTestnet => {
+                Some(MempoolProfile { public: false, ..MempoolProfile::GETH })
+            }
+            // The Arbitrum sequencer orders transactions first come, first served, and only holds
+            // nonce-gapped transactions for a short time.
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumSepolia | ArbitrumNova => {
+                Some(MempoolProfile {
+                    public: false,
+                    replacement_fee_bump: None,
+                    queues_nonce_gaps: false,
+                })
+            }
+            Mainnet
+            | Sepolia
+            | Holesky
+            | Hoodi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Polygon
+            | PolygonAmoy => Some(MempoolProfile::GETH),
+            Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Bob
+            | BobSepolia
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | PolygonMumbai
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Blast
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | Viction
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => None,
+        }
+    }
+
+    /// Returns how addresses are checksummed when displayed on the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{AddressFormat, NamedChain};
+    ///
+    /// assert_eq!(NamedChain::Mainnet.address_format(), AddressFormat::Eip55);
+    /// assert_eq!(NamedChain::Rsk.address_format(), AddressFormat::Eip1191 { chain_id: 30 });
+    /// ```
+    pub const fn address_format(self) -> AddressFormat {
+        use NamedChain::*;
+
+        match self {
+            Rsk => AddressFormat::Eip1191 { chain_id: self as u64 },
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | Optimism
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Base
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => AddressFormat::Eip55,
+        }
+    }
+
+    /// Returns the minimum fee increase, in percent, for a transaction to replace a pending one
+    /// with the same nonce, if known.
+    ///
+    /// Resubmission logic should bump both the max fee and the priority fee by at least this
+    /// much. Returns `None` if replacement is not supported or the chain's
+    /// [mempool profile](Self::mempool_profile) is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Mainnet.replacement_fee_bump_percent(), Some(10));
+    /// assert_eq!(NamedChain::Arbitrum.replacement_fee_bump_percent(), None);
+    /// ```
+    pub const fn replacement_fee_bump_percent(self) -> Option<u8> {
+        match self.mempool_profile() {
+            Some(profile) => profile.replacement_fee_bump,
+            None => None,
+        }
+    }
+
+    /// Returns the [superchain registry](https://github.com/ethereum-optimism/superchain-registry)
+    /// level of this OP-stack chain.
+    ///
+    /// Returns `None` for chains that are not OP-stack chains, are not in the registry, or have
+    /// not been curated yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{NamedChain, SuperchainLevel};
+    ///
+    /// assert_eq!(NamedChain::Base.superchain_level(), Some(SuperchainLevel::Standard));
+    /// assert_eq!(NamedChain::Fraxtal.superchain_level(), Some(SuperchainLevel::Frontier));
+    /// assert_eq!(NamedChain::Arbitrum.superchain_level(), None);
+    /// ```
+    pub const fn superchain_level(self) -> Option<SuperchainLevel> {
+        use NamedChain::*;
+
+        match self {
+            Optimism | OptimismSepolia | Base | BaseSepolia | Zora | ZoraSepolia | Mode
+            | ModeSepolia | Ink | InkSepolia | UnichainSepolia | SoneiumMinatoTestnet | World => {
+                Some(SuperchainLevel::Standard)
+            }
+            Fraxtal | WorldSepolia => Some(SuperchainLevel::Frontier),
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | OptimismKovan
+            | OptimismGoerli
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | BaseGoerli
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | ZoraGoerli
+            | Pgn
+            | PgnSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => None,
+        }
+    }
+
+    /// Returns the proof system of a validity rollup, if known.
+    ///
+    /// Always returns `None` for chains whose [`rollup_type`](Self::rollup_type) is not
+    /// [`RollupType::Validity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{NamedChain, ProofSystem};
+    ///
+    /// assert_eq!(NamedChain::ZkSync.proof_system(), Some(ProofSystem::Boojum));
+    /// assert_eq!(NamedChain::Linea.proof_system(), Some(ProofSystem::Plonk));
+    /// assert_eq!(NamedChain::Optimism.proof_system(), None);
+    /// ```
+    pub const fn proof_system(self) -> Option<ProofSystem> {
+        use NamedChain::*;
+
+        Some(match self {
+            ZkSync | ZkSyncTestnet => ProofSystem::Boojum,
+            Linea | LineaGoerli | LineaSepolia => ProofSystem::Plonk,
+            PolygonZkEvm | PolygonZkEvmTestnet => ProofSystem::Stark,
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | Optimism
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Base
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        })
+    }
+
+    /// Returns a hint for the time between a validity rollup's block being produced and its
+    /// proof being verified on L1.
+    ///
+    /// **Note:** proving pipelines are batched and operator-dependent; this is a sensible default,
+    /// not a guarantee.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(NamedChain::ZkSync.proof_latency_hint(), Some(Duration::from_secs(3600)));
+    /// assert_eq!(NamedChain::Optimism.proof_latency_hint(), None);
+    /// ```
+    pub const fn proof_latency_hint(self) -> Option<Duration> {
+        match data::proof_latency_ms(self) {
+            Some(ms) => Some(Duration::from_millis(ms)),
+            None => None,
+        }
+    }
+
+    /// Returns the chain's average blocktime, if applicable.
+    ///
+    /// It can be beneficial to know the average blocktime to adjust the polling of an HTTP provider
+    /// for example.
+    ///
+    /// **Note:** this is not an accurate average, but is rather a sensible default derived from
+    /// blocktime charts such as [Etherscan's](https://etherscan.com/chart/blocktime)
+    /// or [Polygonscan's](https://polygonscan.com/chart/blocktime).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(NamedChain::Mainnet.average_blocktime_hint(), Some(Duration::from_millis(12_000)),);
+    /// assert_eq!(NamedChain::Optimism.average_blocktime_hint(), Some(Duration::from_millis(2_000)),);
+    /// ```
+    pub const fn average_blocktime_hint(self) -> Option<Duration> {
+        match data::average_blocktime_ms(self) {
+            Some(ms) => Some(Duration::from_millis(ms)),
+            None => None,
+        }
+    }
+
+    /// Returns the chain's average blocktime, or why it is missing.
+    ///
+    /// See [`average_blocktime_hint`](Self::average_blocktime_hint).
+    pub const fn try_average_blocktime_hint(self) -> Result<Duration, MetadataError> {
+        match self.average_blocktime_hint() {
+            Some(block_time) => Ok(block_time),
+            None => Err(self.missing_metadata()),
+        }
+    }
+
+    /// Returns a hint for the time it takes for a block to become final, i.e. irreversible under
+    /// the chain's consensus rules.
+    ///
+    /// **Note:** like [`average_blocktime_hint`](Self::average_blocktime_hint), this is a
+    /// sensible default, not a guarantee.
+    ///
+    /// Returns `None` for optimistic rollups, whose L1 settlement is bounded by their challenge
+    /// period rather than by consensus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(NamedChain::Mainnet.time_to_finality_hint(), Some(Duration::from_secs(768)));
+    /// assert_eq!(NamedChain::Optimism.time_to_finality_hint(), None);
+    /// ```
+    pub const fn time_to_finality_hint(self) -> Option<Duration> {
+        match data::time_to_finality_ms(self) {
+            Some(ms) => Some(Duration::from_millis(ms)),
+            None => None,
+        }
+    }
+
+    /// Returns the average blocktime of the chain a rollup
+    /// [settles on](Self::settlement_chain), if applicable.
+    ///
+    /// Unlike [`average_blocktime_hint`](Self::average_blocktime_hint), which is the rollup's own
+    /// cadence of soft confirmations, this is the cadence at which its batches can land on L1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(NamedChain::Base.average_blocktime_hint(), Some(Duration::from_millis(2_000)));
+    /// assert_eq!(NamedChain::Base.settlement_block_time(), Some(Duration::from_millis(12_000)));
+    /// assert_eq!(NamedChain::Mainnet.settlement_block_time(), None);
+    /// ```
+    pub const fn settlement_block_time(self) -> Option<Duration> {
+        match self.settlement_chain() {
+            Some(chain) => chain.average_blocktime_hint(),
+            None => None,
+        }
+    }
+
+    /// Returns a hint for the chain's block gas limit.
+    ///
+    /// **Note:** block gas limits are voted on or configured by the chain's operators and change
+    /// over time; this is a sensible default, not a guarantee.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Optimism.block_gas_limit_hint(), Some(40_000_000));
+    /// assert_eq!(NamedChain::Moonbeam.block_gas_limit_hint(), None);
+    /// ```
+    pub const fn block_gas_limit_hint(self) -> Option<u64> {
+        data::block_gas_limit(self)
+    }
+
+    /// Returns a hint for the largest block range accepted in a single `eth_getLogs` request by
+    /// the chain's public RPC endpoints.
+    ///
+    /// Indexers can use this to chunk log queries up front. `None` means no chain-wide limit is
+    /// known; the limits of the RPC provider in use still apply.
+    ///
+    /// **Note:** limits are set by RPC operators and change over time; this is a sensible default,
+    /// not a guarantee.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Avalanche.max_block_range_for_get_logs(), Some(2_048));
+    /// assert_eq!(NamedChain::Mainnet.max_block_range_for_get_logs(), None);
+    /// ```
+    pub const fn max_block_range_for_get_logs(self) -> Option<u64> {
+        data::get_logs_max_block_range(self)
+    }
+
+    /// Returns a hint for the maximum size in bytes of a non-blob transaction accepted by the
+    /// chain's mempool or sequencer.
+    ///
+    /// This is the 128 KiB transaction pool limit of geth and its forks. Returns `None` for chains
+    /// that have not been curated yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Mainnet.max_tx_size_hint(), Some(131_072));
+    /// assert_eq!(NamedChain::Arbitrum.max_tx_size_hint(), None);
+    /// ```
+    pub const fn max_tx_size_hint(self) -> Option<u64> {
+        use NamedChain::*;
+
+        match self {
+            Polygon | PolygonAmoy | BinanceSmartChain | BinanceSmartChainTestnet => Some(131_072),
+            Mainnet | Morden | Ropsten | Rinkeby | Goerli | Kovan | Holesky | Hoodi | Sepolia
+            | Optimism | OptimismGoerli | OptimismKovan | OptimismSepolia | Base | BaseGoerli
+            | BaseSepolia | Fraxtal | FraxtalTestnet | Ink | InkSepolia | Mode | ModeSepolia
+            | Pgn | PgnSepolia | Zora | ZoraGoerli | ZoraSepolia | BlastSepolia | OpBNBMainnet
+            | OpBNBTestnet | SoneiumMinatoTestnet | Odyssey | World | WorldSepolia
+            | UnichainSepolia | HappychainTestnet => Some(131_072),
+            Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | PolygonMumbai
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Blast
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | Viction
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => None,
+        }
+    }
+
+    /// Returns a hint for the maximum size in bytes of a transaction's calldata.
+    ///
+    /// Arbitrum's sequencer limits calldata to 95,000 bytes; elsewhere, calldata is only bounded
+    /// by the [transaction size](Self::max_tx_size_hint).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Arbitrum.max_calldata_hint(), Some(95_000));
+    /// assert_eq!(NamedChain::Mainnet.max_calldata_hint(), Some(131_072));
+    /// ```
+    pub const fn max_calldata_hint(self) -> Option<u64> {
+        if self.is_arbitrum() {
+            return Some(95_000);
+        }
+        self.max_tx_size_hint()
+    }
+
+    /// Returns whether the chain implements EIP-1559 (with the type 2 EIP-2718 transaction type).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert!(!NamedChain::Mainnet.is_legacy());
+    /// assert!(NamedChain::Celo.is_legacy());
+    /// ```
+    pub const fn is_legacy(self) -> bool {
+        use NamedChain::*;
+
+        match self {
+            // Known legacy chains / non EIP-1559 compliant.
+            Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | ArbitrumTestnet
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Boba
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Elastos
+            | Emerald
+            | EmeraldTestnet
+            | Fantom
+            | FantomTestnet
+            | Karura
+            | KaruraTestnet
+            | MantleTestnet
+            | Metis
+            | Oasis
+            | OptimismKovan
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Ronin
+            | RoninTestnet
+            | Rsk
+            | Shimmer
+            | Treasure
+            | TreasureTopaz
+            | Viction
+            | ZkSync
+            | ZkSyncTestnet => true,
+
+            // Known EIP-1559 chains.
+            Mainnet
+            | Goerli
+            | Sepolia
+            | Holesky
+            | Hoodi
+            | Odyssey
+            | Base
+            | BaseGoerli
+            | BaseSepolia
+            | Blast
+            | BlastSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Optimism
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | Avalanche
+            | AvalancheFuji
+            | Arbitrum
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Syndr
+            | SyndrSepolia
+            | FilecoinMainnet
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | FilecoinCalibrationTestnet
+            | Gnosis
+            | Chiado
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Ink
+            | InkSepolia
+            | Mantle
+            | MantleSepolia
+            | Mode
+            | ModeSepolia
+            | Pgn
+            | PgnSepolia
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Scroll
+            | ScrollSepolia
+            | Darwinia
+            | Cfx
+            | CfxTestnet
+            | Crab
+            | Pulsechain
+            | PulsechainTestnet
+            | Koi
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | UnichainSepolia
+            | ApeChain
+            | BerachainBartio
+            | BerachainArtio
+            | Curtis => false,
+
+            // Unknown / not applicable, default to false for backwards compatibility.
+            Dev | AnvilHardhat | Morden | Ropsten | Rinkeby | Cronos | CronosTestnet | Kovan
+            | Sokol | Poa | Moonbeam | MoonbeamDev | Moonriver | Moonbase | Evmos
+            | EvmosTestnet | Aurora | AuroraTestnet | Canto | CantoTestnet | Iotex | Core
+            | Merlin | Bitlayer | SonicTestnet | Vana | Zeta | Kaia => false,
+        }
+    }
+
+    /// Returns whether the chain supports the [Shanghai hardfork][ref].
+    ///
+    /// [ref]: https://github.com/ethereum/execution-specs/blob/master/network-upgrades/mainnet-upgrades/shanghai.md
+    pub const fn supports_shanghai(self) -> bool {
+        use NamedChain::*;
+
+        matches!(
+            self,
+            Mainnet
+                | Goerli
+                | Sepolia
+                | Holesky
+                | Hoodi
+                | AnvilHardhat
+                | Optimism
+                | OptimismGoerli
+                | OptimismSepolia
+                | Bob
+                | BobSepolia
+                | Odyssey
+                | Base
+                | BaseGoerli
+                | BaseSepolia
+                | Blast
+                | BlastSepolia
+                | Fraxtal
+                | FraxtalTestnet
+                | Ink
+                | InkSepolia
+                | Gnosis
+                | Chiado
+                | ZoraSepolia
+                | Mantle
+                | MantleSepolia
+                | Mode
+                | ModeSepolia
+                | PolygonMumbai
+                | Polygon
+                | Arbitrum
+                | ArbitrumNova
+                | ArbitrumSepolia
+                | GravityAlphaMainnet
+                | GravityAlphaTestnetSepolia
+                | Xai
+                | XaiSepolia
+                | Syndr
+                | SyndrSepolia
+                | Etherlink
+                | EtherlinkTestnet
+                | Scroll
+                | ScrollSepolia
+                | HappychainTestnet
+                | Shimmer
+                | OpBNBMainnet
+                | OpBNBTestnet
+                | KakarotSepolia
+                | Taiko
+                | TaikoHekla
+                | Avalanche
+                | AvalancheFuji
+                | AutonomysNovaTestnet
+                | Acala
+                | AcalaMandalaTestnet
+                | AcalaTestnet
+                | Karura
+                | KaruraTestnet
+                | Darwinia
+                | Crab
+                | Cfx
+                | CfxTestnet
+                | Pulsechain
+                | PulsechainTestnet
+                | Koi
+                | Immutable
+                | ImmutableTestnet
+                | SoneiumMinatoTestnet
+                | World
+                | WorldSepolia
+                | Iotex
+                | UnichainSepolia
+                | ApeChain
+                | Curtis
+        )
+    }
+
+    /// Returns the date this chain's metadata was last reviewed against its documentation.
+    ///
+    /// Facts such as [`supports_shanghai`](Self::supports_shanghai) go stale as chains upgrade;
+    /// use this to gauge how much to trust them. Returns `None` if the chain's metadata has not
+    /// been reviewed since review dates started being tracked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{Date, NamedChain};
+    ///
+    /// assert!(NamedChain::Mainnet.metadata_reviewed_at().unwrap() >= Date::new(2026, 10, 16));
+    /// ```
+    pub const fn metadata_reviewed_at(self) -> Option<Date> {
+        data::reviewed_at(self)
+    }
+
+    /// Returns why a metadata lookup for this chain returned no value.
+    ///
+    /// Missing metadata is only known not to apply for local development chains and for chains
+    /// whose metadata has been [reviewed](Self::metadata_reviewed_at), as long as it was not
+    /// stripped by the `slim` feature.
+    const fn missing_metadata(self) -> MetadataError {
+        if self.is_deprecated() {
+            MetadataError::Deprecated
+        } else if matches!(self, Self::Dev | Self::AnvilHardhat)
+            || (!cfg!(feature = "slim") && self.metadata_reviewed_at().is_some())
+        {
+            MetadataError::NotApplicable
+        } else {
+            MetadataError::Unknown
+        }
+    }
+
+    /// Returns every chain with its [review date](Self::metadata_reviewed_at), stalest first.
+    ///
+    /// Chains that have never been reviewed come first, in enum order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{Date, NamedChain};
+    ///
+    /// let cutoff = Date::new(2026, 1, 1);
+    /// let stale = NamedChain::metadata_review_report()
+    ///     .into_iter()
+    ///     .take_while(|(_, reviewed_at)| reviewed_at.map_or(true, |date| date < cutoff))
+    ///     .count();
+    /// assert!(stale > 0);
+    /// ```
+    pub fn metadata_review_report() -> alloc::vec::Vec<(Self, Option<Date>)> {
+        use strum::IntoEnumIterator;
+
+        let mut report: alloc::vec::Vec<_> =
+            Self::iter().map(|chain| (chain, chain.metadata_reviewed_at())).collect();
+        report.sort_by_key(|&(_, reviewed_at)| reviewed_at);
+        report
+    }
+
+    /// Returns the latest Ethereum network upgrade whose EVM changes are known to be active on
+    /// this chain.
+    ///
+    /// This is exact for Ethereum and for OP-stack chains with a known
+    /// [upgrade schedule](Self::op_hardforks), and a lower bound otherwise: chains that
+    /// [support Shanghai](Self::supports_shanghai) return at least [`Hardfork::Shanghai`].
+    /// Returns `None` if unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{hardfork::Hardfork, NamedChain};
+    ///
+    /// assert_eq!(NamedChain::Mainnet.latest_evm_hardfork(), Some(Hardfork::Osaka));
+    /// assert_eq!(NamedChain::Base.latest_evm_hardfork(), Some(Hardfork::Prague));
+    /// assert_eq!(NamedChain::Celo.latest_evm_hardfork(), None);
+    /// ```
+    pub const fn latest_evm_hardfork(self) -> Option<Hardfork> {
+        match self.evm_hardfork() {
+            Some((fork, _)) => Some(fork),
+            None => None,
+        }
+    }
+
+    /// Returns the solc `evm_version` to compile contracts deployed to this chain with.
+    ///
+    /// This is the chain's [latest EVM network upgrade](Self::latest_evm_hardfork), defaulting to
+    /// `paris`, the last version before `PUSH0`, if unknown. Newer versions may require a recent
+    /// solc release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Base.recommended_evm_version(), "prague");
+    /// assert_eq!(NamedChain::Arbitrum.recommended_evm_version(), "shanghai");
+    /// assert_eq!(NamedChain::Celo.recommended_evm_version(), "paris");
+    /// ```
+    pub const fn recommended_evm_version(self) -> &'static str {
+        match self.latest_evm_hardfork() {
+            Some(Hardfork::Osaka) => "osaka",
+            Some(Hardfork::Prague) => "prague",
+            Some(Hardfork::Cancun) => "cancun",
+            Some(Hardfork::Shanghai) => "shanghai",
+            Some(Hardfork::London) => "london",
+            _ => "paris",
+        }
+    }
+
+    /// Returns the latest EVM network upgrade active on this chain, and whether it is exact
+    /// rather than a lower bound.
+    const fn evm_hardfork(self) -> Option<(Hardfork, bool)> {
+        use NamedChain::*;
+
+        if matches!(self, Mainnet | Sepolia | Holesky | Hoodi) {
+            return Some((Hardfork::Osaka, true));
+        }
+        if let Some(fork) = self.latest_op_fork() {
+            let fork = match fork {
+                Hardfork::Isthmus => Hardfork::Prague,
+                Hardfork::Ecotone | Hardfork::Fjord | Hardfork::Granite | Hardfork::Holocene => {
+                    Hardfork::Cancun
+                }
+                Hardfork::Canyon | Hardfork::Delta => Hardfork::Shanghai,
+                _ => Hardfork::London,
+            };
+            return Some((fork, true));
+        }
+        if self.supports_shanghai() {
+            return Some((Hardfork::Shanghai, false));
+        }
+        None
+    }
+
+    /// Returns whether this chain supports the given opcode, based on its
+    /// [EVM network upgrade](Self::latest_evm_hardfork).
+    ///
+    /// Returns `None` if unknown, e.g. when only a lower bound of the chain's upgrades is known
+    /// and the opcode was introduced later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{NamedChain, Opcode, OpcodeSupport};
+    ///
+    /// assert_eq!(NamedChain::Base.opcode_support(Opcode::TStore), Some(OpcodeSupport::Supported));
+    /// assert_eq!(NamedChain::Base.opcode_support(Opcode::Clz), Some(OpcodeSupport::Unsupported));
+    /// assert_eq!(
+    ///     NamedChain::Mainnet.opcode_support(Opcode::SelfDestruct),
+    ///     Some(OpcodeSupport::Restricted)
+    /// );
+    /// assert_eq!(NamedChain::Celo.opcode_support(Opcode::Push0), None);
+    /// assert_eq!(
+    ///     NamedChain::Scroll.opcode_support(Opcode::SelfDestruct),
+    ///     Some(OpcodeSupport::Unsupported)
+    /// );
+    /// ```
+    pub const fn opcode_support(self, opcode: Opcode) -> Option<OpcodeSupport> {
+        use NamedChain::*;
+
+        // `SELFDESTRUCT` reverts on Scroll and is rejected by the ZKsync Era compilers.
+        if matches!(opcode, Opcode::SelfDestruct)
+            && matches!(self, Scroll | ScrollSepolia | ZkSync | ZkSyncTestnet)
+        {
+            return Some(OpcodeSupport::Unsupported);
+        }
+
+        let Some((fork, exact)) = self.evm_hardfork() else { return None };
+        let fork = fork as u8;
+
+        if matches!(opcode, Opcode::SelfDestruct) {
+            return if fork >= Hardfork::Cancun as u8 {
+                Some(OpcodeSupport::Restricted)
+            } else if exact {
+                Some(OpcodeSupport::Supported)
+            } else {
+                None
+            };
+        }
+        if fork >= opcode.introduced_in() as u8 {
+            Some(OpcodeSupport::Supported)
+        } else if exact {
+            Some(OpcodeSupport::Unsupported)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the chain's capabilities, as per the curated tables.
+    ///
+    /// See [`ChainCapabilities::merge`] to override them with capabilities observed at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let mainnet = NamedChain::Mainnet.capabilities();
+    /// assert_eq!(mainnet.push0, Some(true));
+    /// assert_eq!(mainnet.blobs, Some(true));
+    ///
+    /// let base = NamedChain::Base.capabilities();
+    /// assert_eq!(base.eip1559, Some(true));
+    /// assert_eq!(base.blobs, Some(false));
+    /// ```
+    pub const fn capabilities(self) -> ChainCapabilities {
+        let push0 = match self.opcode_support(Opcode::Push0) {
+            Some(support) => Some(matches!(support, OpcodeSupport::Supported)),
+            None => None,
+        };
+        let blobs = if self.rollup_type().is_some() {
+            Some(false)
+        } else if self.has_beacon_chain()
+            && matches!(self.opcode_support(Opcode::BlobHash), Some(OpcodeSupport::Supported))
+        {
+            Some(true)
+        } else {
+            None
+        };
+        let block_tags = if self.has_beacon_chain() || self.is_optimism() || self.is_arbitrum() {
+            Some(true)
+        } else {
+            None
+        };
+        ChainCapabilities {
+            push0,
+            eip1559: Some(!self.is_legacy()),
+            blobs,
+            aa: Some(self.aa_support()),
+            block_tags,
+        }
+    }
+
+    /// Returns the chain's gas refund rules, if known.
+    ///
+    /// These follow the chain's [EVM network upgrade](Self::latest_evm_hardfork), except on
+    /// chains whose operator computes refunds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{GasRefundRule, NamedChain};
+    ///
+    /// assert_eq!(NamedChain::Mainnet.gas_refund_rule(), Some(GasRefundRule::Eip3529));
+    /// assert_eq!(NamedChain::ZkSync.gas_refund_rule(), Some(GasRefundRule::Operator));
+    /// assert_eq!(NamedChain::Celo.gas_refund_rule(), None);
+    /// ```
+    pub const fn gas_refund_rule(self) -> Option<GasRefundRule> {
+        use NamedChain::*;
+
+        if matches!(self, ZkSync | ZkSyncTestnet) {
+            return Some(GasRefundRule::Operator);
+        }
+        match self.evm_hardfork() {
+            Some((fork, _)) if fork as u8 >= Hardfork::London as u8 => Some(GasRefundRule::Eip3529),
+            Some((_, true)) => Some(GasRefundRule::Eip2200),
+            _ => None,
+        }
+    }
+
+    #[doc(hidden)]
+    #[deprecated(since = "0.1.3", note = "use `supports_shanghai` instead")]
+    pub const fn supports_push0(self) -> bool {
+        self.supports_shanghai()
+    }
+
+    /// Returns `true` if the network has been shut down or is no longer supported by its
+    /// operators, e.g. the Goerli-based testnets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert!(NamedChain::Goerli.is_deprecated());
+    /// assert!(NamedChain::PolygonMumbai.is_deprecated());
+    /// assert!(!NamedChain::Sepolia.is_deprecated());
+    /// ```
+    pub const fn is_deprecated(self) -> bool {
+        use NamedChain::*;
+
+        matches!(
+            self,
+            Morden
+                | Ropsten
+                | Rinkeby
+                | Goerli
+                | Kovan
+                | Holesky
+                | OptimismKovan
+                | OptimismGoerli
+                | ArbitrumTestnet
+                | ArbitrumGoerli
+                | BaseGoerli
+                | ZoraGoerli
+                | LineaGoerli
+                | MantleTestnet
+                | PolygonMumbai
+                | PolygonZkEvmTestnet
+                | Sokol
+                | BerachainArtio
+                | BerachainBartio
+        )
+    }
+
+    /// Returns the date the testnet was, or is announced to be, shut down by its operators, so
+    /// that tooling can warn users to migrate before its endpoints disappear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{Date, NamedChain};
+    ///
+    /// # #[cfg(not(feature = "mainnets-only"))]
+    /// assert_eq!(NamedChain::PolygonMumbai.sunset_date(), Some(Date::new(2024, 4, 13)));
+    /// assert_eq!(NamedChain::Sepolia.sunset_date(), None);
+    /// ```
+    pub const fn sunset_date(self) -> Option<Date> {
+        data::sunset_date(self)
+    }
+
+    /// Parses a chain name or alias like [`FromStr`](core::str::FromStr), rejecting
+    /// [deprecated](Self::is_deprecated) networks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{NamedChain, StrictParseError};
+    ///
+    /// assert_eq!(NamedChain::from_str_strict("sepolia"), Ok(NamedChain::Sepolia));
+    /// assert_eq!(
+    ///     NamedChain::from_str_strict("goerli"),
+    ///     Err(StrictParseError::Deprecated(NamedChain::Goerli))
+    /// );
+    /// assert_eq!(NamedChain::from_str_strict("unknown"), Err(StrictParseError::Unknown));
+    /// ```
+    pub fn from_str_strict(s: &str) -> Result<Self, StrictParseError> {
+        s.parse::<Self>().map_err(|_| StrictParseError::Unknown)?.check_deprecated()
+    }
+
+    /// Converts a chain ID like [`TryFrom<u64>`], rejecting [deprecated](Self::is_deprecated)
+    /// networks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{NamedChain, StrictParseError};
+    ///
+    /// assert_eq!(NamedChain::try_from_id_strict(1), Ok(NamedChain::Mainnet));
+    /// assert_eq!(NamedChain::try_from_id_strict(5), Err(StrictParseError::Deprecated(NamedChain::Goerli)));
+    /// ```
+    pub fn try_from_id_strict(id: u64) -> Result<Self, StrictParseError> {
+        Self::try_from(id).map_err(|_| StrictParseError::Unknown)?.check_deprecated()
+    }
+
+    const fn check_deprecated(self) -> Result<Self, StrictParseError> {
+        if self.is_deprecated() {
+            Err(StrictParseError::Deprecated(self))
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Returns whether the chain is a testnet.
+    pub const fn is_testnet(self) -> bool {
+        use NamedChain::*;
+
+        match self {
+            // Ethereum testnets.
+            Goerli | Holesky | Hoodi | Kovan | Sepolia | Morden | Ropsten | Rinkeby => true,
+
+            // Other testnets.
+            ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumTestnet
+            | SyndrSepolia
+            | AuroraTestnet
+            | AvalancheFuji
+            | Odyssey
+            | BaseGoerli
+            | BaseSepolia
+            | BlastSepolia
+            | BinanceSmartChainTestnet
+            | CantoTestnet
+            | CronosTestnet
+            | CeloAlfajores
+            | CeloBaklava
+            | EmeraldTestnet
+            | EvmosTestnet
+            | FantomTestnet
+            | FilecoinCalibrationTestnet
+            | FraxtalTestnet
+            | HappychainTestnet
+            | LineaGoerli
+            | LineaSepolia
+            | InkSepolia
+            | MantleTestnet
+            | MantleSepolia
+            | MoonbeamDev
+            | OptimismGoerli
+            | OptimismKovan
+            | OptimismSepolia
+            | BobSepolia
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvmTestnet
+            | ScrollSepolia
+            | Shimmer
+            | ZkSyncTestnet
+            | ZoraGoerli
+            | ZoraSepolia
+            | ModeSepolia
+            | PgnSepolia
+            | KakarotSepolia
+            | EtherlinkTestnet
+            | OpBNBTestnet
+            | RoninTestnet
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | FlareCoston2
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | KaruraTestnet
+            | CfxTestnet
+            | PulsechainTestnet
+            | GravityAlphaTestnetSepolia
+            | XaiSepolia
+            | Koi
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | WorldSepolia
+            | UnichainSepolia
+            | Curtis
+            | TreasureTopaz
+            | SonicTestnet
+            | BerachainBartio
+            | BerachainArtio => true,
+
+            // Dev chains.
+            Dev | AnvilHardhat => true,
+
+            // Mainnets.
+            Mainnet | Optimism | Arbitrum | ArbitrumNova | Blast | Syndr | Cronos | Rsk
+            | BinanceSmartChain | Poa | Sokol | Scroll | Metis | Gnosis | Polygon
+            | PolygonZkEvm | Fantom | Moonbeam | Moonriver | Moonbase | Evmos | Chiado | Oasis
+            | Emerald | FilecoinMainnet | Avalanche | Celo | Aurora | Canto | Boba | Base
+            | Fraxtal | Ink | Linea | ZkSync | Mantle | GravityAlphaMainnet | Xai | Zora | Pgn
+            | Mode | Viction | Elastos | Degen | OpBNBMainnet | Ronin | Taiko | Flare | Acala
+            | Karura | Darwinia | Cfx | Crab | Pulsechain | Etherlink | Immutable | World
+            | Iotex | Core | Merlin | Bitlayer | ApeChain | Vana | Zeta | Kaia | Treasure | Bob => {
+                false
+            }
+        }
+    }
+
+    /// Returns the chain's native currency.
+    ///
+    /// Always returns `None` if the `slim` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// # #[cfg(not(feature = "slim"))]
+    /// # {
+    /// let currency = NamedChain::Mainnet.native_currency().unwrap();
+    /// assert_eq!((currency.name, currency.symbol, currency.decimals), ("Ether", "ETH", 18));
+    /// # }
+    /// ```
+    pub const fn native_currency(self) -> Option<NativeCurrency> {
+        data::native_currency(self)
+    }
+
+    /// Returns the chain's native currency, or why it is missing.
+    ///
+    /// See [`native_currency`](Self::native_currency).
+    pub const fn try_native_currency(self) -> Result<NativeCurrency, MetadataError> {
+        match self.native_currency() {
+            Some(currency) => Ok(currency),
+            None => Err(self.missing_metadata()),
+        }
+    }
+
+    /// Returns the symbol of the chain's native currency.
+    ///
+    /// See [`native_currency`](Self::native_currency).
+    pub const fn native_currency_symbol(self) -> Option<&'static str> {
+        match self.native_currency() {
+            Some(currency) => Some(currency.symbol),
+            None => None,
+        }
+    }
+
+    /// Returns the full name of the chain's native currency.
+    ///
+    /// See [`native_currency`](Self::native_currency).
+    pub const fn native_currency_name(self) -> Option<&'static str> {
+        match self.native_currency() {
+            Some(currency) => Some(currency.name),
+            None => None,
+        }
+    }
+
+    /// Returns the number of decimals of the chain's native currency.
+    ///
+    /// Use this instead of assuming 18 decimals when formatting values or computing fees.
+    ///
+    /// See [`native_currency`](Self::native_currency).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// # #[cfg(not(feature = "slim"))]
+    /// # {
+    /// assert_eq!(NamedChain::Mainnet.native_currency_decimals(), Some(18));
+    /// # }
+    /// assert_eq!(NamedChain::AnvilHardhat.native_currency_decimals(), None);
+    /// ```
+    pub const fn native_currency_decimals(self) -> Option<u8> {
+        match self.native_currency() {
+            Some(currency) => Some(currency.decimals),
+            None => None,
+        }
+    }
+
+    /// Returns all chains whose native currency has the given symbol, in enum order.
+    ///
+    /// Symbols are matched exactly; testnet currencies have their own symbols (see
+    /// [`is_valueless_currency`](Self::is_valueless_currency)).
+    ///
+    /// Always returns an empty vector if the `slim` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// # #[cfg(not(feature = "slim"))]
+    /// # {
+    /// let chains = NamedChain::chains_using_currency("BNB");
+    /// assert_eq!(chains, [NamedChain::BinanceSmartChain, NamedChain::OpBNBMainnet]);
+    /// # }
+    /// ```
+    pub fn chains_using_currency(symbol: &str) -> alloc::vec::Vec<Self> {
+        use strum::IntoEnumIterator;
+
+        Self::iter().filter(|chain| chain.native_currency_symbol() == Some(symbol)).collect()
+    }
+
+    /// Returns whether the chain's native currency has no market value, e.g. on testnets and
+    /// development chains.
+    ///
+    /// Testnet currencies use distinct symbols (e.g. `SepoliaETH`, `tBNB`) so that they are not
+    /// mistaken for their mainnet counterparts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert!(NamedChain::Sepolia.is_valueless_currency());
+    /// assert!(!NamedChain::Mainnet.is_valueless_currency());
+    /// ```
+    pub const fn is_valueless_currency(self) -> bool {
+        // Shimmer is IOTA's staging network, but SMR is traded.
+        self.is_testnet() && !matches!(self, Self::Shimmer)
+    }
+
+    /// Returns the asset the chain's native currency is pegged to, if the chain pays gas in a
+    /// stablecoin.
+    ///
+    /// This allows converting fees to USD without a price oracle. Testnet currencies are
+    /// [valueless](Self::is_valueless_currency) and never pegged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{GasTokenPeg, NamedChain};
+    ///
+    /// let peg = NamedChain::Gnosis.gas_token_peg().unwrap();
+    /// assert_eq!((peg.asset, peg.usd_value), ("DAI", GasTokenPeg::ONE_USD));
+    /// assert_eq!(NamedChain::Mainnet.gas_token_peg(), None);
+    /// ```
+    pub const fn gas_token_peg(self) -> Option<GasTokenPeg> {
+        use NamedChain::*;
+
+        match self {
+            // xDAI is DAI bridged from Ethereum.
+            Gnosis => Some(GasTokenPeg { asset: "DAI", usd_value: GasTokenPeg::ONE_USD }),
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | Optimism
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Base
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => None,
+        }
+    }
+
+    /// Returns the chain's blockchain explorer and its API (Etherscan and Etherscan-like) URLs.
+    ///
+    /// Returns `(API_URL, BASE_URL)`.
+    ///
+    /// All URLs have no trailing `/`
+    ///
+    /// This is the chain's primary explorer; see [`explorers`](Self::explorers) for all known
+    /// explorer instances.
+    ///
+    /// Always returns `None` if the `slim` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// # #[cfg(not(feature = "slim"))]
+    /// # {
+    /// assert_eq!(
+    ///     NamedChain::Mainnet.etherscan_urls(),
+    ///     Some(("https://api.etherscan.io/api", "https://etherscan.io"))
+    /// );
+    /// assert_eq!(
+    ///     NamedChain::Avalanche.etherscan_urls(),
+    ///     Some(("https://api.snowtrace.io/api", "https://snowtrace.io"))
+    /// );
+    /// assert_eq!(NamedChain::AnvilHardhat.etherscan_urls(), None);
+    /// # }
+    /// ```
+    pub const fn etherscan_urls(self) -> Option<(&'static str, &'static str)> {
+        match self.explorers() {
+            [primary, ..] => Some((primary.api_url, primary.base_url)),
+            [] => None,
+        }
+    }
+
+    /// Returns the chain's blockchain explorer and its API URLs, or why they are missing.
+    ///
+    /// This lets UIs hide the explorer link of chains that have none, while reporting chains that
+    /// have not been curated yet.
+    ///
+    /// See [`etherscan_urls`](Self::etherscan_urls).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{MetadataError, NamedChain};
+    ///
+    /// assert_eq!(NamedChain::AnvilHardhat.try_etherscan_urls(), Err(MetadataError::NotApplicable));
+    /// assert_eq!(NamedChain::Morden.try_etherscan_urls(), Err(MetadataError::Deprecated));
+    /// ```
+    pub const fn try_etherscan_urls(self) -> Result<(&'static str, &'static str), MetadataError> {
+        match self.etherscan_urls() {
+            Some(urls) => Ok(urls),
+            None => Err(self.missing_metadata()),
+        }
+    }
+
+    /// Returns the chain whose block explorer serves the given URL, e.g. a transaction or address
+    /// link pasted by a user.
+    ///
+    /// The URL's host is matched case-insensitively, ignoring any `www.` prefix and port,
+    /// against the base URLs of every chain's [`explorers`](Self::explorers). A scheme is
+    /// optional.
+    ///
+    /// Always returns `None` if the `slim` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// # #[cfg(not(feature = "slim"))]
+    /// # {
+    /// assert_eq!(
+    ///     NamedChain::from_explorer_url("https://basescan.org/tx/0x1234"),
+    ///     Some(NamedChain::Base)
+    /// );
+    /// # #[cfg(not(feature = "mainnets-only"))]
+    /// assert_eq!(
+    ///     NamedChain::from_explorer_url("sepolia.etherscan.io/address/0x1234"),
+    ///     Some(NamedChain::Sepolia)
+    /// );
+    /// assert_eq!(NamedChain::from_explorer_url("https://example.com/tx/0x1234"), None);
+    /// # }
+    /// ```
+    pub fn from_explorer_url(url: &str) -> Option<Self> {
+        use strum::IntoEnumIterator;
+
+        /// Splits a URL into its host, without `www.` or port, and the rest.
+        fn split(url: &str) -> (&str, &str) {
+            let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+            let (host, rest) = url.split_at(url.find(['/', '?', '#']).unwrap_or(url.len()));
+            let host = host.split(':').next().unwrap_or_default();
+            let host = host.strip_prefix("www.").unwrap_or(host);
+            (host, rest)
+        }
+
+        let (host, rest) = split(url.trim());
+        if host.is_empty() {
+            return None;
+        }
+        Self::iter().find(|chain| {
+            chain.explorers().iter().any(|explorer| {
+                let (base_host, base_path) = split(explorer.base_url);
+                base_host.eq_ignore_ascii_case(host)
+                    && rest.strip_prefix(base_path).is_some_and(|path| {
+                        base_path.is_empty() || path.is_empty() || path.starts_with(['/', '?', '#'])
+                    })
+            })
+        })
+    }
+
+    /// Returns the family of the chain's primary block explorer, which determines its API flavor.
+    ///
+    /// This is the kind of the first of [`explorers`](Self::explorers), and lets verification
+    /// logic branch on the explorer family without parsing
+    /// [`etherscan_urls`](Self::etherscan_urls).
+    ///
+    /// Always returns `None` if the `slim` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{ExplorerKind, NamedChain};
+    /// # #[cfg(not(feature = "slim"))]
+    /// # {
+    /// assert_eq!(NamedChain::Mainnet.explorer_family(), Some(ExplorerKind::Etherscan));
+    /// assert_eq!(NamedChain::Avalanche.explorer_family(), Some(ExplorerKind::Routescan));
+    /// assert_eq!(NamedChain::AnvilHardhat.explorer_family(), None);
+    /// # }
+    /// ```
+    pub const fn explorer_family(self) -> Option<ExplorerKind> {
+        match self.explorers() {
+            [primary, ..] => Some(primary.kind),
+            [] => None,
+        }
+    }
+
+    /// Returns a hint for the number of API requests per second allowed on the free tier of the
+    /// chain's primary explorer.
+    ///
+    /// Verification and scraping tools can use this to throttle themselves instead of running
+    /// into rate limits. See [`ExplorerInfo::rate_limit_hint`].
+    ///
+    /// Always returns `None` if the `slim` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// # #[cfg(not(feature = "slim"))]
+    /// # {
+    /// assert_eq!(NamedChain::Mainnet.etherscan_rate_limit_hint(), Some(3));
+    /// # }
+    /// assert_eq!(NamedChain::AnvilHardhat.etherscan_rate_limit_hint(), None);
+    /// ```
+    pub const fn etherscan_rate_limit_hint(self) -> Option<u32> {
+        match self.explorers() {
+            [primary, ..] => primary.rate_limit_hint,
+            [] => None,
+        }
+    }
+
+    /// Returns all known block explorer instances for the chain, ordered by priority.
+    ///
+    /// The first entry is the chain's primary explorer, as returned by
+    /// [`etherscan_urls`](Self::etherscan_urls). The rest can be used as fallbacks.
+    ///
+    /// Always returns an empty slice if the `slim` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{ExplorerKind, NamedChain};
+    /// # #[cfg(not(feature = "slim"))]
+    /// # {
+    /// let explorers = NamedChain::Base.explorers();
+    /// assert_eq!(explorers[0].kind, ExplorerKind::Etherscan);
+    /// assert_eq!(explorers[1].base_url, "https://base.blockscout.com");
+    /// assert!(NamedChain::AnvilHardhat.explorers().is_empty());
+    /// # }
+    /// ```
+    pub const fn explorers(self) -> &'static [ExplorerInfo] {
+        data::explorers(self)
+    }
+
+    /// Returns the chain's blockchain explorer's API key environment variable's default name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Mainnet.etherscan_api_key_name(), Some("ETHERSCAN_API_KEY"));
+    /// assert_eq!(NamedChain::AnvilHardhat.etherscan_api_key_name(), None);
+    /// ```
+    pub const fn etherscan_api_key_name(self) -> Option<&'static str> {
+        data::etherscan_api_key_name(self)
+    }
+
+    /// Returns the chain's blockchain explorer's API key, from the environment variable with the
+    /// name specified in [`etherscan_api_key_name`](NamedChain::etherscan_api_key_name).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let chain = NamedChain::Mainnet;
+    /// std::env::set_var(chain.etherscan_api_key_name().unwrap(), "KEY");
+    /// assert_eq!(chain.etherscan_api_key().as_deref(), Some("KEY"));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn etherscan_api_key(self) -> Option<String> {
+        self.etherscan_api_key_name().and_then(|name| std::env::var(name).ok())
+    }
+
+    /// Returns whether the chain has a proof-of-stake consensus layer (beacon chain).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert!(NamedChain::Mainnet.has_beacon_chain());
+    /// assert!(NamedChain::Gnosis.has_beacon_chain());
+    /// assert!(!NamedChain::Optimism.has_beacon_chain());
+    /// ```
+    pub const fn has_beacon_chain(self) -> bool {
+        use NamedChain::*;
+
+        matches!(self, Mainnet | Goerli | Sepolia | Holesky | Hoodi | Gnosis | Chiado)
+    }
+
+    /// Returns a well-known public [beacon API](https://ethereum.github.io/beacon-APIs) endpoint
+    /// for the chain's consensus layer, without a trailing `/`.
+    ///
+    /// Public endpoints are rate limited and may not serve historical blobs; they are meant for
+    /// discovery and light use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(
+    ///     NamedChain::Mainnet.beacon_api_url(),
+    ///     Some("https://ethereum-beacon-api.publicnode.com")
+    /// );
+    /// assert_eq!(NamedChain::Optimism.beacon_api_url(), None);
+    /// ```
+    pub const fn beacon_api_url(self) -> Option<&'static str> {
+        data::beacon_api_url(self)
+    }
+
+    /// Returns the URL of a canonical [token list](https://tokenlists.org) covering the chain.
+    ///
+    /// Token lists may cover several chains; filter their tokens by `chainId`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Mainnet.token_list_url(), Some("https://tokens.uniswap.org"));
+    /// assert_eq!(
+    ///     NamedChain::Base.token_list_url(),
+    ///     Some("https://static.optimism.io/optimism.tokenlist.json")
+    /// );
+    /// ```
+    pub const fn token_list_url(self) -> Option<&'static str> {
+        data::token_list_url(self)
+    }
+
+    /// Returns the URL template of the given hosted RPC provider's endpoint for this chain, if the
+    /// provider serves the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{NamedChain, RpcProvider};
+    ///
+    /// let url = NamedChain::Mainnet.rpc_url_template(RpcProvider::Infura).unwrap().fill("KEY");
+    /// assert_eq!(url, "https://mainnet.infura.io/v3/KEY");
+    /// assert_eq!(NamedChain::Mainnet.rpc_url_template(RpcProvider::QuickNode), None);
+    /// ```
+    pub const fn rpc_url_template(self, provider: RpcProvider) -> Option<RpcUrlTemplate> {
+        use NamedChain::*;
+
+        macro_rules! templates {
+            (
+                $prefix:literal, $suffix:literal;
+                $($chain:ident => $network:literal),*;
+                unsupported: $($unsupported:ident)|*
+            ) => {
+                match self {
+                    $($chain => concat!($prefix, $network, $suffix),)*
+                    $($unsupported)|* => return None,
+                }
+            };
+        }
+
+        let template = match provider {
+            RpcProvider::Alchemy => templates! {
+                "https://", ".g.alchemy.com/v2/{API_KEY}";
+                Mainnet => "eth-mainnet",
+                Sepolia => "eth-sepolia",
+                Holesky => "eth-holesky",
+                Optimism => "opt-mainnet",
+                OptimismSepolia => "opt-sepolia",
+                Arbitrum => "arb-mainnet",
+                ArbitrumSepolia => "arb-sepolia",
+                ArbitrumNova => "arbnova-mainnet",
+                Base => "base-mainnet",
+                BaseSepolia => "base-sepolia",
+                Polygon => "polygon-mainnet",
+                PolygonAmoy => "polygon-amoy",
+                ZkSync => "zksync-mainnet",
+                ZkSyncTestnet => "zksync-sepolia",
+                Linea => "linea-mainnet",
+                LineaSepolia => "linea-sepolia",
+                Scroll => "scroll-mainnet",
+                ScrollSepolia => "scroll-sepolia",
+                Blast => "blast-mainnet",
+                Zora => "zora-mainnet",
+                BinanceSmartChain => "bnb-mainnet",
+                Avalanche => "avax-mainnet",
+                Gnosis => "gnosis-mainnet";
+                unsupported: Morden
+                    | Ropsten
+                    | Rinkeby
+                    | Goerli
+                    | Kovan
+                    | Hoodi
+                    | Odyssey
+                    | OptimismKovan
+                    | OptimismGoerli
+                    | Bob
+                    | BobSepolia
+                    | ArbitrumTestnet
+                    | ArbitrumGoerli
+                    | Cronos
+                    | CronosTestnet
+                    | Rsk
+                    | Crab
+                    | Darwinia
+                    | Koi
+                    | BinanceSmartChainTestnet
+                    | Poa
+                    | Sokol
+                    | Metis
+                    | CfxTestnet
+                    | Cfx
+                    | PolygonMumbai
+                    | PolygonZkEvm
+                    | PolygonZkEvmTestnet
+                    | Fantom
+                    | FantomTestnet
+                    | Moonbeam
+                    | MoonbeamDev
+                    | Moonriver
+                    | Moonbase
+                    | Dev
+                    | AnvilHardhat
+                    | GravityAlphaMainnet
+                    | GravityAlphaTestnetSepolia
+                    | Evmos
+                    | EvmosTestnet
+                    | Chiado
+                    | Oasis
+                    | Emerald
+                    | EmeraldTestnet
+                    | FilecoinMainnet
+                    | FilecoinCalibrationTestnet
+                    | AvalancheFuji
+                    | Celo
+                    | CeloAlfajores
+                    | CeloBaklava
+                    | Aurora
+                    | AuroraTestnet
+                    | Canto
+                    | CantoTestnet
+                    | Boba
+                    | BaseGoerli
+                    | Syndr
+                    | SyndrSepolia
+                    | Shimmer
+                    | Ink
+                    | InkSepolia
+                    | Fraxtal
+                    | FraxtalTestnet
+                    | BlastSepolia
+                    | LineaGoerli
+                    | Mantle
+                    | MantleTestnet
+                    | MantleSepolia
+                    | Xai
+                    | XaiSepolia
+                    | HappychainTestnet
+                    | Viction
+                    | ZoraGoerli
+                    | ZoraSepolia
+                    | Pgn
+                    | PgnSepolia
+                    | Mode
+                    | ModeSepolia
+                    | Elastos
+                    | KakarotSepolia
+                    | Etherlink
+                    | EtherlinkTestnet
+                    | Degen
+                    | OpBNBMainnet
+                    | OpBNBTestnet
+                    | Ronin
+                    | RoninTestnet
+                    | Taiko
+                    | TaikoHekla
+                    | AutonomysNovaTestnet
+                    | Flare
+                    | FlareCoston2
+                    | Acala
+                    | AcalaMandalaTestnet
+                    | AcalaTestnet
+                    | Karura
+                    | KaruraTestnet
+                    | Pulsechain
+                    | PulsechainTestnet
+                    | Immutable
+                    | ImmutableTestnet
+                    | SoneiumMinatoTestnet
+                    | World
+                    | WorldSepolia
+                    | Iotex
+                    | Core
+                    | Merlin
+                    | Bitlayer
+                    | Vana
+                    | Zeta
+                    | Kaia
+                    | UnichainSepolia
+                    | ApeChain
+                    | Curtis
+                    | SonicTestnet
+                    | Treasure
+                    | TreasureTopaz
+                    | BerachainBartio
+                    | BerachainArtio
+            },
+            RpcProvider::Infura => templates! {
+                "https://", ".infura.io/v3/{API_KEY}";
+                Mainnet => "mainnet",
+                Sepolia => "sepolia",
+                Holesky => "holesky",
+                Optimism => "optimism-mainnet",
+                OptimismSepolia => "optimism-sepolia",
+                Arbitrum => "arbitrum-mainnet",
+                ArbitrumSepolia => "arbitrum-sepolia",
+                Base => "base-mainnet",
+                BaseSepolia => "base-sepolia",
+                Polygon => "polygon-mainnet",
+                PolygonAmoy => "polygon-amoy",
+                Linea => "linea-mainnet",
+                LineaSepolia => "linea-sepolia",
+                Avalanche => "avalanche-mainnet",
+                AvalancheFuji => "avalanche-fuji";
+                unsupported: Morden
+                    | Ropsten
+                    | Rinkeby
+                    | Goerli
+                    | Kovan
+                    | Hoodi
+                    | Odyssey
+                    | OptimismKovan
+                    | OptimismGoerli
+                    | Bob
+                    | BobSepolia
+                    | ArbitrumTestnet
+                    | ArbitrumGoerli
+                    | ArbitrumNova
+                    | Cronos
+                    | CronosTestnet
+                    | Rsk
+                    | Crab
+                    | Darwinia
+                    | Koi
+                    | BinanceSmartChain
+                    | BinanceSmartChainTestnet
+                    | Poa
+                    | Sokol
+                    | Scroll
+                    | ScrollSepolia
+                    | Metis
+                    | CfxTestnet
+                    | Cfx
+                    | Gnosis
+                    | PolygonMumbai
+                    | PolygonZkEvm
+                    | PolygonZkEvmTestnet
+                    | Fantom
+                    | FantomTestnet
+                    | Moonbeam
+                    | MoonbeamDev
+                    | Moonriver
+                    | Moonbase
+                    | Dev
+                    | AnvilHardhat
+                    | GravityAlphaMainnet
+                    | GravityAlphaTestnetSepolia
+                    | Evmos
+                    | EvmosTestnet
+                    | Chiado
+                    | Oasis
+                    | Emerald
+                    | EmeraldTestnet
+                    | FilecoinMainnet
+                    | FilecoinCalibrationTestnet
+                    | Celo
+                    | CeloAlfajores
+                    | CeloBaklava
+                    | Aurora
+                    | AuroraTestnet
+                    | Canto
+                    | CantoTestnet
+                    | Boba
+                    | BaseGoerli
+                    | Syndr
+                    | SyndrSepolia
+                    | Shimmer
+                    | Ink
+                    | InkSepolia
+                    | Fraxtal
+                    | FraxtalTestnet
+                    | Blast
+                    | BlastSepolia
+                    | LineaGoerli
+                    | ZkSync
+                    | ZkSyncTestnet
+                    | Mantle
+                    | MantleTestnet
+                    | MantleSepolia
+                    | Xai
+                    | XaiSepolia
+                    | HappychainTestnet
+                    | Viction
+                    | Zora
+                    | ZoraGoerli
+                    | ZoraSepolia
+                    | Pgn
+                    | PgnSepolia
+                    | Mode
+                    | ModeSepolia
+                    | Elastos
+                    | KakarotSepolia
+                    | Etherlink
+                    | EtherlinkTestnet
+                    | Degen
+                    | OpBNBMainnet
+                    | OpBNBTestnet
+                    | Ronin
+                    | RoninTestnet
+                    | Taiko
+                    | TaikoHekla
+                    | AutonomysNovaTestnet
+                    | Flare
+                    | FlareCoston2
+                    | Acala
+                    | AcalaMandalaTestnet
+                    | AcalaTestnet
+                    | Karura
+                    | KaruraTestnet
+                    | Pulsechain
+                    | PulsechainTestnet
+                    | Immutable
+                    | ImmutableTestnet
+                    | SoneiumMinatoTestnet
+                    | World
+                    | WorldSepolia
+                    | Iotex
+                    | Core
+                    | Merlin
+                    | Bitlayer
+                    | Vana
+                    | Zeta
+                    | Kaia
+                    | UnichainSepolia
+                    | ApeChain
+                    | Curtis
+                    | SonicTestnet
+                    | Treasure
+                    | TreasureTopaz
+                    | BerachainBartio
+                    | BerachainArtio
+            },
+            RpcProvider::QuickNode => return None,
+            RpcProvider::Ankr => templates! {
+                "https://rpc.ankr.com/", "/{API_KEY}";
+                Mainnet => "eth",
+                Sepolia => "eth_sepolia",
+                Holesky => "eth_holesky",
+                Optimism => "optimism",
+                Arbitrum => "arbitrum",
+                ArbitrumNova => "arbitrumnova",
+                Base => "base",
+                BaseSepolia => "base_sepolia",
+                Polygon => "polygon",
+                PolygonAmoy => "polygon_amoy",
+                PolygonZkEvm => "polygon_zkevm",
+                BinanceSmartChain => "bsc",
+                Avalanche => "avalanche",
+                AvalancheFuji => "avalanche_fuji",
+                Fantom => "fantom",
+                Gnosis => "gnosis",
+                Celo => "celo",
+                Linea => "linea",
+                Scroll => "scroll",
+                ZkSync => "zksync_era",
+                Blast => "blast",
+                Mantle => "mantle",
+                Moonbeam => "moonbeam";
+                unsupported: Morden
+                    | Ropsten
+                    | Rinkeby
+                    | Goerli
+                    | Kovan
+                    | Hoodi
+                    | Odyssey
+                    | OptimismKovan
+                    | OptimismGoerli
+                    | OptimismSepolia
+                    | Bob
+                    | BobSepolia
+                    | ArbitrumTestnet
+                    | ArbitrumGoerli
+                    | ArbitrumSepolia
+                    | Cronos
+                    | CronosTestnet
+                    | Rsk
+                    | Crab
+                    | Darwinia
+                    | Koi
+                    | BinanceSmartChainTestnet
+                    | Poa
+                    | Sokol
+                    | ScrollSepolia
+                    | Metis
+                    | CfxTestnet
+                    | Cfx
+                    | PolygonMumbai
+                    | PolygonZkEvmTestnet
+                    | FantomTestnet
+                    | MoonbeamDev
+                    | Moonriver
+                    | Moonbase
+                    | Dev
+                    | AnvilHardhat
+                    | GravityAlphaMainnet
+                    | GravityAlphaTestnetSepolia
+                    | Evmos
+                    | EvmosTestnet
+                    | Chiado
+                    | Oasis
+                    | Emerald
+                    | EmeraldTestnet
+                    | FilecoinMainnet
+                    | FilecoinCalibrationTestnet
+                    | CeloAlfajores
+                    | CeloBaklava
+                    | Aurora
+                    | AuroraTestnet
+                    | Canto
+                    | CantoTestnet
+                    | Boba
+                    | BaseGoerli
+                    | Syndr
+                    | SyndrSepolia
+                    | Shimmer
+                    | Ink
+                    | InkSepolia
+                    | Fraxtal
+                    | FraxtalTestnet
+                    | BlastSepolia
+                    | LineaGoerli
+                    | LineaSepolia
+                    | ZkSyncTestnet
+                    | MantleTestnet
+                    | MantleSepolia
+                    | Xai
+                    | XaiSepolia
+                    | HappychainTestnet
+                    | Viction
+                    | Zora
+                    | ZoraGoerli
+                    | ZoraSepolia
+                    | Pgn
+                    | PgnSepolia
+                    | Mode
+                    | ModeSepolia
+                    | Elastos
+                    | KakarotSepolia
+                    | Etherlink
+                    | EtherlinkTestnet
+                    | Degen
+                    | OpBNBMainnet
+                    | OpBNBTestnet
+                    | Ronin
+                    | RoninTestnet
+                    | Taiko
+                    | TaikoHekla
+                    | AutonomysNovaTestnet
+                    | Flare
+                    | FlareCoston2
+                    | Acala
+                    | AcalaMandalaTestnet
+                    | AcalaTestnet
+                    | Karura
+                    | KaruraTestnet
+                    | Pulsechain
+                    | PulsechainTestnet
+                    | Immutable
+                    | ImmutableTestnet
+                    | SoneiumMinatoTestnet
+                    | World
+                    | WorldSepolia
+                    | Iotex
+                    | Core
+                    | Merlin
+                    | Bitlayer
+                    | Vana
+                    | Zeta
+                    | Kaia
+                    | UnichainSepolia
+                    | ApeChain
+                    | Curtis
+                    | SonicTestnet
+                    | Treasure
+                    | TreasureTopaz
+                    | BerachainBartio
+                    | BerachainArtio
+            },
+            RpcProvider::Drpc => templates! {
+                "https://lb.drpc.org/ogrpc?network=", "&dkey={API_KEY}";
+                Mainnet => "ethereum",
+                Sepolia => "sepolia",
+                Holesky => "holesky",
+                Optimism => "optimism",
+                Arbitrum => "arbitrum",
+                Base => "base",
+                Polygon => "polygon",
+                BinanceSmartChain => "bsc",
+                Avalanche => "avalanche",
+                Gnosis => "gnosis",
+                Linea => "linea",
+                Scroll => "scroll",
+                ZkSync => "zksync",
+                Blast => "blast";
+                unsupported: Morden
+                    | Ropsten
+                    | Rinkeby
+                    | Goerli
+                    | Kovan
+                    | Hoodi
+                    | Odyssey
+                    | OptimismKovan
+                    | OptimismGoerli
+                    | OptimismSepolia
+                    | Bob
+                    | BobSepolia
+                    | ArbitrumTestnet
+                    | ArbitrumGoerli
+                    | ArbitrumSepolia
+                    | ArbitrumNova
+                    | Cronos
+                    | CronosTestnet
+                    | Rsk
+                    | Crab
+                    | Darwinia
+                    | Koi
+                    | BinanceSmartChainTestnet
+                    | Poa
+                    | Sokol
+                    | ScrollSepolia
+                    | Metis
+                    | CfxTestnet
+                    | Cfx
+                    | PolygonMumbai
+                    | PolygonAmoy
+                    | PolygonZkEvm
+                    | PolygonZkEvmTestnet
+                    | Fantom
+                    | FantomTestnet
+                    | Moonbeam
+                    | MoonbeamDev
+                    | Moonriver
+                    | Moonbase
+                    | Dev
+                    | AnvilHardhat
+                    | GravityAlphaMainnet
+                    | GravityAlphaTestnetSepolia
+                    | Evmos
+                    | EvmosTestnet
+                    | Chiado
+                    | Oasis
+                    | Emerald
+                    | EmeraldTestnet
+                    | FilecoinMainnet
+                    | FilecoinCalibrationTestnet
+                    | AvalancheFuji
+                    | Celo
+                    | CeloAlfajores
+                    | CeloBaklava
+                    | Aurora
+                    | AuroraTestnet
+                    | Canto
+                    | CantoTestnet
+                    | Boba
+                    | BaseGoerli
+                    | BaseSepolia
+                    | Syndr
+                    | SyndrSepolia
+                    | Shimmer
+                    | Ink
+                    | InkSepolia
+                    | Fraxtal
+                    | FraxtalTestnet
+                    | BlastSepolia
+                    | LineaGoerli
+                    | LineaSepolia
+                    | ZkSyncTestnet
+                    | Mantle
+                    | MantleTestnet
+                    | MantleSepolia
+                    | Xai
+                    | XaiSepolia
+                    | HappychainTestnet
+                    | Viction
+                    | Zora
+                    | ZoraGoerli
+                    | ZoraSepolia
+                    | Pgn
+                    | PgnSepolia
+                    | Mode
+                    | ModeSepolia
+                    | Elastos
+                    | KakarotSepolia
+                    | Etherlink
+                    | EtherlinkTestnet
+                    | Degen
+                    | OpBNBMainnet
+                    | OpBNBTestnet
+                    | Ronin
+                    | RoninTestnet
+                    | Taiko
+                    | TaikoHekla
+                    | AutonomysNovaTestnet
+                    | Flare
+                    | FlareCoston2
+                    | Acala
+                    | AcalaMandalaTestnet
+                    | AcalaTestnet
+                    | Karura
+                    | KaruraTestnet
+                    | Pulsechain
+                    | PulsechainTestnet
+                    | Immutable
+                    | ImmutableTestnet
+                    | SoneiumMinatoTestnet
+                    | World
+                    | WorldSepolia
+                    | Iotex
+                    | Core
+                    | Merlin
+                    | Bitlayer
+                    | Vana
+                    | Zeta
+                    | Kaia
+                    | UnichainSepolia
+                    | ApeChain
+                    | Curtis
+                    | SonicTestnet
+                    | Treasure
+                    | TreasureTopaz
+                    | BerachainBartio
+                    | BerachainArtio
+            },
+        };
+        Some(RpcUrlTemplate::new(template))
+    }
+
+    /// Returns the identifier of this chain in the given bundler provider's endpoint URLs, if the
+    /// provider serves the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{BundlerProvider, NamedChain};
+    ///
+    /// assert_eq!(NamedChain::Base.bundler_rpc_slug(BundlerProvider::Pimlico), Some("base"));
+    /// assert_eq!(NamedChain::Base.bundler_rpc_slug(BundlerProvider::Alchemy), Some("base-mainnet"));
+    /// assert_eq!(NamedChain::AnvilHardhat.bundler_rpc_slug(BundlerProvider::Pimlico), None);
+    /// ```
+    pub const fn bundler_rpc_slug(self, provider: BundlerProvider) -> Option<&'static str> {
+        use NamedChain::*;
+
+        let slug = match provider {
+            BundlerProvider::Pimlico => match self {
+                Mainnet => "ethereum",
+                Sepolia => "sepolia",
+                Optimism => "optimism",
+                OptimismSepolia => "optimism-sepolia",
+                Base => "base",
+                BaseSepolia => "base-sepolia",
+                Arbitrum => "arbitrum",
+                ArbitrumNova => "arbitrum-nova",
+                ArbitrumSepolia => "arbitrum-sepolia",
+                Polygon => "polygon",
+                PolygonAmoy => "polygon-amoy",
+                BinanceSmartChain => "binance",
+                Avalanche => "avalanche",
+                Gnosis => "gnosis",
+                Linea => "linea",
+                Scroll => "scroll",
+                Blast => "blast",
+                Morden
+                | Ropsten
+                | Rinkeby
+                | Goerli
+                | Kovan
+                | Holesky
+                | Hoodi
+                | Odyssey
+                | OptimismKovan
+                | OptimismGoerli
+                | Bob
+                | BobSepolia
+                | ArbitrumTestnet
+                | ArbitrumGoerli
+                | Cronos
+                | CronosTestnet
+                | Rsk
+                | Crab
+                | Darwinia
+                | Koi
+                | BinanceSmartChainTestnet
+                | Poa
+                | Sokol
+                | ScrollSepolia
+                | Metis
+                | CfxTestnet
+                | Cfx
+                | PolygonMumbai
+                | PolygonZkEvm
+                | PolygonZkEvmTestnet
+                | Fantom
+                | FantomTestnet
+                | Moonbeam
+                | MoonbeamDev
+                | Moonriver
+                | Moonbase
+                | Dev
+                | AnvilHardhat
+                | GravityAlphaMainnet
+                | GravityAlphaTestnetSepolia
+                | Evmos
+                | EvmosTestnet
+                | Chiado
+                | Oasis
+                | Emerald
+                | EmeraldTestnet
+                | FilecoinMainnet
+                | FilecoinCalibrationTestnet
+                | AvalancheFuji
+                | Celo
+                | CeloAlfajores
+                | CeloBaklava
+                | Aurora
+                | AuroraTestnet
+                | Canto
+                | CantoTestnet
+                | Boba
+                | BaseGoerli
+                | Syndr
+                | SyndrSepolia
+                | Shimmer
+                | Ink
+                | InkSepolia
+                | Fraxtal
+                | FraxtalTestnet
+                | BlastSepolia
+                | LineaGoerli
+                | LineaSepolia
+                | ZkSync
+                | ZkSyncTestnet
+                | Mantle
+                | MantleTestnet
+                | MantleSepolia
+                | Xai
+                | XaiSepolia
+                | HappychainTestnet
+                | Viction
+                | Zora
+                | ZoraGoerli
+                | ZoraSepolia
+                | Pgn
+                | PgnSepolia
+                | Mode
+                | ModeSepolia
+                | Elastos
+                | KakarotSepolia
                 | Etherlink
                 | EtherlinkTestnet
+                | Degen
+                | OpBNBMainnet
+                | OpBNBTestnet
+                | Ronin
+                | RoninTestnet
+                | Taiko
+                | TaikoHekla
+                | AutonomysNovaTestnet
+                | Flare
+                | FlareCoston2
+                | Acala
+                | AcalaMandalaTestnet
+                | AcalaTestnet
+                | Karura
+                | KaruraTestnet
+                | Pulsechain
+                | PulsechainTestnet
+                | Immutable
+                | ImmutableTestnet
+                | SoneiumMinatoTestnet
+                | World
+                | WorldSepolia
+                | Iotex
+                | Core
+                | Merlin
+                | Bitlayer
+                | Vana
+                | Zeta
+                | Kaia
+                | UnichainSepolia
+                | ApeChain
+                | Curtis
+                | SonicTestnet
+                | Treasure
+                | TreasureTopaz
+                | BerachainBartio
+                | BerachainArtio => return None,
+            },
+            BundlerProvider::Alchemy => match self {
+                Mainnet => "eth-mainnet",
+                Sepolia => "eth-sepolia",
+                Optimism => "opt-mainnet",
+                OptimismSepolia => "opt-sepolia",
+                Base => "base-mainnet",
+                BaseSepolia => "base-sepolia",
+                Arbitrum => "arb-mainnet",
+                ArbitrumSepolia => "arb-sepolia",
+                Polygon => "polygon-mainnet",
+                PolygonAmoy => "polygon-amoy",
+                Morden
+                | Ropsten
+                | Rinkeby
+                | Goerli
+                | Kovan
+                | Holesky
+                | Hoodi
+                | Odyssey
+                | OptimismKovan
+                | OptimismGoerli
+                | Bob
+                | BobSepolia
+                | ArbitrumTestnet
+                | ArbitrumGoerli
+                | ArbitrumNova
+                | Cronos
+                | CronosTestnet
+                | Rsk
+                | Crab
+                | Darwinia
+                | Koi
+                | BinanceSmartChain
+                | BinanceSmartChainTestnet
+                | Poa
+                | Sokol
                 | Scroll
                 | ScrollSepolia
-                | HappychainTestnet
+                | Metis
+                | CfxTestnet
+                | Cfx
+                | Gnosis
+                | PolygonMumbai
+                | PolygonZkEvm
+                | PolygonZkEvmTestnet
+                | Fantom
+                | FantomTestnet
+                | Moonbeam
+                | MoonbeamDev
+                | Moonriver
+                | Moonbase
+                | Dev
+                | AnvilHardhat
+                | GravityAlphaMainnet
+                | GravityAlphaTestnetSepolia
+                | Evmos
+                | EvmosTestnet
+                | Chiado
+                | Oasis
+                | Emerald
+                | EmeraldTestnet
+                | FilecoinMainnet
+                | FilecoinCalibrationTestnet
+                | Avalanche
+                | AvalancheFuji
+                | Celo
+                | CeloAlfajores
+                | CeloBaklava
+                | Aurora
+                | AuroraTestnet
+                | Canto
+                | CantoTestnet
+                | Boba
+                | BaseGoerli
+                | Syndr
+                | SyndrSepolia
                 | Shimmer
+                | Ink
+                | InkSepolia
+                | Fraxtal
+                | FraxtalTestnet
+                | Blast
+                | BlastSepolia
+                | Linea
+                | LineaGoerli
+                | LineaSepolia
+                | ZkSync
+                | ZkSyncTestnet
+                | Mantle
+                | MantleTestnet
+                | MantleSepolia
+                | Xai
+                | XaiSepolia
+                | HappychainTestnet
+                | Viction
+                | Zora
+                | ZoraGoerli
+                | ZoraSepolia
+                | Pgn
+                | PgnSepolia
+                | Mode
+                | ModeSepolia
+                | Elastos
+                | KakarotSepolia
+                | Etherlink
+                | EtherlinkTestnet
+                | Degen
                 | OpBNBMainnet
                 | OpBNBTestnet
-                | KakarotSepolia
+                | Ronin
+                | RoninTestnet
                 | Taiko
                 | TaikoHekla
-                | Avalanche
-                | AvalancheFuji
                 | AutonomysNovaTestnet
+                | Flare
+                | FlareCoston2
                 | Acala
                 | AcalaMandalaTestnet
                 | AcalaTestnet
                 | Karura
                 | KaruraTestnet
-                | Darwinia
-                | Crab
-                | Cfx
-                | CfxTestnet
                 | Pulsechain
                 | PulsechainTestnet
-                | Koi
                 | Immutable
                 | ImmutableTestnet
                 | SoneiumMinatoTestnet
                 | World
                 | WorldSepolia
                 | Iotex
+                | Core
+                | Merlin
+                | Bitlayer
+                | Vana
+                | Zeta
+                | Kaia
                 | UnichainSepolia
                 | ApeChain
                 | Curtis
-        )
+                | SonicTestnet
+                | Treasure
+                | TreasureTopaz
+                | BerachainBartio
+                | BerachainArtio => return None,
+            },
+            BundlerProvider::Stackup => return None,
+        };
+        Some(slug)
+    }
+
+    /// Returns the bundler providers serving this chain, with the chain's identifier in their
+    /// endpoint URLs.
+    ///
+    /// See [`bundler_rpc_slug`](Self::bundler_rpc_slug).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{BundlerProvider, NamedChain};
+    ///
+    /// let slugs: Vec<_> = NamedChain::Gnosis.bundler_rpc_slugs().collect();
+    /// assert_eq!(slugs, [(BundlerProvider::Pimlico, "gnosis")]);
+    /// ```
+    pub fn bundler_rpc_slugs(self) -> impl Iterator<Item = (BundlerProvider, &'static str)> {
+        BundlerProvider::ALL
+            .into_iter()
+            .filter_map(move |provider| Some((provider, self.bundler_rpc_slug(provider)?)))
+    }
+
+    /// Probes the RPC endpoint at `url` over `transport`, and reports the capabilities it
+    /// observes.
+    ///
+    /// This checks that `eth_chainId` returns this chain's ID, then whether a call executing
+    /// `PUSH0` succeeds and whether `eth_feeHistory` returns base fees. A JSON-RPC error from
+    /// either probe is reported as the capability being missing. Compare the report with
+    /// [`supports_shanghai`](Self::supports_shanghai) and [`is_legacy`](Self::is_legacy) to detect
+    /// stale tables.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use alloy_chains::{probe::RpcTransport, NamedChain};
+    ///
+    /// # fn run(transport: impl RpcTransport<Error = std::io::Error>) -> std::io::Result<()> {
+    /// let report = NamedChain::Mainnet
+    ///     .verify_rpc(transport, "https://ethereum-rpc.publicnode.com")
+    ///     .map_err(std::io::Error::other)?;
+    /// assert_eq!(report.push0, NamedChain::Mainnet.supports_shanghai());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "probe")]
+    pub fn verify_rpc<T: crate::probe::RpcTransport>(
+        self,
+        transport: T,
+        url: &str,
+    ) -> Result<crate::probe::RpcReport, crate::probe::VerifyRpcError<T::Error>> {
+        crate::probe::verify_rpc(self, transport, url)
+    }
+
+    /// Returns the chain's project slug on [L2BEAT](https://l2beat.com), for joining with its
+    /// risk and activity data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::ZkSync.l2beat_slug(), Some("zksync-era"));
+    /// assert_eq!(NamedChain::Mainnet.l2beat_slug(), None);
+    /// ```
+    pub const fn l2beat_slug(self) -> Option<&'static str> {
+        data::l2beat_slug(self)
+    }
+
+    /// Returns the chain's key in the [DefiLlama](https://defillama.com) APIs (e.g. the
+    /// `<chain>:<address>` coin identifiers), for joining with its TVL and price data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Mainnet.defillama_slug(), Some("ethereum"));
+    /// assert_eq!(NamedChain::BinanceSmartChain.defillama_slug(), Some("bsc"));
+    /// ```
+    pub const fn defillama_slug(self) -> Option<&'static str> {
+        data::defillama_slug(self)
+    }
+
+    /// Returns the chain's `shortName` in the [chainid.network](https://chainid.network) dataset,
+    /// which is also used as its [EIP-3770](https://eips.ethereum.org/EIPS/eip-3770) prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Arbitrum.chainlist_short_name(), Some("arb1"));
+    /// assert_eq!(NamedChain::Optimism.chainlist_short_name(), Some("oeth"));
+    /// ```
+    pub const fn chainlist_short_name(self) -> Option<&'static str> {
+        data::chainlist_short_name(self)
+    }
+
+    /// Returns the chain whose [`chainlist_short_name`](Self::chainlist_short_name) is
+    /// `short_name`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::from_chainlist_short_name("arb1"), Some(NamedChain::Arbitrum));
+    /// assert_eq!(NamedChain::from_chainlist_short_name("arbitrum"), None);
+    /// ```
+    pub fn from_chainlist_short_name(short_name: &str) -> Option<Self> {
+        use strum::IntoEnumIterator;
+
+        Self::iter().find(|chain| chain.chainlist_short_name() == Some(short_name))
+    }
+
+    /// Returns the chain's brand color as a `#RRGGBB` hex string, for color-coding chains in
+    /// terminal UIs and dashboards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Mainnet.brand_color(), Some("#627EEA"));
+    /// assert_eq!(NamedChain::Base.brand_color(), Some("#0052FF"));
+    /// ```
+    #[cfg(feature = "ui-metadata")]
+    pub const fn brand_color(self) -> Option<&'static str> {
+        data::brand_color(self)
+    }
+
+    /// Returns the chain's sequencer RPC URL, for rollups whose sequencer accepts raw transactions
+    /// directly.
+    ///
+    /// Submitting to the sequencer bypasses third-party RPC mempools. Only
+    /// `eth_sendRawTransaction` (and its conditional variants) are expected to be supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Base.sequencer_rpc_url(), Some("https://mainnet-sequencer.base.org"));
+    /// assert_eq!(NamedChain::Mainnet.sequencer_rpc_url(), None);
+    /// ```
+    pub const fn sequencer_rpc_url(self) -> Option<&'static str> {
+        data::sequencer_rpc_url(self)
+    }
+
+    /// Returns the known private transaction and MEV relay endpoints for the chain.
+    ///
+    /// These can be used to submit transactions without exposing them to the public mempool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{MevEndpointKind, NamedChain};
+    ///
+    /// let endpoints = NamedChain::Mainnet.mev_endpoints();
+    /// assert!(endpoints.iter().any(|e| e.kind == MevEndpointKind::Protect));
+    /// assert!(NamedChain::AnvilHardhat.mev_endpoints().is_empty());
+    /// ```
+    pub const fn mev_endpoints(self) -> &'static [MevEndpoint] {
+        data::mev_endpoints(self)
+    }
+
+    /// Returns the chain's devp2p network ID, as exchanged in the `eth` protocol `Status` message.
+    ///
+    /// The network ID is usually, but not necessarily, equal to the chain ID. The chains known to
+    /// differ are:
+    /// - [`Rsk`](Self::Rsk), whose nodes use network ID `775` with chain ID `30`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert_eq!(NamedChain::Sepolia.network_id(), 11155111);
+    /// assert_eq!(NamedChain::Rsk.network_id(), 775);
+    /// ```
+    pub const fn network_id(self) -> u64 {
+        use NamedChain::*;
+
+        match self {
+            Rsk => 775,
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | Optimism
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Base
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => self as u64,
+        }
+    }
+
+    /// Returns the address of the public DNS node list for the given chain.
+    ///
+    /// This is the `enrtree://` URL of the chain's [`dns_discovery_tree`](Self::dns_discovery_tree).
+    ///
+    /// See also <https://github.com/ethereum/discv4-dns-lists>.
+    pub fn public_dns_network_protocol(self) -> Option<String> {
+        let (public_key, domain) = self.dns_discovery_tree()?;
+        Some(format!("enrtree://{public_key}@{domain}"))
+    }
+
+    /// Returns the public key and domain of the chain's [EIP-1459] DNS node discovery tree, if it
+    /// publishes one.
+    ///
+    /// [EIP-1459]: https://eips.ethereum.org/EIPS/eip-1459
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let (_, domain) = NamedChain::Hoodi.dns_discovery_tree().unwrap();
+    /// assert_eq!(domain, "all.hoodi.ethdisco.net");
+    /// assert_eq!(NamedChain::Optimism.dns_discovery_tree(), None);
+    /// ```
+    pub const fn dns_discovery_tree(self) -> Option<(&'static str, &'static str)> {
+        use NamedChain::*;
+
+        // Signing key of the ethdisco.net trees, maintained by the geth team.
+        const ETHDISCO: &str = "AKA3AM6LPBYEUDMVNU3BSVQJ5AD45Y7YPOHJLEF6W26QOE4VTUDPE";
+
+        Some(match self {
+            Mainnet => (ETHDISCO, "all.mainnet.ethdisco.net"),
+            Ropsten => (ETHDISCO, "all.ropsten.ethdisco.net"),
+            Rinkeby => (ETHDISCO, "all.rinkeby.ethdisco.net"),
+            Goerli => (ETHDISCO, "all.goerli.ethdisco.net"),
+            Sepolia => (ETHDISCO, "all.sepolia.ethdisco.net"),
+            Holesky => (ETHDISCO, "all.holesky.ethdisco.net"),
+            Hoodi => (ETHDISCO, "all.hoodi.ethdisco.net"),
+            Morden
+            | Kovan
+            | Odyssey
+            | Optimism
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Base
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        })
+    }
+
+    /// Returns the enode URLs of the chain's public devp2p bootnodes, as published by the client
+    /// teams (e.g. in [geth](https://github.com/ethereum/go-ethereum/blob/master/params/bootnodes.go)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let bootnodes = NamedChain::Mainnet.bootnodes().unwrap();
+    /// assert!(bootnodes.iter().all(|enode| enode.starts_with("enode://")));
+    /// assert_eq!(NamedChain::AnvilHardhat.bootnodes(), None);
+    /// ```
+    pub const fn bootnodes(self) -> Option<&'static [&'static str]> {
+        use NamedChain::*;
+
+        Some(match self {
+            Mainnet => &[
+                "enode://d860a01f9722d78051619d1e2351aba3f43f943f6f00718d1b9baa4101932a1f5011f16bb2b1bb35db20d6fe28fa0bf09636d26a87d31de9ec6203eeedb1f666@18.138.108.67:30303",
+                "enode://22a8232c3abc76a16ae9d6c3b164f98775fe226f0917b0ca871128a74a8e9630b458460865bab457221f1d448dd9791d24c4e5d88786180ac185df813a68d4de@3.209.45.79:30303",
+                "enode://2b252ab6a1d0f971d9722cb839a42cb81db019ba44c08754628ab4a823487071b5695317c8ccd085219c3a03af063495b2f1da8d18218da2d6a82981b45e6ffc@65.108.70.101:30303",
+                "enode://4aeb4ab6c14b23e2c4cfdce879c04b0748a20d8e9b59e25ded2a08143e265c6c25936e74cbc8e641e3312ca288673d91f2f93f8e277de3cfa444ecdaaf982052@157.90.35.166:30303",
+            ],
+            Sepolia => &[
+                "enode://4e5e92199ee224a01932a377160aa432f31d0b351f84ab413a8e0a42f4f36476f8fb1cbe914af0d9aef0d51665c214cf653c651c4bbd9d5550a934f241f1682b@138.197.51.181:30303",
+                "enode://143e11fb766781d22d92a2e33f8f104cddae4411a122295ed1fdb6638de96a6ce65f5b7c964ba3763bba27961738fef7d3ecc739268f3e5e771fb4c87b6234ba@146.190.1.103:30303",
+                "enode://8b61dc2d06c3f96fddcbebb0efb29d60d3598650275dc469c22229d3e5620369b0d3dedafd929835fe7f489618f19f456fe7c0df572bf2d914a9f4e006f783a9@170.64.250.88:30303",
+                "enode://10d62eff032205fcef19497f35ca8477bea0eadfff6d769a147e895d8b2b8f8ae6341630c645c30f5df6e67547c03494ced3d9c5764e8622a26587b083b028e8@139.59.49.206:30303",
+                "enode://9e9492e2e8836114cc75f5b929784f4f46c324ad01daf87d956f98b3b6c5fcba95524d6e5cf9861dc96a2c8a171ea7105bb554a197455058de185fa870970c7c@138.68.123.152:30303",
+            ],
+            Holesky => &[
+                "enode://ac906289e4b7f12df423d654c5a962b6ebe5b3a74cc9e06292a85221f9a64a6f1cfdd6b714ed6dacef51578f92b34c60ee91e9ede9c7f8fadc4d347326d95e2b@146.190.13.128:30303",
+                "enode://a3435a0155a3e837c02f5e7f5662a2f1fbc25b48e4dc232016e1c51b544cb5b4510ef633ea3278c0e970fa8ad8141e2d4d0f9f95456c537ff05fdf9b31c15072@178.128.136.233:30303",
+            ],
+            Hoodi => &[
+                "enode://2112dd3839dd752813d4df7f40936f06829fc54c0e051a93967c26e5f5d27d99d886b57b4ffcc3c475e930ec9e79c56ef1dbb7d86ca5ee83a9d2ccf36e5c240c@134.209.138.84:30303",
+                "enode://60203fcb3524e07c5df60a14ae1c9c5b24023ea5d47463dfae051d2c9f3219f309657537576090ca0ae641f73d419f53d8e8000d7a464319d4784acd7d2abc41@209.38.124.160:30303",
+                "enode://8ae4a48101b2299597341263da0deb47cc38aa4d3ef4b7430b897d49bfa10eb1ccfe1655679b1ed46928ef177fbf21b86837bd724400196c508427a6f41602cd@134.199.184.23:30303",
+            ],
+            Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Odyssey
+            | Optimism
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Base
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        })
+    }
+
+    /// Returns the address of the most popular wrapped native token address for this chain, if it
+    /// exists.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// let chain = NamedChain::Mainnet;
+    /// assert_eq!(
+    ///     chain.wrapped_native_token(),
+    ///     Some(address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"))
+    /// );
+    /// ```
+    pub const fn wrapped_native_token(self) -> Option<Address> {
+        match self.wrapped_native_token_info() {
+            Some(token) => Some(token.address),
+            None => None,
+        }
+    }
+
+    /// Returns the most popular wrapped native token for this chain, if it exists, along with
+    /// the token facts routers need.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let token = NamedChain::Mantle.wrapped_native_token_info().unwrap();
+    /// assert_eq!(token.symbol, "WMNT");
+    /// assert!(!token.supports_permit);
+    /// ```
+    pub const fn wrapped_native_token_info(self) -> Option<WrappedNativeToken> {
+        use NamedChain::*;
+
+        let token = match self {
+            Mainnet => WrappedNativeToken {
+                address: address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+                symbol: "WETH",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Optimism => WrappedNativeToken {
+                address: address!("4200000000000000000000000000000000000006"),
+                symbol: "WETH",
+                decimals: 18,
+                supports_permit: false,
+            },
+            BinanceSmartChain => WrappedNativeToken {
+                address: address!("bb4cdb9cbd36b01bd1cbaebf2de08d9173bc095c"),
+                symbol: "WBNB",
+                decimals: 18,
+                supports_permit: false,
+            },
+            OpBNBMainnet => WrappedNativeToken {
+                address: address!("4200000000000000000000000000000000000006"),
+                symbol: "WBNB",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Arbitrum => WrappedNativeToken {
+                address: address!("82af49447d8a07e3bd95bd0d56f35241523fbab1"),
+                symbol: "WETH",
+                decimals: 18,
+                supports_permit: true,
+            },
+            Base => WrappedNativeToken {
+                address: address!("4200000000000000000000000000000000000006"),
+                symbol: "WETH",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Linea => WrappedNativeToken {
+                address: address!("e5d7c2a44ffddf6b295a15c148167daaaf5cf34f"),
+                symbol: "WETH",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Mantle => WrappedNativeToken {
+                address: address!("deaddeaddeaddeaddeaddeaddeaddeaddead1111"),
+                symbol: "WMNT",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Blast => WrappedNativeToken {
+                address: address!("4300000000000000000000000000000000000004"),
+                symbol: "WETH",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Gnosis => WrappedNativeToken {
+                address: address!("e91d153e0b41518a2ce8dd3d7944fa863463a97d"),
+                symbol: "WXDAI",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Scroll => WrappedNativeToken {
+                address: address!("5300000000000000000000000000000000000004"),
+                symbol: "WETH",
+                decimals: 18,
+                supports_permit: true,
+            },
+            Taiko => WrappedNativeToken {
+                address: address!("a51894664a773981c6c112c43ce576f315d5b1b6"),
+                symbol: "WETH",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Avalanche => WrappedNativeToken {
+                address: address!("b31f66aa3c1e785363f0875a1b74e27b85fd66c7"),
+                symbol: "WAVAX",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Polygon => WrappedNativeToken {
+                address: address!("0d500b1d8e8ef31e21c99d1db9a6444d3adf1270"),
+                symbol: "WPOL",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Fantom => WrappedNativeToken {
+                address: address!("21be370d5312f44cb42ce377bc9b8a0cef1a4c83"),
+                symbol: "WFTM",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Iotex => WrappedNativeToken {
+                address: address!("a00744882684c3e4747faefd68d283ea44099d03"),
+                symbol: "WIOTX",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Core => WrappedNativeToken {
+                address: address!("40375C92d9FAf44d2f9db9Bd9ba41a3317a2404f"),
+                symbol: "WCORE",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Merlin => WrappedNativeToken {
+                address: address!("F6D226f9Dc15d9bB51182815b320D3fBE324e1bA"),
+                symbol: "WBTC",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Bitlayer => WrappedNativeToken {
+                address: address!("ff204e2681a6fa0e2c3fade68a1b28fb90e4fc5f"),
+                symbol: "WBTC",
+                decimals: 18,
+                supports_permit: false,
+            },
+            ApeChain => WrappedNativeToken {
+                address: address!("48b62137EdfA95a428D35C09E44256a739F6B557"),
+                symbol: "WAPE",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Vana => WrappedNativeToken {
+                address: address!("00EDdD9621Fb08436d0331c149D1690909a5906d"),
+                symbol: "WVANA",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Zeta => WrappedNativeToken {
+                address: address!("5F0b1a82749cb4E2278EC87F8BF6B618dC71a8bf"),
+                symbol: "WZETA",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Kaia => WrappedNativeToken {
+                address: address!("19aac5f612f524b754ca7e7c41cbfa2e981a4432"),
+                symbol: "WKAIA",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Treasure => WrappedNativeToken {
+                address: address!("263d8f36bb8d0d9526255e205868c26690b04b88"),
+                symbol: "WMAGIC",
+                decimals: 18,
+                supports_permit: false,
+            },
+            Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | BlastSepolia
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | UnichainSepolia
+            | Curtis
+            | SonicTestnet
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        };
+
+        Some(token)
+    }
+
+    /// Returns the canonical bridge pairs of common assets for this chain, as
+    /// `(l1_token, l2_token)` tuples sorted by L1 address.
+    ///
+    /// Only tokens bridged through the chain's native bridge (the OP-stack standard bridge or the
+    /// Arbitrum gateway) are listed. Natively issued tokens, such as native USDC, are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert!(!NamedChain::Arbitrum.token_bridge_pairs().is_empty());
+    /// assert!(NamedChain::Mainnet.token_bridge_pairs().is_empty());
+    /// ```
+    pub const fn token_bridge_pairs(self) -> &'static [(Address, Address)] {
+        use NamedChain::*;
+
+        const USDC: Address = address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+        const WBTC: Address = address!("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599");
+        const LINK: Address = address!("514910771AF9Ca656af840dff83E8264EcF986CA");
+        const DAI: Address = address!("6B175474E89094C44Da98b954EedeAC495271d0F");
+        const WETH: Address = address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+        const USDT: Address = address!("dAC17F958D2ee523a2206206994597C13D831ec7");
+
+        const OPTIMISM: &[(Address, Address)] = &[
+            (WBTC, address!("68f180fcCe6836688e9084f035309E29Bf0A2095")),
+            (LINK, address!("350a791Bfc2C21F9Ed5d10980Dad2e2638ffa7f6")),
+            (DAI, address!("DA10009cBd5D07dd0CeCc66161FC93D7c9000da1")),
+            (USDC, address!("7F5c764cBc14f9669B88837ca1490cCa17c31607")),
+            (USDT, address!("94b008aA00579c1307B0EF2c499aD98a8ce58e58")),
+        ];
+        const BASE: &[(Address, Address)] = &[
+            (DAI, address!("50c5725949A6F0c72E6C4a641F24049A917DB0Cb")),
+            (USDC, address!("d9aAEc86B65D86f6A7B5B1b0c42FFA531710b6CA")),
+        ];
+        const ARBITRUM: &[(Address, Address)] = &[
+            (WBTC, address!("2f2a2543B76A4166549F7aaB2e75Bef0aefC5B0f")),
+            (LINK, address!("f97f4df75117a78c1A5a0DBb814Af92458539FB4")),
+            (DAI, address!("DA10009cBd5D07dd0CeCc66161FC93D7c9000da1")),
+            (USDC, address!("FF970A61A04b1cA14834A43f5dE4533eBDDB5CC8")),
+            (WETH, address!("82aF49447D8a07e3bd95BD0d56f35241523fBab1")),
+            (USDT, address!("Fd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9")),
+        ];
+
+        match self {
+            Optimism => OPTIMISM,
+            Base => BASE,
+            Arbitrum => ARBITRUM,
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => &[],
+        }
+    }
+
+    /// Returns the address of the canonically bridged counterpart of `l1_token` on this chain, if
+    /// known. See [`token_bridge_pairs`](Self::token_bridge_pairs).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// let dai = address!("6B175474E89094C44Da98b954EedeAC495271d0F");
+    /// assert_eq!(
+    ///     NamedChain::Arbitrum.bridged_token(dai),
+    ///     Some(address!("DA10009cBd5D07dd0CeCc66161FC93D7c9000da1"))
+    /// );
+    /// assert_eq!(NamedChain::Mainnet.bridged_token(dai), None);
+    /// ```
+    pub fn bridged_token(self, l1_token: Address) -> Option<Address> {
+        self.token_bridge_pairs().iter().find(|(l1, _)| *l1 == l1_token).map(|&(_, l2)| l2)
+    }
+
+    /// Returns the address of the chain's beacon chain [deposit contract][ref], used to stake
+    /// validators.
+    ///
+    /// [ref]: https://ethereum.org/en/staking/deposit-contract/
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// assert_eq!(
+    ///     NamedChain::Mainnet.beacon_deposit_contract(),
+    ///     Some(address!("00000000219ab540356cBB839Cbe05303d7705Fa"))
+    /// );
+    /// assert_eq!(NamedChain::Optimism.beacon_deposit_contract(), None);
+    /// ```
+    pub const fn beacon_deposit_contract(self) -> Option<Address> {
+        use NamedChain::*;
+
+        let addr = match self {
+            Mainnet | Hoodi => address!("00000000219ab540356cBB839Cbe05303d7705Fa"),
+            Goerli => address!("ff50ed3d0ec03aC01D4C79aAd74928BFF48a7b2b"),
+            Sepolia => address!("7f02C3E3c98b133055B8B348B2Ac625669Ed295D"),
+            Holesky => address!("4242424242424242424242424242424242424242"),
+            Gnosis => address!("0B98057eA310F4d31F2a452B414647007d1645d9"),
+            Chiado => address!("b97036A26259B7147018913bD58a774cf91acf25"),
+            Morden
+            | Ropsten
+            | Rinkeby
+            | Kovan
+            | Odyssey
+            | Optimism
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Base
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        };
+
+        Some(addr)
+    }
+
+    /// Returns the address of the chain's Chainlink [Feed Registry][ref], which resolves price
+    /// feeds by base and quote asset.
+    ///
+    /// [ref]: https://docs.chain.link/data-feeds/feed-registry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// assert_eq!(
+    ///     NamedChain::Mainnet.chainlink_feed_registry(),
+    ///     Some(address!("47Fb2585D2C56Fe188D0E6ec628a38b74fCeeeDf"))
+    /// );
+    /// assert_eq!(NamedChain::Optimism.chainlink_feed_registry(), None);
+    /// ```
+    pub const fn chainlink_feed_registry(self) -> Option<Address> {
+        use NamedChain::*;
+
+        match self {
+            Mainnet => Some(address!("47Fb2585D2C56Fe188D0E6ec628a38b74fCeeeDf")),
+            Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | Optimism
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Base
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => None,
+        }
+    }
+
+    /// Returns the address of the chain's [Pyth][ref] price feed contract.
+    ///
+    /// [ref]: https://docs.pyth.network/price-feeds/contract-addresses/evm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// assert_eq!(
+    ///     NamedChain::Arbitrum.pyth_address(),
+    ///     Some(address!("ff1a0f4744e8582DF1aE09D5611b887B6a12925C"))
+    /// );
+    /// assert_eq!(NamedChain::AnvilHardhat.pyth_address(), None);
+    /// ```
+    pub const fn pyth_address(self) -> Option<Address> {
+        use NamedChain::*;
+
+        let addr = match self {
+            Mainnet | Avalanche => address!("4305FB66699C3B2702D4d05CF36551390A4c69C6"),
+            Optimism | Arbitrum | Polygon | Fantom => {
+                address!("ff1a0f4744e8582DF1aE09D5611b887B6a12925C")
+            }
+            Base => address!("8250f4aF4B972684F7b336503E2D6dFeDeB1487a"),
+            BinanceSmartChain => address!("4D7E825f80bDf85e913E0DD2A2D54927e9dE1594"),
+            Gnosis => address!("2880aB155794e7179c9eE2e38200202908C17B43"),
+            Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        };
+
+        Some(addr)
+    }
+
+    /// Returns the L1 protocol contracts of this OP-stack chain, as listed in the
+    /// [superchain registry](https://github.com/ethereum-optimism/superchain-registry).
+    ///
+    /// Returns `None` for chains that are not OP-stack chains or that have not been curated yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// let contracts = NamedChain::Optimism.op_l1_contracts().unwrap();
+    /// assert_eq!(contracts.optimism_portal, address!("bEb5Fc579115071764c7423A4f12eDde41f106Ed"));
+    /// assert_eq!(NamedChain::Mainnet.op_l1_contracts(), None);
+    /// ```
+    pub const fn op_l1_contracts(self) -> Option<OpL1Contracts> {
+        use NamedChain::*;
+
+        let contracts = match self {
+            Optimism => OpL1Contracts {
+                optimism_portal: address!("bEb5Fc579115071764c7423A4f12eDde41f106Ed"),
+                system_config: address!("229047fed2591dbec1eF1118d64F7aF3dB9EB290"),
+                dispute_game_factory: Some(address!("e5965Ab5962eDc7477C8520243A95517CD252fA9")),
+                l2_output_oracle: Some(address!("dfe97868233d1aa22e815a266982f2cf17685a27")),
+            },
+            OptimismSepolia => OpL1Contracts {
+                optimism_portal: address!("16Fc5058F25648194471939df75CF27A2fdC48BC"),
+                system_config: address!("034edD2A225f7f429A63E0f1D2084B9E0A93b538"),
+                dispute_game_factory: Some(address!("05F9613aDB30026FFd634f38e5C4dFd30a197Fa1")),
+                l2_output_oracle: Some(address!("90E9c4f8a994a250F6aEfd61CAFb4F2e895D458F")),
+            },
+            Base => OpL1Contracts {
+                optimism_portal: address!("49048044D57e1C92A77f79988d21Fa8fAF74E97e"),
+                system_config: address!("73a79Fab69143498Ed3712e519A88a918e1f4072"),
+                dispute_game_factory: Some(address!("43edB88C4B80fDD2AdFF2412A7BebF9dF42cB40e")),
+                l2_output_oracle: Some(address!("56315b90c40730925ec5485cf004d835058518A0")),
+            },
+            BaseSepolia => OpL1Contracts {
+                optimism_portal: address!("49f53e41452C74589E85cA1677426Ba426459e85"),
+                system_config: address!("f272670eb55e895584501d564AfEB048bEd26194"),
+                dispute_game_factory: Some(address!("d6E6dBf4F7EA0ac412fD8b65ED297e64BB7a06E1")),
+                l2_output_oracle: Some(address!("84457ca9D0163FbC4bbfe4Dfbb20ba46e48DF254")),
+            },
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | OptimismKovan
+            | OptimismGoerli
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | BaseGoerli
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        };
+
+        Some(contracts)
+    }
+
+    /// Returns the core contracts of this Arbitrum or Arbitrum Orbit chain on its
+    /// [parent chain](ArbitrumL1Contracts::parent_chain), as listed in the
+    /// [Arbitrum docs](https://docs.arbitrum.io/build-decentralized-apps/reference/contract-addresses)
+    /// or by the Orbit chain's operator.
+    ///
+    /// Returns `None` for chains that are not Arbitrum or Orbit chains, or that have not been
+    /// curated yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// let contracts = NamedChain::Arbitrum.arbitrum_l1_contracts().unwrap();
+    /// assert_eq!(contracts.inbox, address!("4Dbd4fc535Ac27206064B68FfCf827b0A60BAB3f"));
+    /// assert_eq!(NamedChain::Optimism.arbitrum_l1_contracts(), None);
+    ///
+    /// let xai = NamedChain::Xai.arbitrum_l1_contracts().unwrap();
+    /// assert_eq!(xai.parent_chain, NamedChain::Arbitrum);
+    /// ```
+    pub const fn arbitrum_l1_contracts(self) -> Option<ArbitrumL1Contracts> {
+        use NamedChain::*;
+
+        let contracts = match self {
+            Arbitrum => ArbitrumL1Contracts {
+                parent_chain: Mainnet,
+                rollup: address!("5eF0D09d1E6204141B4d37530808eD19f60FBa35"),
+                bridge: address!("8315177aB297bA92A06054cE80a67Ed4DBd7ed3a"),
+                inbox: address!("4Dbd4fc535Ac27206064B68FfCf827b0A60BAB3f"),
+                sequencer_inbox: address!("1c479675ad559DC151F6Ec7ed3FbF8ceE79582B6"),
+                outbox: address!("0B9857ae2D4A3DBe74ffE1d7DF045bb7F96E4840"),
+            },
+            ArbitrumNova => ArbitrumL1Contracts {
+                parent_chain: Mainnet,
+                rollup: address!("FB209827c58283535b744575e11953DCC4bEAD88"),
+                bridge: address!("C1Ebd02f738644983b6C4B2d440b8e77DdE276Bd"),
+                inbox: address!("c4448b71118c9071Bcb9734A0EAc55D18A153949"),
+                sequencer_inbox: address!("211E1c4c7f1bF5351Ac850Ed10FD68CFfCF6c21b"),
+                outbox: address!("D4B80C3D7240325D18E645B49e6535A3Bf95cc58"),
+            },
+            ArbitrumSepolia => ArbitrumL1Contracts {
+                parent_chain: Sepolia,
+                rollup: address!("042B2E6C5E99d4c521bd49beeD5E99651D9B0Cf4"),
+                bridge: address!("38f918D0E9F1b721EDaA41302E399fa1B79333a9"),
+                inbox: address!("aAe29B0366299461418F5324a79Afc425BE5ae21"),
+                sequencer_inbox: address!("6c97864CE4bEf387dE0b3310A44230f7E3F1be0D"),
+                outbox: address!("65f07C7D521164a4d5DaC6eB8Fac8DA067A3B78F"),
+            },
+            Xai => ArbitrumL1Contracts {
+                parent_chain: Arbitrum,
+                rollup: address!("C47DacFbAa80Bd9D8112F4e8069482c2A3221336"),
+                bridge: address!("7dd8A76bdAeBE3BBBaCD7Aa87f1D4FDa1E60f94f"),
+                inbox: address!("aE21fDA3de92dE2FDAF606233b2863782Ba046F9"),
+                sequencer_inbox: address!("995a9d3ca121D48d21087eDE20bc8acb2398c8B1"),
+                outbox: address!("1E400568AD4840dbE50FB32f306B842e9ddeF726"),
+            },
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | Optimism
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Base
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        };
+
+        Some(contracts)
+    }
+
+    /// Returns the account abstraction mechanisms available on the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// let aa = NamedChain::Mainnet.aa_support();
+    /// assert!(aa.erc4337_bundlers && aa.eip7702 && !aa.native);
+    /// assert!(NamedChain::ZkSync.aa_support().native);
+    /// assert!(!NamedChain::AnvilHardhat.aa_support().any());
+    /// ```
+    pub const fn aa_support(self) -> AaSupport {
+        use NamedChain::*;
+
+        AaSupport {
+            erc4337_bundlers: matches!(
+                self,
+                Mainnet
+                    | Sepolia
+                    | Optimism
+                    | OptimismSepolia
+                    | Base
+                    | BaseSepolia
+                    | Arbitrum
+                    | ArbitrumNova
+                    | ArbitrumSepolia
+                    | Polygon
+                    | PolygonAmoy
+                    | BinanceSmartChain
+                    | BinanceSmartChainTestnet
+                    | Avalanche
+                    | AvalancheFuji
+                    | Gnosis
+                    | Linea
+                    | LineaSepolia
+                    | Scroll
+                    | ScrollSepolia
+                    | Blast
+                    | Mode
+                    | Zora
+                    | Celo
+                    | Mantle
+            ),
+            native: matches!(self, ZkSync | ZkSyncTestnet),
+            eip7702: matches!(
+                self,
+                Mainnet
+                    | Sepolia
+                    | Holesky
+                    | Hoodi
+                    | Gnosis
+                    | Chiado
+                    | Optimism
+                    | OptimismSepolia
+                    | Base
+                    | BaseSepolia
+                    | BinanceSmartChain
+                    | BinanceSmartChainTestnet
+            ),
+        }
+    }
+
+    /// Returns the structural properties of the chain relevant to compliance policies.
+    ///
+    /// See [`ComplianceFlag`] for more info.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{ComplianceFlag, NamedChain};
+    ///
+    /// assert_eq!(NamedChain::Base.compliance_flags(), &[ComplianceFlag::CentralizedSequencer]);
+    /// assert!(NamedChain::Mainnet.compliance_flags().is_empty());
+    /// ```
+    pub const fn compliance_flags(self) -> &'static [ComplianceFlag] {
+        use ComplianceFlag::*;
+        use NamedChain::*;
+
+        match self {
+            Poa | Sokol | Kovan | Rinkeby => &[PermissionedValidators],
+
+            Scroll | ScrollSepolia | Linea | LineaGoerli | LineaSepolia | ZkSync
+            | ZkSyncTestnet | PolygonZkEvm | PolygonZkEvmTestnet => &[CentralizedSequencer],
+            Optimism | OptimismGoerli | OptimismKovan | OptimismSepolia | Base | BaseGoerli
+            | BaseSepolia | Fraxtal | FraxtalTestnet | Ink | InkSepolia | Mode | ModeSepolia
+            | Pgn | PgnSepolia | Zora | ZoraGoerli | ZoraSepolia | BlastSepolia | OpBNBMainnet
+            | OpBNBTestnet | SoneiumMinatoTestnet | Odyssey | World | WorldSepolia
+            | UnichainSepolia | HappychainTestnet | Arbitrum | ArbitrumTestnet | ArbitrumGoerli
+            | ArbitrumSepolia | ArbitrumNova => &[CentralizedSequencer],
+
+            Mainnet
+            | Morden
+            | Ropsten
+            | Goerli
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Bob
+            | BobSepolia
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Blast
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | Viction
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => &[],
+        }
+    }
+
+    /// Returns `true` if the chain aliases the sender of L1-to-L2 messages sent by L1 contracts,
+    /// by adding `0x1111000000000000000000000000000000001111` to its address.
+    ///
+    /// This is the scheme used by OP-stack and Arbitrum chains, so that an L1 contract cannot
+    /// impersonate the L2 contract deployed at the same address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert!(NamedChain::Base.has_l1_to_l2_alias());
+    /// assert!(NamedChain::Arbitrum.has_l1_to_l2_alias());
+    /// assert!(!NamedChain::ZkSync.has_l1_to_l2_alias());
+    /// ```
+    pub const fn has_l1_to_l2_alias(self) -> bool {
+        use NamedChain::*;
+
+        self.is_optimism() || self.is_arbitrum() || matches!(self, Bob | BobSepolia | Blast)
+    }
+
+    /// Returns the address an L1 contract at `address` appears as on this chain when it sends a
+    /// message to L2, or `None` if the chain does not alias addresses.
+    ///
+    /// See [`has_l1_to_l2_alias`](Self::has_l1_to_l2_alias).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// let l1 = address!("ffffffffffffffffffffffffffffffffffffffff");
+    /// let l2 = address!("1111000000000000000000000000000000001110");
+    /// assert_eq!(NamedChain::Optimism.apply_l1_to_l2_alias(l1), Some(l2));
+    /// assert_eq!(NamedChain::Optimism.undo_l1_to_l2_alias(l2), Some(l1));
+    /// assert_eq!(NamedChain::Mainnet.apply_l1_to_l2_alias(l1), None);
+    /// ```
+    pub const fn apply_l1_to_l2_alias(self, address: Address) -> Option<Address> {
+        if !self.has_l1_to_l2_alias() {
+            return None;
+        }
+        Some(offset_alias(address, false))
+    }
+
+    /// Returns the L1 address of the contract whose messages appear as sent by the aliased
+    /// `address` on this chain, or `None` if the chain does not alias addresses.
+    ///
+    /// This is the inverse of [`apply_l1_to_l2_alias`](Self::apply_l1_to_l2_alias).
+    pub const fn undo_l1_to_l2_alias(self, address: Address) -> Option<Address> {
+        if !self.has_l1_to_l2_alias() {
+            return None;
+        }
+        Some(offset_alias(address, true))
+    }
+
+    /// Returns the challenge period of this optimistic rollup: the time a withdrawal to L1 must
+    /// wait after its L2 state is proposed before it can be finalized.
+    ///
+    /// For Arbitrum chains, this is the rollup's confirmation period of 45,818 L1 blocks.
+    /// Returns `None` for chains that are not optimistic rollups or that have not been curated
+    /// yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(NamedChain::Optimism.challenge_period(), Some(Duration::from_secs(7 * 86_400)));
+    /// assert_eq!(NamedChain::Mainnet.challenge_period(), None);
+    /// ```
+    pub const fn challenge_period(self) -> Option<Duration> {
+        use NamedChain::*;
+
+        Some(Duration::from_secs(match self {
+            Optimism | Base => 7 * 86_400,
+            Arbitrum | ArbitrumNova => 45_818 * 12,
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | OptimismKovan
+            | OptimismGoerli
+            | OptimismSepolia
+            | Bob
+            | BobSepolia
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | BaseGoerli
+            | BaseSepolia
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Ink
+            | InkSepolia
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | Zora
+            | ZoraGoerli
+            | ZoraSepolia
+            | Pgn
+            | PgnSepolia
+            | Mode
+            | ModeSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | World
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | UnichainSepolia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => return None,
+        }))
+    }
+
+    /// Returns the precompiled contracts available on this chain, ordered by address.
+    ///
+    /// These are Ethereum's precompiles up to the chain's current fork, minus those the chain
+    /// does not implement, plus chain-specific additions such as
+    /// [RIP-7212](https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md). Returns an empty
+    /// slice for chains that have not been curated yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{NamedChain, Precompile};
+    ///
+    /// assert!(NamedChain::Mainnet.supported_precompiles().contains(&Precompile::Bls12G1Add));
+    /// assert!(NamedChain::Base.supported_precompiles().contains(&Precompile::P256Verify));
+    /// assert!(!NamedChain::Scroll.supported_precompiles().contains(&Precompile::Blake2F));
+    /// assert!(NamedChain::Celo.supported_precompiles().contains(&Precompile::CeloTransfer));
+    /// ```
+    pub const fn supported_precompiles(self) -> &'static [Precompile] {
+        use NamedChain::*;
+        use Precompile::*;
+
+        const ISTANBUL: &[Precompile] = &[
+            EcRecover,
+            Sha256,
+            Ripemd160,
+            Identity,
+            ModExp,
+            Bn254Add,
+            Bn254Mul,
+            Bn254Pairing,
+            Blake2F,
+        ];
+        const CANCUN: &[Precompile] = &[
+            EcRecover,
+            Sha256,
+            Ripemd160,
+            Identity,
+            ModExp,
+            Bn254Add,
+            Bn254Mul,
+            Bn254Pairing,
+            Blake2F,
+            PointEvaluation,
+        ];
+        // Prague's BLS12-381 precompiles, with Osaka's `P256VERIFY`.
+        const OSAKA: &[Precompile] = &[
+            EcRecover,
+            Sha256,
+            Ripemd160,
+            Identity,
+            ModExp,
+            Bn254Add,
+            Bn254Mul,
+            Bn254Pairing,
+            Blake2F,
+            PointEvaluation,
+            Bls12G1Add,
+            Bls12G1Msm,
+            Bls12G2Add,
+            Bls12G2Msm,
+            Bls12PairingCheck,
+            Bls12MapFpToG1,
+            Bls12MapFp2ToG2,
+            P256Verify,
+        ];
+        // Ecotone's point evaluation, with Fjord's RIP-7212.
+        const OP_FJORD: &[Precompile] = &[
+            EcRecover,
+            Sha256,
+            Ripemd160,
+            Identity,
+            ModExp,
+            Bn254Add,
+            Bn254Mul,
+            Bn254Pairing,
+            Blake2F,
+            PointEvaluation,
+            P256Verify,
+        ];
+        // Isthmus' BLS12-381 precompiles.
+        const OP_ISTHMUS: &[Precompile] = OSAKA;
+        const ISTANBUL_P256: &[Precompile] = &[
+            EcRecover,
+            Sha256,
+            Ripemd160,
+            Identity,
+            ModExp,
+            Bn254Add,
+            Bn254Mul,
+            Bn254Pairing,
+            Blake2F,
+            P256Verify,
+        ];
+        const CELO: &[Precompile] = &[
+            EcRecover,
+            Sha256,
+            Ripemd160,
+            Identity,
+            ModExp,
+            Bn254Add,
+            Bn254Mul,
+            Bn254Pairing,
+            Blake2F,
+            PointEvaluation,
+            CeloTransfer,
+            P256Verify,
+        ];
+        // Scroll does not implement `ripemd160` and `blake2f`.
+        const SCROLL: &[Precompile] =
+            &[EcRecover, Sha256, Identity, ModExp, Bn254Add, Bn254Mul, Bn254Pairing];
+
+        match self {
+            Mainnet | Sepolia | Holesky | Hoodi => OSAKA,
+            // RIP-7212 shipped in ArbOS 30 and in Polygon's Napoli upgrade.
+            Arbitrum | ArbitrumNova | ArbitrumSepolia | Polygon | PolygonAmoy => ISTANBUL_P256,
+            Celo => CELO,
+            Scroll | ScrollSepolia => SCROLL,
+            Optimism | OptimismGoerli | OptimismKovan | OptimismSepolia | Base | BaseGoerli
+            | BaseSepolia | Fraxtal | FraxtalTestnet | Ink | InkSepolia | Mode | ModeSepolia
+            | Pgn | PgnSepolia | Zora | ZoraGoerli | ZoraSepolia | BlastSepolia | OpBNBMainnet
+            | OpBNBTestnet | SoneiumMinatoTestnet | Odyssey | World | WorldSepolia
+            | UnichainSepolia | HappychainTestnet => match self.latest_op_fork() {
+                Some(fork) if fork as u8 >= Hardfork::Isthmus as u8 => OP_ISTHMUS,
+                Some(fork) if fork as u8 >= Hardfork::Fjord as u8 => OP_FJORD,
+                Some(fork) if fork as u8 >= Hardfork::Ecotone as u8 => CANCUN,
+                Some(_) => ISTANBUL,
+                None => &[],
+            },
+            Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Bob
+            | BobSepolia
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | PolygonMumbai
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Blast
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | Viction
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => &[],
+        }
+    }
+
+    /// Returns `true` if the chain implements the secp256r1 signature verification precompile
+    /// ([RIP-7212](https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md)), used to verify
+    /// passkey signatures on-chain.
+    ///
+    /// See [`supported_precompiles`](Self::supported_precompiles).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    ///
+    /// assert!(NamedChain::Base.supports_p256_precompile());
+    /// assert!(NamedChain::Arbitrum.supports_p256_precompile());
+    /// assert!(!NamedChain::Scroll.supports_p256_precompile());
+    /// ```
+    pub const fn supports_p256_precompile(self) -> bool {
+        let precompiles = self.supported_precompiles();
+        let mut i = 0;
+        while i < precompiles.len() {
+            if matches!(precompiles[i], Precompile::P256Verify) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Returns `true` if `address` is a precompiled contract on this chain.
+    ///
+    /// This covers the [`supported_precompiles`](Self::supported_precompiles), or Ethereum's
+    /// `0x01` to `0x0a` range for chains that have not been curated yet, as well as Arbitrum's
+    /// ArbOS precompiles from `0x64` (`ArbSys`) to `0x72` (`ArbWasmCache`) and ZKsync Era's system
+    /// contracts from `0x8000`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::Address;
+    ///
+    /// let arb_sys = Address::with_last_byte(0x64);
+    /// assert!(NamedChain::Mainnet.is_precompile(Address::with_last_byte(0x01)));
+    /// assert!(NamedChain::Arbitrum.is_precompile(arb_sys));
+    /// assert!(!NamedChain::Mainnet.is_precompile(arb_sys));
+    /// ```
+    pub const fn is_precompile(self, address: Address) -> bool {
+        use NamedChain::*;
+
+        let Some(index) = low_address(address) else { return false };
+        if self.is_arbitrum() && matches!(index, 0x64..=0x72) {
+            return true;
+        }
+        if matches!(self, ZkSync | ZkSyncTestnet) && index >= 0x8000 {
+            return true;
+        }
+
+        let precompiles = self.supported_precompiles();
+        if precompiles.is_empty() {
+            return matches!(index, 0x01..=0x0a);
+        }
+        let mut i = 0;
+        while i < precompiles.len() {
+            if matches!(low_address(precompiles[i].address()), Some(other) if other == index) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Returns the OP-stack network upgrades of this chain with their activation timestamps, in
+    /// activation order.
+    ///
+    /// Upgrades that were active at the chain's Bedrock genesis share the genesis timestamp. Only
+    /// upgrades that have already activated are listed, so that the last one is the upgrade
+    /// currently active on the chain; scheduled upgrades are added once they go live.
+    ///
+    /// Returns an empty slice for chains that are not OP-stack chains or that have not been
+    /// curated yet.
+    pub const fn op_hardforks(self) -> &'static [(Hardfork, u64)] {
+        use Hardfork::*;
+        use NamedChain::*;
+
+        match self {
+            Optimism => &[
+                (Bedrock, 1686068903),
+                (Regolith, 1686068903),
+                (Canyon, 1704992401),
+                (Delta, 1708560000),
+                (Ecotone, 1710374401),
+                (Fjord, 1720627201),
+                (Granite, 1726070401),
+                (Holocene, 1736445601),
+                (Isthmus, 1746806401),
+            ],
+            Base => &[
+                (Bedrock, 1686789347),
+                (Regolith, 1686789347),
+                (Canyon, 1704992401),
+                (Delta, 1708560000),
+                (Ecotone, 1710374401),
+                (Fjord, 1720627201),
+                (Granite, 1726070401),
+                (Holocene, 1736445601),
+                (Isthmus, 1746806401),
+            ],
+            OptimismSepolia => &[
+                (Bedrock, 1691802540),
+                (Regolith, 1691802540),
+                (Canyon, 1699981200),
+                (Delta, 1703203200),
+                (Ecotone, 1708534800),
+                (Fjord, 1716998400),
+                (Granite, 1723478400),
+                (Holocene, 1732633200),
+                (Isthmus, 1744905600),
+            ],
+            BaseSepolia => &[
+                (Bedrock, 1695768288),
+                (Regolith, 1695768288),
+                (Canyon, 1699981200),
+                (Delta, 1703203200),
+                (Ecotone, 1708534800),
+                (Fjord, 1716998400),
+                (Granite, 1723478400),
+                (Holocene, 1732633200),
+                (Isthmus, 1744905600),
+            ],
+            Zora => &[
+                (Bedrock, 1686693839),
+                (Regolith, 1686693839),
+                (Canyon, 1704992401),
+                (Delta, 1708560000),
+                (Ecotone, 1710374401),
+                (Fjord, 1720627201),
+                (Granite, 1726070401),
+                (Holocene, 1736445601),
+                (Isthmus, 1746806401),
+            ],
+            Mode => &[
+                (Bedrock, 1700167583),
+                (Regolith, 1700167583),
+                (Canyon, 1704992401),
+                (Delta, 1708560000),
+                (Ecotone, 1710374401),
+                (Fjord, 1720627201),
+                (Granite, 1726070401),
+                (Holocene, 1736445601),
+                (Isthmus, 1746806401),
+            ],
+            Ink => &[
+                (Bedrock, 1733498411),
+                (Regolith, 1733498411),
+                (Canyon, 1733498411),
+                (Delta, 1733498411),
+                (Ecotone, 1733498411),
+                (Fjord, 1733498411),
+                (Granite, 1733498411),
+                (Holocene, 1736445601),
+                (Isthmus, 1746806401),
+            ],
+            World => &[
+                (Bedrock, 1719335639),
+                (Regolith, 1719335639),
+                (Canyon, 1719335639),
+                (Delta, 1719335639),
+                (Ecotone, 1719335639),
+                (Fjord, 1721826000),
+                (Granite, 1727780400),
+                (Holocene, 1738238400),
+                (Isthmus, 1746806401),
+            ],
+            ZoraSepolia => &[
+                (Bedrock, 1698080004),
+                (Regolith, 1698080004),
+                (Canyon, 1699981200),
+                (Delta, 1703203200),
+                (Ecotone, 1708534800),
+                (Fjord, 1716998400),
+                (Granite, 1723478400),
+                (Holocene, 1732633200),
+                (Isthmus, 1744905600),
+            ],
+            ModeSepolia => &[
+                (Bedrock, 1687867932),
+                (Regolith, 1687867932),
+                (Canyon, 1699981200),
+                (Delta, 1703203200),
+                (Ecotone, 1708534800),
+                (Fjord, 1716998400),
+                (Granite, 1723478400),
+                (Holocene, 1732633200),
+                (Isthmus, 1744905600),
+            ],
+            InkSepolia => &[
+                (Bedrock, 1723496937),
+                (Regolith, 1723496937),
+                (Canyon, 1723496937),
+                (Delta, 1723496937),
+                (Ecotone, 1723496937),
+                (Fjord, 1723496937),
+                (Granite, 1723496937),
+                (Holocene, 1732633200),
+                (Isthmus, 1744905600),
+            ],
+            UnichainSepolia => &[
+                (Bedrock, 1730748359),
+                (Regolith, 1730748359),
+                (Canyon, 1730748359),
+                (Delta, 1730748359),
+                (Ecotone, 1730748359),
+                (Fjord, 1730748359),
+                (Granite, 1730748359),
+                (Holocene, 1732633200),
+                (Isthmus, 1744905600),
+            ],
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Odyssey
+            | OptimismKovan
+            | OptimismGoerli
+            | Bob
+            | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | BaseGoerli
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Fraxtal
+            | FraxtalTestnet
+            | Blast
+            | BlastSepolia
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | HappychainTestnet
+            | Viction
+            | ZoraGoerli
+            | Pgn
+            | PgnSepolia
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | OpBNBMainnet
+            | OpBNBTestnet
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | SoneiumMinatoTestnet
+            | WorldSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => &[],
+        }
     }
 
-    #[doc(hidden)]
-    #[deprecated(since = "0.1.3", note = "use `supports_shanghai` instead")]
-    pub const fn supports_push0(self) -> bool {
-        self.supports_shanghai()
+    /// Returns the OP-stack network upgrade active on this chain at the given block timestamp.
+    ///
+    /// Returns `None` if the timestamp precedes Bedrock, or if [`op_hardforks`](Self::op_hardforks)
+    /// is empty for this chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{hardfork::Hardfork, NamedChain};
+    ///
+    /// assert_eq!(NamedChain::Optimism.op_fork_at(1720627200), Some(Hardfork::Ecotone));
+    /// assert_eq!(NamedChain::Optimism.op_fork_at(1720627201), Some(Hardfork::Fjord));
+    /// assert_eq!(NamedChain::Mainnet.op_fork_at(1720627201), None);
+    /// ```
+    pub const fn op_fork_at(self, timestamp: u64) -> Option<Hardfork> {
+        let forks = self.op_hardforks();
+        let mut active = None;
+        let mut i = 0;
+        while i < forks.len() {
+            if forks[i].1 > timestamp {
+                break;
+            }
+            active = Some(forks[i].0);
+            i += 1;
+        }
+        active
     }
 
-    /// Returns whether the chain is a testnet.
-    pub const fn is_testnet(self) -> bool {
+    /// Returns the latest OP-stack network upgrade known to be active on this chain.
+    ///
+    /// This is the last entry of [`op_hardforks`](Self::op_hardforks), which only lists upgrades
+    /// that have already activated. Use [`op_fork_at`](Self::op_fork_at) for the upgrade active at
+    /// a given block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{hardfork::Hardfork, NamedChain};
+    ///
+    /// assert!(NamedChain::Base.latest_op_fork().unwrap() >= Hardfork::Holocene);
+    /// assert_eq!(NamedChain::Arbitrum.latest_op_fork(), None);
+    /// ```
+    pub const fn latest_op_fork(self) -> Option<Hardfork> {
+        match self.op_hardforks() {
+            [.., (fork, _)] => Some(*fork),
+            [] => None,
+        }
+    }
+
+    /// Returns the predeployed system contracts and precompiles that are specific to this chain.
+    ///
+    /// These are the contracts a local fork must keep for the chain's protocol to work, such as
+    /// the OP-stack predeploys at `0x4200..` or Arbitrum's precompiles at `0x64..`. Ethereum's
+    /// precompiles at `0x01..` are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// // L1Block
+    /// let l1_block = address!("4200000000000000000000000000000000000015");
+    /// assert!(NamedChain::Base.predeploys().contains(&l1_block));
+    /// assert!(NamedChain::Moonbeam.predeploys().is_empty());
+    /// ```
+    pub const fn predeploys(self) -> &'static [Address] {
         use NamedChain::*;
 
-        match self {
-            // Ethereum testnets.
-            Goerli | Holesky | Kovan | Sepolia | Morden | Ropsten | Rinkeby => true,
+        const ETHEREUM: &[Address] = &[
+            // EIP-7002 withdrawal requests
+            address!("00000961Ef480Eb55e80D19ad83579A64c007002"),
+            // EIP-7251 consolidation requests
+            address!("0000BBdDc7CE488642fb579F8B00f3a590007251"),
+            // EIP-2935 history storage
+            address!("0000F90827F1C53a10cb7A02335B175320002935"),
+            // EIP-4788 beacon roots
+            address!("000F3df6D732807Ef1319fB7B8bB8522d0Beac02"),
+        ];
+        const OPTIMISM: &[Address] = &[
+            // WETH9
+            address!("4200000000000000000000000000000000000006"),
+            // L2CrossDomainMessenger
+            address!("4200000000000000000000000000000000000007"),
+            // GasPriceOracle
+            address!("420000000000000000000000000000000000000F"),
+            // L2StandardBridge
+            address!("4200000000000000000000000000000000000010"),
+            // SequencerFeeVault
+            address!("4200000000000000000000000000000000000011"),
+            // OptimismMintableERC20Factory
+            address!("4200000000000000000000000000000000000012"),
+            // L1BlockNumber
+            address!("4200000000000000000000000000000000000013"),
+            // L2ERC721Bridge
+            address!("4200000000000000000000000000000000000014"),
+            // L1Block
+            address!("4200000000000000000000000000000000000015"),
+            // L2ToL1MessagePasser
+            address!("4200000000000000000000000000000000000016"),
+            // OptimismMintableERC721Factory
+            address!("4200000000000000000000000000000000000017"),
+            // ProxyAdmin
+            address!("4200000000000000000000000000000000000018"),
+            // BaseFeeVault
+            address!("4200000000000000000000000000000000000019"),
+            // L1FeeVault
+            address!("420000000000000000000000000000000000001a"),
+        ];
+        const ARBITRUM: &[Address] = &[
+            // ArbSys
+            address!("0000000000000000000000000000000000000064"),
+            // ArbInfo
+            address!("0000000000000000000000000000000000000065"),
+            // ArbAddressTable
+            address!("0000000000000000000000000000000000000066"),
+            // ArbOwnerPublic
+            address!("000000000000000000000000000000000000006b"),
+            // ArbGasInfo
+            address!("000000000000000000000000000000000000006C"),
+            // ArbAggregator
+            address!("000000000000000000000000000000000000006D"),
+            // ArbRetryableTx
+            address!("000000000000000000000000000000000000006E"),
+            // ArbStatistics
+            address!("000000000000000000000000000000000000006F"),
+            // ArbOwner
+            address!("0000000000000000000000000000000000000070"),
+            // NodeInterface
+            address!("00000000000000000000000000000000000000C8"),
+        ];
 
-            // Other testnets.
-            ArbitrumGoerli
-            | ArbitrumSepolia
-            | ArbitrumTestnet
-            | SyndrSepolia
-            | AuroraTestnet
-            | AvalancheFuji
-            | Odyssey
-            | BaseGoerli
-            | BaseSepolia
-            | BlastSepolia
-            | BinanceSmartChainTestnet
-            | CantoTestnet
+        match self {
+            Mainnet | Sepolia | Holesky | Hoodi => ETHEREUM,
+            Optimism | OptimismGoerli | OptimismKovan | OptimismSepolia | Base | BaseGoerli
+            | BaseSepolia | Fraxtal | FraxtalTestnet | Ink | InkSepolia | Mode | ModeSepolia
+            | Pgn | PgnSepolia | Zora | ZoraGoerli | ZoraSepolia | BlastSepolia | OpBNBMainnet
+            | OpBNBTestnet | SoneiumMinatoTestnet | Odyssey | World | WorldSepolia
+            | UnichainSepolia | HappychainTestnet => OPTIMISM,
+            Arbitrum | ArbitrumTestnet | ArbitrumGoerli | ArbitrumSepolia | ArbitrumNova => {
+                ARBITRUM
+            }
+            Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Bob
+            | BobSepolia
+            | Cronos
             | CronosTestnet
-            | CeloAlfajores
-            | CeloBaklava
-            | EmeraldTestnet
-            | EvmosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
+            | PolygonMumbai
+            | PolygonAmoy
+            | PolygonZkEvm
+            | PolygonZkEvmTestnet
+            | Fantom
             | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
             | FilecoinCalibrationTestnet
-            | FraxtalTestnet
-            | HappychainTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Syndr
+            | SyndrSepolia
+            | Shimmer
+            | Blast
+            | Linea
             | LineaGoerli
             | LineaSepolia
-            | InkSepolia
+            | ZkSync
+            | ZkSyncTestnet
+            | Mantle
             | MantleTestnet
             | MantleSepolia
-            | MoonbeamDev
-            | OptimismGoerli
-            | OptimismKovan
-            | OptimismSepolia
+            | Xai
+            | XaiSepolia
+            | Viction
+            | Elastos
+            | KakarotSepolia
+            | Etherlink
+            | EtherlinkTestnet
+            | Degen
+            | Ronin
+            | RoninTestnet
+            | Taiko
+            | TaikoHekla
+            | AutonomysNovaTestnet
+            | Flare
+            | FlareCoston2
+            | Acala
+            | AcalaMandalaTestnet
+            | AcalaTestnet
+            | Karura
+            | KaruraTestnet
+            | Pulsechain
+            | PulsechainTestnet
+            | Immutable
+            | ImmutableTestnet
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | ApeChain
+            | Curtis
+            | SonicTestnet
+            | Treasure
+            | TreasureTopaz
+            | BerachainBartio
+            | BerachainArtio => &[],
+        }
+    }
+
+    /// Returns the address of the `ProxyAdmin` predeploy that administers the chain's proxied
+    /// predeploys, if the chain standardizes one.
+    ///
+    /// On OP-stack chains, this is the `ProxyAdmin` at `0x4200000000000000000000000000000000000018`,
+    /// owned by the aliased L1 `ProxyAdmin` owner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// assert_eq!(
+    ///     NamedChain::Base.predeploy_proxy_admin(),
+    ///     Some(address!("4200000000000000000000000000000000000018"))
+    /// );
+    /// assert_eq!(NamedChain::Arbitrum.predeploy_proxy_admin(), None);
+    /// ```
+    pub const fn predeploy_proxy_admin(self) -> Option<Address> {
+        if self.is_optimism() {
+            Some(address!("4200000000000000000000000000000000000018"))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the address ranges reserved for the chain's system contracts, in which user
+    /// deployments can not live.
+    ///
+    /// These are the OP-stack predeploy namespace, from
+    /// `0x4200000000000000000000000000000000000000` to
+    /// `0x42000000000000000000000000000000000007ff`, and ZKsync Era's system contracts, from
+    /// `0x8000` to `0xffff`. Precompiles are not included; see
+    /// [`is_precompile`](Self::is_precompile).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
+    ///
+    /// let l1_block = address!("4200000000000000000000000000000000000015");
+    /// let reserved = NamedChain::Base.reserved_system_address_ranges();
+    /// assert!(reserved.iter().any(|range| range.contains(&l1_block)));
+    /// assert!(NamedChain::Mainnet.reserved_system_address_ranges().is_empty());
+    /// ```
+    pub const fn reserved_system_address_ranges(self) -> &'static [RangeInclusive<Address>] {
+        use NamedChain::*;
+
+        const OP_PREDEPLOYS: &[RangeInclusive<Address>] =
+            &[address!("4200000000000000000000000000000000000000")
+                ..=address!("42000000000000000000000000000000000007ff")];
+        const ZKSYNC_SYSTEM_CONTRACTS: &[RangeInclusive<Address>] =
+            &[address!("0000000000000000000000000000000000008000")
+                ..=address!("000000000000000000000000000000000000ffff")];
+
+        match self {
+            Optimism | OptimismGoerli | OptimismKovan | OptimismSepolia | Base | BaseGoerli
+            | BaseSepolia | Fraxtal | FraxtalTestnet | Ink | InkSepolia | Mode | ModeSepolia
+            | Pgn | PgnSepolia | Zora | ZoraGoerli | ZoraSepolia | BlastSepolia | OpBNBMainnet
+            | OpBNBTestnet | SoneiumMinatoTestnet | Odyssey | World | WorldSepolia
+            | UnichainSepolia | HappychainTestnet => OP_PREDEPLOYS,
+            ZkSync | ZkSyncTestnet => ZKSYNC_SYSTEM_CONTRACTS,
+            Mainnet
+            | Morden
+            | Ropsten
+            | Rinkeby
+            | Goerli
+            | Kovan
+            | Holesky
+            | Hoodi
+            | Sepolia
+            | Bob
             | BobSepolia
+            | Arbitrum
+            | ArbitrumTestnet
+            | ArbitrumGoerli
+            | ArbitrumSepolia
+            | ArbitrumNova
+            | Cronos
+            | CronosTestnet
+            | Rsk
+            | Crab
+            | Darwinia
+            | Koi
+            | BinanceSmartChain
+            | BinanceSmartChainTestnet
+            | Poa
+            | Sokol
+            | Scroll
+            | ScrollSepolia
+            | Metis
+            | CfxTestnet
+            | Cfx
+            | Gnosis
+            | Polygon
             | PolygonMumbai
             | PolygonAmoy
+            | PolygonZkEvm
             | PolygonZkEvmTestnet
-            | ScrollSepolia
+            | Fantom
+            | FantomTestnet
+            | Moonbeam
+            | MoonbeamDev
+            | Moonriver
+            | Moonbase
+            | Dev
+            | AnvilHardhat
+            | GravityAlphaMainnet
+            | GravityAlphaTestnetSepolia
+            | Evmos
+            | EvmosTestnet
+            | Chiado
+            | Oasis
+            | Emerald
+            | EmeraldTestnet
+            | FilecoinMainnet
+            | FilecoinCalibrationTestnet
+            | Avalanche
+            | AvalancheFuji
+            | Celo
+            | CeloAlfajores
+            | CeloBaklava
+            | Aurora
+            | AuroraTestnet
+            | Canto
+            | CantoTestnet
+            | Boba
+            | Syndr
+            | SyndrSepolia
             | Shimmer
-            | ZkSyncTestnet
-            | ZoraGoerli
-            | ZoraSepolia
-            | ModeSepolia
-            | PgnSepolia
+            | Blast
+            | Linea
+            | LineaGoerli
+            | LineaSepolia
+            | Mantle
+            | MantleTestnet
+            | MantleSepolia
+            | Xai
+            | XaiSepolia
+            | Viction
+            | Elastos
             | KakarotSepolia
+            | Etherlink
             | EtherlinkTestnet
-            | OpBNBTestnet
+            | Degen
+            | Ronin
             | RoninTestnet
+            | Taiko
             | TaikoHekla
             | AutonomysNovaTestnet
+            | Flare
             | FlareCoston2
+            | Acala
             | AcalaMandalaTestnet
             | AcalaTestnet
+            | Karura
             | KaruraTestnet
-            | CfxTestnet
+            | Pulsechain
             | PulsechainTestnet
-            | GravityAlphaTestnetSepolia
-            | XaiSepolia
-            | Koi
+            | Immutable
             | ImmutableTestnet
-            | SoneiumMinatoTestnet
-            | WorldSepolia
-            | UnichainSepolia
+            | Iotex
+            | Core
+            | Merlin
+            | Bitlayer
+            | Vana
+            | Zeta
+            | Kaia
+            | ApeChain
             | Curtis
-            | TreasureTopaz
             | SonicTestnet
+            | Treasure
+            | TreasureTopaz
             | BerachainBartio
-            | BerachainArtio => true,
-
-            // Dev chains.
-            Dev | AnvilHardhat => true,
-
-            // Mainnets.
-            Mainnet | Optimism | Arbitrum | ArbitrumNova | Blast | Syndr | Cronos | Rsk
-            | BinanceSmartChain | Poa | Sokol | Scroll | Metis | Gnosis | Polygon
-            | PolygonZkEvm | Fantom | Moonbeam | Moonriver | Moonbase | Evmos | Chiado | Oasis
-            | Emerald | FilecoinMainnet | Avalanche | Celo | Aurora | Canto | Boba | Base
-            | Fraxtal | Ink | Linea | ZkSync | Mantle | GravityAlphaMainnet | Xai | Zora | Pgn
-            | Mode | Viction | Elastos | Degen | OpBNBMainnet | Ronin | Taiko | Flare | Acala
-            | Karura | Darwinia | Cfx | Crab | Pulsechain | Etherlink | Immutable | World
-            | Iotex | Core | Merlin | Bitlayer | ApeChain | Vana | Zeta | Kaia | Treasure | Bob => {
-                false
-            }
+            | BerachainArtio => &[],
         }
     }
 
-    /// Returns the symbol of the chain's native currency.
-    pub const fn native_currency_symbol(self) -> Option<&'static str> {
-        use NamedChain::*;
-
-        Some(match self {
-            Mainnet | Goerli | Holesky | Kovan | Sepolia | Morden | Ropsten | Rinkeby | Scroll
-            | ScrollSepolia | Taiko | TaikoHekla | UnichainSepolia => "ETH",
-
-            Mantle | MantleSepolia => "MNT",
-
-            GravityAlphaMainnet | GravityAlphaTestnetSepolia => "G",
-
-            Xai | XaiSepolia => "XAI",
-
-            HappychainTestnet => "HAPPY",
-
-            BinanceSmartChain | BinanceSmartChainTestnet | OpBNBMainnet | OpBNBTestnet => "BNB",
-
-            Etherlink | EtherlinkTestnet => "XTZ",
-
-            Degen => "DEGEN",
-
-            Ronin | RoninTestnet => "RON",
-
-            Shimmer => "SMR",
-
-            Flare => "FLR",
-
-            FlareCoston2 => "C2FLR",
-
-            Darwinia => "RING",
-
-            Crab => "CRAB",
-
-            Koi => "KRING",
-
-            Cfx | CfxTestnet => "CFX",
-            Pulsechain | PulsechainTestnet => "PLS",
-
-            Immutable => "IMX",
-            ImmutableTestnet => "tIMX",
-
-            World | WorldSepolia => "WRLD",
-
-            Iotex => "IOTX",
-            Core => "CORE",
-            Merlin => "BTC",
-            Bitlayer => "BTC",
-            Vana => "VANA",
-            Zeta => "ZETA",
-            Kaia => "KAIA",
-            ApeChain | Curtis => "APE",
-
-            Treasure | TreasureTopaz => "MAGIC",
-
-            BerachainBartio | BerachainArtio => "BERA",
-
-            _ => return None,
-        })
-    }
-
-    /// Returns the chain's blockchain explorer and its API (Etherscan and Etherscan-like) URLs.
-    ///
-    /// Returns `(API_URL, BASE_URL)`.
+    /// Returns `true` if `address` is a predeploy behind a proxy administered by the
+    /// [`predeploy_proxy_admin`](Self::predeploy_proxy_admin).
     ///
-    /// All URLs have no trailing `/`
+    /// On OP-stack chains, every address in the `0x4200000000000000000000000000000000000000` to
+    /// `0x42000000000000000000000000000000000007ff` namespace is proxied, except for `WETH9` and
+    /// the governance token.
     ///
     /// # Examples
     ///
     /// ```
     /// use alloy_chains::NamedChain;
+    /// use alloy_primitives::address;
     ///
-    /// assert_eq!(
-    ///     NamedChain::Mainnet.etherscan_urls(),
-    ///     Some(("https://api.etherscan.io/api", "https://etherscan.io"))
-    /// );
-    /// assert_eq!(
-    ///     NamedChain::Avalanche.etherscan_urls(),
-    ///     Some(("https://api.snowtrace.io/api", "https://snowtrace.io"))
-    /// );
-    /// assert_eq!(NamedChain::AnvilHardhat.etherscan_urls(), None);
+    /// let l1_block = address!("4200000000000000000000000000000000000015");
+    /// let weth = address!("4200000000000000000000000000000000000006");
+    /// assert!(NamedChain::Optimism.is_proxied_predeploy(l1_block));
+    /// assert!(!NamedChain::Optimism.is_proxied_predeploy(weth));
+    /// assert!(!NamedChain::Mainnet.is_proxied_predeploy(l1_block));
     /// ```
-    pub const fn etherscan_urls(self) -> Option<(&'static str, &'static str)> {
-        use NamedChain::*;
-
-        Some(match self {
-            Mainnet => ("https://api.etherscan.io/api", "https://etherscan.io"),
-            Ropsten => ("https://api-ropsten.etherscan.io/api", "https://ropsten.etherscan.io"),
-            Kovan => ("https://api-kovan.etherscan.io/api", "https://kovan.etherscan.io"),
-            Rinkeby => ("https://api-rinkeby.etherscan.io/api", "https://rinkeby.etherscan.io"),
-            Goerli => ("https://api-goerli.etherscan.io/api", "https://goerli.etherscan.io"),
-            Sepolia => ("https://api-sepolia.etherscan.io/api", "https://sepolia.etherscan.io"),
-            Holesky => ("https://api-holesky.etherscan.io/api", "https://holesky.etherscan.io"),
-
-            Polygon => ("https://api.polygonscan.com/api", "https://polygonscan.com"),
-            PolygonMumbai => {
-                ("https://api-testnet.polygonscan.com/api", "https://mumbai.polygonscan.com")
-            }
-            PolygonAmoy => ("https://api-amoy.polygonscan.com/api", "https://amoy.polygonscan.com"),
-
-            PolygonZkEvm => {
-                ("https://api-zkevm.polygonscan.com/api", "https://zkevm.polygonscan.com")
-            }
-            PolygonZkEvmTestnet => (
-                "https://api-testnet-zkevm.polygonscan.com/api",
-                "https://testnet-zkevm.polygonscan.com",
-            ),
-
-            Avalanche => ("https://api.snowtrace.io/api", "https://snowtrace.io"),
-            AvalancheFuji => {
-                ("https://api-testnet.snowtrace.io/api", "https://testnet.snowtrace.io")
-            }
-
-            Optimism => {
-                ("https://api-optimistic.etherscan.io/api", "https://optimistic.etherscan.io")
-            }
-            OptimismGoerli => (
-                "https://api-goerli-optimistic.etherscan.io/api",
-                "https://goerli-optimism.etherscan.io",
-            ),
-            OptimismKovan => (
-                "https://api-kovan-optimistic.etherscan.io/api",
-                "https://kovan-optimistic.etherscan.io",
-            ),
-            OptimismSepolia => (
-                "https://api-sepolia-optimistic.etherscan.io/api",
-                "https://sepolia-optimism.etherscan.io",
-            ),
-
-            Bob => ("https://explorer.gobob.xyz/api", "https://explorer.gobob.xyz"),
-            BobSepolia => (
-                "https://bob-sepolia.explorer.gobob.xyz/api",
-                "https://bob-sepolia.explorer.gobob.xyz",
-            ),
-
-            Fantom => ("https://api.ftmscan.com/api", "https://ftmscan.com"),
-            FantomTestnet => ("https://api-testnet.ftmscan.com/api", "https://testnet.ftmscan.com"),
-
-            BinanceSmartChain => ("https://api.bscscan.com/api", "https://bscscan.com"),
-            BinanceSmartChainTestnet => {
-                ("https://api-testnet.bscscan.com/api", "https://testnet.bscscan.com")
-            }
-
-            OpBNBMainnet => ("https://opbnb.bscscan.com/api", "https://opbnb.bscscan.com"),
-            OpBNBTestnet => {
-                ("https://opbnb-testnet.bscscan.com/api", "https://opbnb-testnet.bscscan.com")
-            }
-
-            Arbitrum => ("https://api.arbiscan.io/api", "https://arbiscan.io"),
-            ArbitrumTestnet => {
-                ("https://api-testnet.arbiscan.io/api", "https://testnet.arbiscan.io")
-            }
-            ArbitrumGoerli => ("https://api-goerli.arbiscan.io/api", "https://goerli.arbiscan.io"),
-            ArbitrumSepolia => {
-                ("https://api-sepolia.arbiscan.io/api", "https://sepolia.arbiscan.io")
-            }
-            ArbitrumNova => ("https://api-nova.arbiscan.io/api", "https://nova.arbiscan.io"),
+    pub const fn is_proxied_predeploy(self, address: Address) -> bool {
+        if !self.is_optimism() {
+            return false;
+        }
 
-            GravityAlphaMainnet => {
-                ("https://explorer.gravity.xyz/api", "https://explorer.gravity.xyz")
-            }
-            GravityAlphaTestnetSepolia => {
-                ("https://explorer-sepolia.gravity.xyz/api", "https://explorer-sepolia.gravity.xyz")
+        let bytes = &address.0 .0;
+        if bytes[0] != 0x42 {
+            return false;
+        }
+        let mut i = 1;
+        while i < 18 {
+            if bytes[i] != 0 {
+                return false;
             }
-            HappychainTestnet => (
-                "https://happy-testnet-sepolia.explorer.caldera.xyz/api",
-                "https://happy-testnet-sepolia.explorer.caldera.xyz",
-            ),
+            i += 1;
+        }
+        // WETH9 and the governance token are not proxied.
+        let index = u16::from_be_bytes([bytes[18], bytes[19]]);
+        index <= 0x07ff && index != 0x06 && index != 0x42
+    }
 
-            XaiSepolia => ("https://sepolia.xaiscan.io/api", "https://sepolia.xaiscan.io"),
-            Xai => ("https://xaiscan.io/api", "https://xaiscan.io"),
+    /// Returns the facts a local fork (e.g. anvil or hardhat) needs to emulate this chain.
+    ///
+    /// See [`ForkProfile`] for more info.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{FeeModel, NamedChain};
+    /// use std::time::Duration;
+    ///
+    /// let profile = NamedChain::Optimism.fork_profile();
+    /// assert_eq!(profile.chain_id, 10);
+    /// assert_eq!(profile.fee_model, FeeModel::Eip1559);
+    /// assert_eq!(profile.block_time, Some(Duration::from_secs(2)));
+    /// assert_eq!(profile.gas_limit, Some(40_000_000));
+    /// assert!(!profile.predeploys.is_empty());
+    ///
+    /// assert_eq!(NamedChain::BinanceSmartChain.fork_profile().fee_model, FeeModel::Legacy);
+    /// ```
+    pub const fn fork_profile(self) -> ForkProfile {
+        ForkProfile::new(self)
+    }
+}
 
-            Syndr => ("https://explorer.syndr.com/api", "https://explorer.syndr.com"),
-            SyndrSepolia => {
-                ("https://sepolia-explorer.syndr.com/api", "https://sepolia-explorer.syndr.com")
-            }
+/// Adds (or, if `undo`, subtracts) the L1-to-L2 alias offset to `address`, wrapping around.
+const fn offset_alias(address: Address, undo: bool) -> Address {
+    const OFFSET: Address = address!("1111000000000000000000000000000000001111");
+
+    let mut bytes = address.0 .0;
+    let mut carry = 0u16;
+    let mut i = bytes.len();
+    while i > 0 {
+        i -= 1;
+        let (a, b) = (bytes[i] as u16, OFFSET.0 .0[i] as u16 + carry);
+        if undo {
+            bytes[i] = a.wrapping_sub(b) as u8;
+            carry = (a < b) as u16;
+        } else {
+            bytes[i] = (a + b) as u8;
+            carry = (a + b) >> 8;
+        }
+    }
+    Address::new(bytes)
+}
 
-            Cronos => ("https://api.cronoscan.com/api", "https://cronoscan.com"),
-            CronosTestnet => {
-                ("https://api-testnet.cronoscan.com/api", "https://testnet.cronoscan.com")
-            }
+/// Returns the last two bytes of `address` if all the others are zero.
+pub(crate) const fn low_address(address: Address) -> Option<u16> {
+    let bytes = &address.0 .0;
+    let mut i = 0;
+    while i < 18 {
+        if bytes[i] != 0 {
+            return None;
+        }
+        i += 1;
+    }
+    Some(u16::from_be_bytes([bytes[18], bytes[19]]))
+}
 
-            Moonbeam => ("https://api-moonbeam.moonscan.io/api", "https://moonbeam.moonscan.io"),
-            Moonbase => ("https://api-moonbase.moonscan.io/api", "https://moonbase.moonscan.io"),
-            Moonriver => ("https://api-moonriver.moonscan.io/api", "https://moonriver.moonscan.io"),
+/// Metadata tables generated from `assets/chains.toml`.
+mod data {
+    use super::NamedChain;
+    use crate::{Date, ExplorerInfo, MevEndpoint, NativeCurrency};
 
-            Gnosis => ("https://api.gnosisscan.io/api", "https://gnosisscan.io"),
+    include!("named_data.rs");
+}
 
-            Scroll => ("https://api.scrollscan.com/api", "https://scrollscan.com"),
-            ScrollSepolia => {
-                ("https://api-sepolia.scrollscan.com/api", "https://sepolia.scrollscan.com")
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::{EnumCount, IntoEnumIterator};
 
-            Ink => ("https://explorer.inkonchain.com/api/v2", "https://explorer.inkonchain.com"),
-            InkSepolia => (
-                "https://explorer-sepolia.inkonchain.com/api/v2",
-                "https://explorer-sepolia.inkonchain.com",
-            ),
+    #[allow(unused_imports)]
+    use alloc::string::ToString;
 
-            Shimmer => {
-                ("https://explorer.evm.shimmer.network/api", "https://explorer.evm.shimmer.network")
-            }
+    #[test]
+    #[cfg(feature = "serde")]
+    fn default() {
+        assert_eq!(serde_json::to_string(&NamedChain::default()).unwrap(), "\"mainnet\"");
+    }
 
-            Metis => (
-                "https://api.routescan.io/v2/network/mainnet/evm/1088/etherscan",
-                "https://explorer.metis.io",
-            ),
+    #[test]
+    fn enum_iter() {
+        assert_eq!(NamedChain::COUNT, NamedChain::iter().size_hint().0);
+    }
 
-            Chiado => {
-                ("https://blockscout.chiadochain.net/api", "https://blockscout.chiadochain.net")
-            }
+    #[test]
+    fn sorted_iterators() {
+        let by_id: alloc::vec::Vec<_> = NamedChain::iter_sorted_by_id().collect();
+        assert_eq!(by_id.len(), NamedChain::COUNT);
+        assert!(by_id.windows(2).all(|w| (w[0] as u64) < (w[1] as u64)));
+
+        let by_name: alloc::vec::Vec<_> = NamedChain::iter_sorted_by_name().collect();
+        assert_eq!(by_name.len(), NamedChain::COUNT);
+        assert!(by_name.windows(2).all(|w| w[0].as_str() < w[1].as_str()));
+    }
 
-            FilecoinCalibrationTestnet => (
-                "https://api.calibration.node.glif.io/rpc/v1",
-                "https://calibration.filfox.info/en",
-            ),
+    #[test]
+    fn search_exact_name_first() {
+        for chain in NamedChain::iter() {
+            assert_eq!(NamedChain::search(chain.as_str())[0], chain);
+        }
+        assert!(NamedChain::search("").is_empty());
+    }
 
-            Sokol => ("https://blockscout.com/poa/sokol/api", "https://blockscout.com/poa/sokol"),
+    #[test]
+    fn roundtrip_string() {
+        for chain in NamedChain::iter() {
+            let chain_string = chain.to_string();
+            assert_eq!(chain_string, format!("{chain}"));
+            assert_eq!(chain_string.as_str(), chain.as_ref());
+            #[cfg(feature = "serde")]
+            assert_eq!(serde_json::to_string(&chain).unwrap(), format!("\"{chain_string}\""));
 
-            Poa => ("https://blockscout.com/poa/core/api", "https://blockscout.com/poa/core"),
+            assert_eq!(chain_string.parse::<NamedChain>().unwrap(), chain);
+        }
+    }
 
-            Rsk => ("https://blockscout.com/rsk/mainnet/api", "https://blockscout.com/rsk/mainnet"),
+    #[test]
+    #[cfg(feature = "serde")]
+    fn roundtrip_serde() {
+        for chain in NamedChain::iter() {
+            let chain_string = serde_json::to_string(&chain).unwrap();
+            let chain_string = chain_string.replace('-', "_");
+            assert_eq!(serde_json::from_str::<'_, NamedChain>(&chain_string).unwrap(), chain);
+        }
+    }
 
-            Oasis => ("https://scan.oasischain.io/api", "https://scan.oasischain.io"),
+    #[test]
+    fn aliases() {
+        use NamedChain::*;
 
-            Emerald => {
-                ("https://explorer.emerald.oasis.dev/api", "https://explorer.emerald.oasis.dev")
-            }
-            EmeraldTestnet => (
-                "https://testnet.explorer.emerald.oasis.dev/api",
-                "https://testnet.explorer.emerald.oasis.dev",
+        // kebab-case
+        const ALIASES: &[(NamedChain, &[&str])] = &[
+            (Mainnet, &["ethlive"]),
+            (BinanceSmartChain, &["bsc", "bnb-smart-chain", "binance-smart-chain"]),
+            (
+                BinanceSmartChainTestnet,
+                &["bsc-testnet", "bnb-smart-chain-testnet", "binance-smart-chain-testnet"],
             ),
+            (Gnosis, &["gnosis", "gnosis-chain"]),
+            (Polygon, &["matic"]),
+            (PolygonMumbai, &["mumbai"]),
+            (Fantom, &["ftm"]),
+            (Avalanche, &["avax"]),
+            (Kaia, &["klaytn", "klay"]),
+            (PolygonZkEvm, &["zkevm", "polygon-zkevm"]),
+            (PolygonZkEvmTestnet, &["zkevm-testnet", "polygon-zkevm-testnet"]),
+            (AnvilHardhat, &["anvil", "hardhat"]),
+            (AvalancheFuji, &["fuji"]),
+            (ZkSync, &["zksync"]),
+            (Mantle, &["mantle"]),
+            (MantleTestnet, &["mantle-testnet"]),
+            (MantleSepolia, &["mantle-sepolia"]),
+            (GravityAlphaMainnet, &["gravity-alpha-mainnet"]),
+            (GravityAlphaTestnetSepolia, &["gravity-alpha-testnet-sepolia"]),
+            (Bob, &["bob"]),
+            (BobSepolia, &["bob-sepolia"]),
+            (HappychainTestnet, &["happychain-testnet"]),
+            (Xai, &["xai"]),
+            (XaiSepolia, &["xai-sepolia"]),
+            (Base, &["base"]),
+            (BaseGoerli, &["base-goerli"]),
+            (BaseSepolia, &["base-sepolia"]),
+            (Fraxtal, &["fraxtal"]),
+            (FraxtalTestnet, &["fraxtal-testnet"]),
+            (Ink, &["ink"]),
+            (InkSepolia, &["ink-sepolia"]),
+            (BlastSepolia, &["blast-sepolia"]),
+            (Syndr, &["syndr"]),
+            (SyndrSepolia, &["syndr-sepolia"]),
+            (LineaGoerli, &["linea-goerli"]),
+            (LineaSepolia, &["linea-sepolia"]),
+            (AutonomysNovaTestnet, &["autonomys-nova-testnet"]),
+            (Immutable, &["immutable"]),
+            (ImmutableTestnet, &["immutable-testnet"]),
+            (SoneiumMinatoTestnet, &["soneium-minato-testnet"]),
+            (ApeChain, &["apechain"]),
+            (Curtis, &["apechain-testnet", "curtis"]),
+            (Treasure, &["treasure"]),
+            (TreasureTopaz, &["treasure-topaz-testnet", "treasure-topaz"]),
+            (BerachainArtio, &["berachain-artio-testnet", "berachain-artio"]),
+            (BerachainBartio, &["berachain-bartio-testnet", "berachain-bartio"]),
+        ];
 
-            Aurora => ("https://api.aurorascan.dev/api", "https://aurorascan.dev"),
-            AuroraTestnet => {
-                ("https://testnet.aurorascan.dev/api", "https://testnet.aurorascan.dev")
-            }
+        for &(chain, aliases) in ALIASES {
+            for &alias in aliases {
+                let named = alias.parse::<NamedChain>().expect(alias);
+                assert_eq!(named, chain);
 
-            Evmos => ("https://evm.evmos.org/api", "https://evm.evmos.org"),
-            EvmosTestnet => ("https://evm.evmos.dev/api", "https://evm.evmos.dev"),
+                #[cfg(feature = "serde")]
+                {
+                    assert_eq!(
+                        serde_json::from_str::<NamedChain>(&format!("\"{alias}\"")).unwrap(),
+                        chain
+                    );
 
-            Celo => ("https://api.celoscan.io/api", "https://celoscan.io"),
-            CeloAlfajores => {
-                ("https://api-alfajores.celoscan.io/api", "https://alfajores.celoscan.io")
-            }
-            CeloBaklava => {
-                ("https://explorer.celo.org/baklava/api", "https://explorer.celo.org/baklava")
+                    assert_eq!(
+                        serde_json::from_str::<NamedChain>(&format!("\"{named}\"")).unwrap(),
+                        chain
+                    );
+                }
             }
+        }
+    }
 
-            Canto => ("https://evm.explorer.canto.io/api", "https://evm.explorer.canto.io"),
-            CantoTestnet => (
-                "https://testnet-explorer.canto.neobase.one/api",
-                "https://testnet-explorer.canto.neobase.one",
-            ),
-
-            Boba => ("https://api.bobascan.com/api", "https://bobascan.com"),
-
-            Base => ("https://api.basescan.org/api", "https://basescan.org"),
-            BaseGoerli => ("https://api-goerli.basescan.org/api", "https://goerli.basescan.org"),
-            BaseSepolia => ("https://api-sepolia.basescan.org/api", "https://sepolia.basescan.org"),
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_to_string_match() {
+        for chain in NamedChain::iter() {
+            let chain_serde = serde_json::to_string(&chain).unwrap();
+            let chain_string = format!("\"{chain}\"");
+            assert_eq!(chain_serde, chain_string);
+        }
+    }
 
-            Fraxtal => ("https://api.fraxscan.com/api", "https://fraxscan.com"),
-            FraxtalTestnet => {
-                ("https://api-holesky.fraxscan.com/api", "https://holesky.fraxscan.com")
-            }
+    #[test]
+    fn test_dns_network() {
+        let s = "enrtree://AKA3AM6LPBYEUDMVNU3BSVQJ5AD45Y7YPOHJLEF6W26QOE4VTUDPE@all.mainnet.ethdisco.net";
+        assert_eq!(NamedChain::Mainnet.public_dns_network_protocol().unwrap(), s);
+        for chain in NamedChain::iter() {
+            assert_eq!(
+                chain.public_dns_network_protocol().is_some(),
+                chain.dns_discovery_tree().is_some()
+            );
+        }
+    }
 
-            Blast => ("https://api.blastscan.io/api", "https://blastscan.io"),
-            BlastSepolia => {
-                ("https://api-sepolia.blastscan.io/api", "https://sepolia.blastscan.io")
+    #[test]
+    fn op_l1_contracts_only_for_optimism() {
+        for chain in NamedChain::iter() {
+            if chain.op_l1_contracts().is_some() {
+                assert!(chain.is_optimism(), "{chain:?} has OP L1 contracts but is not OP-stack");
             }
+        }
+    }
 
-            ZkSync => ("https://api-era.zksync.network/api", "https://era.zksync.network"),
-            ZkSyncTestnet => {
-                ("https://api-sepolia-era.zksync.network/api", "https://sepolia-era.zksync.network")
+    #[test]
+    fn arbitrum_l1_contracts_only_for_arbitrum() {
+        for chain in NamedChain::iter() {
+            if let Some(contracts) = chain.arbitrum_l1_contracts() {
+                let parent = contracts.parent_chain;
+                // Orbit chains are not `is_arbitrum`, but settle on one.
+                assert!(
+                    chain.is_arbitrum() || parent.is_arbitrum(),
+                    "{chain:?} has Arbitrum L1 contracts but is not Arbitrum"
+                );
+                assert_eq!(chain.is_testnet(), parent.is_testnet(), "{chain:?}");
             }
+        }
+    }
 
-            Linea => ("https://api.lineascan.build/api", "https://lineascan.build"),
-            LineaGoerli => {
-                ("https://explorer.goerli.linea.build/api", "https://explorer.goerli.linea.build")
-            }
-            LineaSepolia => {
-                ("https://api-sepolia.lineascan.build/api", "https://sepolia.lineascan.build")
+    #[test]
+    fn op_hardforks_sorted() {
+        for chain in NamedChain::iter() {
+            let forks = chain.op_hardforks();
+            assert!(forks.is_empty() || chain.is_optimism(), "{chain:?}");
+            for w in forks.windows(2) {
+                assert!(w[0].0 < w[1].0 && w[0].1 <= w[1].1, "{chain:?} forks are not sorted");
             }
+            assert!(forks.iter().all(|(fork, _)| fork.is_optimism()), "{chain:?}");
+        }
+    }
 
-            Mantle => ("https://explorer.mantle.xyz/api", "https://explorer.mantle.xyz"),
-            MantleTestnet => {
-                ("https://explorer.testnet.mantle.xyz/api", "https://explorer.testnet.mantle.xyz")
-            }
-            MantleSepolia => {
-                ("https://explorer.sepolia.mantle.xyz/api", "https://explorer.sepolia.mantle.xyz")
+    #[cfg(feature = "std")]
+    #[test]
+    fn op_hardforks_activated() {
+        let now =
+            std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
+        for chain in NamedChain::iter() {
+            for &(fork, timestamp) in chain.op_hardforks() {
+                assert!(timestamp <= now, "{chain:?} {fork} is not active yet");
             }
+            assert_eq!(chain.latest_op_fork(), chain.op_fork_at(now), "{chain:?}");
+        }
+    }
 
-            Viction => ("https://www.vicscan.xyz/api", "https://www.vicscan.xyz"),
+    #[test]
+    fn network_ids() {
+        let differing: alloc::vec::Vec<_> =
+            NamedChain::iter().filter(|&chain| chain.network_id() != chain as u64).collect();
+        assert_eq!(differing, [NamedChain::Rsk]);
+        assert_eq!(NamedChain::Mainnet.network_id(), 1);
+    }
 
-            Zora => ("https://explorer.zora.energy/api", "https://explorer.zora.energy"),
-            ZoraGoerli => {
-                ("https://testnet.explorer.zora.energy/api", "https://testnet.explorer.zora.energy")
-            }
-            ZoraSepolia => {
-                ("https://sepolia.explorer.zora.energy/api", "https://sepolia.explorer.zora.energy")
+    #[test]
+    fn beacon_metadata_only_for_beacon_chains() {
+        for chain in NamedChain::iter() {
+            if chain.beacon_api_url().is_some() || chain.beacon_deposit_contract().is_some() {
+                assert!(chain.has_beacon_chain(), "{chain:?}");
             }
+        }
+    }
 
-            Pgn => {
-                ("https://explorer.publicgoods.network/api", "https://explorer.publicgoods.network")
+    #[test]
+    fn challenge_period_only_for_rollups() {
+        for chain in NamedChain::iter() {
+            if chain.challenge_period().is_some() {
+                assert!(chain.is_optimism() || chain.is_arbitrum(), "{chain:?}");
+                assert_eq!(chain.time_to_finality_hint(), None, "{chain:?}");
             }
+        }
+    }
 
-            PgnSepolia => (
-                "https://explorer.sepolia.publicgoods.network/api",
-                "https://explorer.sepolia.publicgoods.network",
-            ),
-
-            Mode => ("https://explorer.mode.network/api", "https://explorer.mode.network"),
-            ModeSepolia => (
-                "https://sepolia.explorer.mode.network/api",
-                "https://sepolia.explorer.mode.network",
-            ),
-
-            Elastos => ("https://esc.elastos.io/api", "https://esc.elastos.io"),
-
-            AnvilHardhat | Dev | Morden | MoonbeamDev | FilecoinMainnet | AutonomysNovaTestnet
-            | Iotex => {
-                return None;
-            }
-            KakarotSepolia => {
-                ("https://sepolia.kakarotscan.org/api", "https://sepolia.kakarotscan.org")
-            }
-            Etherlink => ("https://explorer.etherlink.com/api", "https://explorer.etherlink.com"),
-            EtherlinkTestnet => (
-                "https://testnet-explorer.etherlink.com/api",
-                "https://testnet-explorer.etherlink.com",
-            ),
-            Degen => ("https://explorer.degen.tips/api", "https://explorer.degen.tips"),
-            Ronin => ("https://skynet-api.roninchain.com/ronin", "https://app.roninchain.com"),
-            RoninTestnet => (
-                "https://api-gateway.skymavis.com/rpc/testnet",
-                "https://saigon-app.roninchain.com",
-            ),
-            Taiko => ("https://api.taikoscan.io/api", "https://taikoscan.io"),
-            TaikoHekla => ("https://api-testnet.taikoscan.io/api", "https://hekla.taikoscan.io"),
-            Flare => {
-                ("https://flare-explorer.flare.network/api", "https://flare-explorer.flare.network")
-            }
-            FlareCoston2 => (
-                "https://coston2-explorer.flare.network/api",
-                "https://coston2-explorer.flare.network",
-            ),
-            Acala => ("https://blockscout.acala.network/api", "https://blockscout.acala.network"),
-            AcalaMandalaTestnet => (
-                "https://blockscout.mandala.aca-staging.network/api",
-                "https://blockscout.mandala.aca-staging.network",
-            ),
-            AcalaTestnet => (
-                "https://blockscout.acala-testnet.aca-staging.network/api",
-                "https://blockscout.acala-testnet.aca-staging.network",
-            ),
-            Karura => {
-                ("https://blockscout.karura.network/api", "https://blockscout.karura.network")
+    #[test]
+    fn valueless_currencies_have_distinct_symbols() {
+        let mainnet_symbols: alloc::vec::Vec<_> = NamedChain::iter()
+            .filter(|chain| !chain.is_valueless_currency())
+            .filter_map(NamedChain::native_currency_symbol)
+            .collect();
+        for chain in NamedChain::iter().filter(|chain| chain.is_valueless_currency()) {
+            if let Some(symbol) = chain.native_currency_symbol() {
+                assert!(
+                    !mainnet_symbols.contains(&symbol),
+                    "{chain:?} reuses mainnet symbol {symbol}"
+                );
             }
-            KaruraTestnet => (
-                "https://blockscout.karura-testnet.aca-staging.network/api",
-                "https://blockscout.karura-testnet.aca-staging.network",
-            ),
+        }
+    }
 
-            Darwinia => {
-                ("https://explorer.darwinia.network/api", "https://explorer.darwinia.network")
-            }
-            Crab => {
-                ("https://crab-scan.darwinia.network/api", "https://crab-scan.darwinia.network")
-            }
-            Koi => ("https://koi-scan.darwinia.network/api", "https://koi-scan.darwinia.network"),
-            Cfx => ("https://evmapi.confluxscan.net/api", "https://evm.confluxscan.io"),
-            CfxTestnet => {
-                ("https://evmapi-testnet.confluxscan.net/api", "https://evmtestnet.confluxscan.io")
+    #[test]
+    fn bootnodes_are_enodes() {
+        for chain in NamedChain::iter() {
+            for enode in chain.bootnodes().unwrap_or_default() {
+                let (id, addr) = enode.strip_prefix("enode://").unwrap().split_once('@').unwrap();
+                assert_eq!(id.len(), 128, "{chain:?}: {enode}");
+                assert!(id.bytes().all(|b| b.is_ascii_hexdigit()), "{chain:?}: {enode}");
+                assert!(addr.contains(':'), "{chain:?}: {enode}");
             }
-            Pulsechain => ("https://api.scan.pulsechain.com", "https://scan.pulsechain.com"),
-            PulsechainTestnet => (
-                "https://api.scan.v4.testnet.pulsechain.com",
-                "https://scan.v4.testnet.pulsechain.com",
-            ),
+        }
+    }
 
-            Immutable => ("https://explorer.immutable.com/api", "https://explorer.immutable.com"),
-            ImmutableTestnet => (
-                "https://explorer.testnet.immutable.com/api",
-                "https://explorer.testnet.immutable.com",
-            ),
-            SoneiumMinatoTestnet => {
-                ("https://explorer-testnet.soneium.org/api", "https://explorer-testnet.soneium.org")
-            }
-            Odyssey => {
-                ("https://odyssey-explorer.ithaca.xyz/api", "https://odyssey-explorer.ithaca.xyz")
-            }
-            World => ("https://api.worldscan.org/api", "https://worldscan.org"),
-            WorldSepolia => {
-                ("https://api-sepolia.worldscan.org/api", "https://sepolia.worldscan.org")
-            }
-            UnichainSepolia => {
-                ("https://sepolia.uniscan.xyz", "https://api-sepolia.uniscan.xyz/api")
+    #[test]
+    fn wrapped_native_token_info() {
+        for chain in NamedChain::iter() {
+            assert_eq!(
+                chain.wrapped_native_token_info().map(|token| token.address),
+                chain.wrapped_native_token()
+            );
+            if let Some(token) = chain.wrapped_native_token_info() {
+                assert!(token.symbol.starts_with('W'), "{chain}: {}", token.symbol);
+                assert_eq!(token.decimals, 18, "{chain}");
             }
-            Core => ("https://scan.coredao.org", "https://openapi.coredao.org/api"),
-            Merlin => ("https://scan.merlinchain.io", "https://scan.merlinchain.io/api"),
-            Bitlayer => ("https://www.btrscan.com", "https://api.btrscan.com/scan/api"),
-            Vana => ("https://vanascan.io", "https://instance_base_url/api"),
-            Zeta => ("https://zetachain.blockscout.com", "https://zetachain.blockscout.com/api"),
-            Kaia => ("https://kaiascan.io", "https://mainnet-oapi.kaiascan.io/api"),
-
-            ApeChain => ("https://api.apescan.io/api", "https://apescan.io"),
-            Curtis => ("https://curtis.explorer.caldera.xyz/api/v2", "https://curtis.apescan.io"),
-            SonicTestnet => (
-                "https://api.routescan.io/v2/network/testnet/evm/64165/etherscan/api",
-                "https://scan.soniclabs.com",
-            ),
-
-            Treasure => ("https://block-explorer.treasurescan.io/api", "https://treasurescan.io"),
-            TreasureTopaz => (
-                "https://block-explorer.topaz.treasurescan.io/api",
-                "https://topaz.treasurescan.io",
-            ),
-            BerachainBartio => ("https://bartio.beratrail.io/api", "https://bartio.beratrail.io"),
-            BerachainArtio => ("https://artio.beratrail.io/api", "https://artio.beratrail.io"),
-        })
+        }
+        assert!(NamedChain::Arbitrum.wrapped_native_token_info().unwrap().supports_permit);
+        assert!(!NamedChain::Mainnet.wrapped_native_token_info().unwrap().supports_permit);
     }
 
-    /// Returns the chain's blockchain explorer's API key environment variable's default name.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use alloy_chains::NamedChain;
-    ///
-    /// assert_eq!(NamedChain::Mainnet.etherscan_api_key_name(), Some("ETHERSCAN_API_KEY"));
-    /// assert_eq!(NamedChain::AnvilHardhat.etherscan_api_key_name(), None);
-    /// ```
-    pub const fn etherscan_api_key_name(self) -> Option<&'static str> {
-        use NamedChain::*;
+    #[test]
+    fn fork_profile() {
+        for chain in NamedChain::iter() {
+            let profile = chain.fork_profile();
+            assert_eq!(profile.chain_id, chain as u64);
+            assert_eq!(profile.fee_model == crate::FeeModel::Legacy, chain.is_legacy(), "{chain}");
+            assert_eq!(profile.block_time, chain.average_blocktime_hint());
+            assert_eq!(profile.gas_limit, chain.block_gas_limit_hint());
+            assert!(profile.predeploys.windows(2).all(|w| w[0] < w[1]), "{chain}");
+        }
+    }
 
-        let api_key_name = match self {
-            Mainnet
-            | Morden
-            | Ropsten
-            | Kovan
-            | Rinkeby
-            | Goerli
-            | Holesky
-            | Optimism
-            | OptimismGoerli
-            | OptimismKovan
-            | OptimismSepolia
-            | BinanceSmartChain
-            | BinanceSmartChainTestnet
-            | OpBNBMainnet
-            | OpBNBTestnet
-            | Arbitrum
-            | ArbitrumTestnet
-            | ArbitrumGoerli
-            | ArbitrumSepolia
-            | ArbitrumNova
-            | Syndr
-            | SyndrSepolia
-            | Cronos
-            | CronosTestnet
-            | Aurora
-            | AuroraTestnet
-            | Celo
-            | CeloAlfajores
-            | Base
-            | Linea
-            | LineaSepolia
-            | Mantle
-            | MantleTestnet
-            | MantleSepolia
-            | Xai
-            | XaiSepolia
-            | BaseGoerli
-            | BaseSepolia
-            | Fraxtal
-            | FraxtalTestnet
-            | Blast
-            | BlastSepolia
-            | Gnosis
-            | Scroll
-            | ScrollSepolia
-            | Taiko
-            | TaikoHekla
-            | UnichainSepolia
-            | ApeChain => "ETHERSCAN_API_KEY",
+    #[test]
+    fn predeploys_match_families() {
+        let l1_block = address!("4200000000000000000000000000000000000015");
+        let arb_sys = address!("0000000000000000000000000000000000000064");
+        for chain in NamedChain::iter() {
+            assert_eq!(chain.predeploys().contains(&l1_block), chain.is_optimism(), "{chain}");
+            assert_eq!(chain.predeploys().contains(&arb_sys), chain.is_arbitrum(), "{chain}");
+        }
+    }
 
-            Avalanche | AvalancheFuji => "SNOWTRACE_API_KEY",
+    #[test]
+    #[cfg(not(feature = "slim"))]
+    fn native_currency_decimals_coverage() {
+        use NamedChain::*;
 
-            Polygon | PolygonMumbai | PolygonAmoy | PolygonZkEvm | PolygonZkEvmTestnet => {
-                "POLYGONSCAN_API_KEY"
-            }
+        // Local development chains, and chains whose currency has not been curated yet.
+        let uncovered =
+            [Dev, AnvilHardhat, MoonbeamDev, Oasis, AuroraTestnet, Fraxtal, FraxtalTestnet];
+        for chain in NamedChain::iter() {
+            // `mainnets-only` strips the testnet entries.
+            let stripped = cfg!(feature = "mainnets-only") && chain.is_testnet();
+            assert_eq!(
+                chain.native_currency_decimals().is_none(),
+                uncovered.contains(&chain) || stripped,
+                "{chain:?}"
+            );
+        }
+    }
 
-            Fantom | FantomTestnet => "FTMSCAN_API_KEY",
+    #[test]
+    fn supported_precompiles_are_sorted_by_address() {
+        for chain in NamedChain::iter() {
+            let precompiles = chain.supported_precompiles();
+            assert!(precompiles.windows(2).all(|w| w[0] < w[1]), "{chain:?}");
+            assert!(precompiles.windows(2).all(|w| w[0].address() < w[1].address()), "{chain:?}");
+        }
+    }
 
-            Moonbeam | Moonbase | MoonbeamDev | Moonriver => "MOONSCAN_API_KEY",
+    #[test]
+    fn op_forked_chains_have_precompiles() {
+        for chain in NamedChain::iter().filter(|chain| chain.latest_op_fork().is_some()) {
+            assert!(!chain.supported_precompiles().is_empty(), "{chain:?}");
+        }
+    }
 
-            Acala | AcalaMandalaTestnet | AcalaTestnet | Canto | CantoTestnet | CeloBaklava
-            | Etherlink | EtherlinkTestnet | Flare | FlareCoston2 | KakarotSepolia | Karura
-            | KaruraTestnet | Mode | ModeSepolia | Pgn | PgnSepolia | Shimmer | Zora
-            | ZoraGoerli | ZoraSepolia | Darwinia | Crab | Koi | Immutable | ImmutableTestnet
-            | SoneiumMinatoTestnet | World | WorldSepolia | Curtis | Ink | InkSepolia => {
-                "BLOCKSCOUT_API_KEY"
-            }
+    #[test]
+    #[cfg(feature = "mainnets-only")]
+    fn mainnets_only_strips_testnet_metadata() {
+        for chain in NamedChain::iter().filter(|chain| chain.is_testnet()) {
+            assert_eq!(chain.average_blocktime_hint(), None, "{chain:?}");
+            assert_eq!(chain.native_currency(), None, "{chain:?}");
+            assert!(chain.explorers().is_empty(), "{chain:?}");
+        }
+        assert!(NamedChain::Mainnet.average_blocktime_hint().is_some());
+    }
 
-            Boba => "BOBASCAN_API_KEY",
+    #[test]
+    fn names_and_aliases_parse() {
+        let names = NamedChain::names_and_aliases();
+        assert!(names.windows(2).all(|w| w[0].0 < w[1].0));
+        for &(name, chain) in names {
+            assert_eq!(name.parse::<NamedChain>(), Ok(chain), "{name}");
+        }
+        for chain in NamedChain::iter() {
+            assert!(names.contains(&(chain.as_str(), chain)), "{chain:?}");
+        }
+    }
 
-            Core => "CORESCAN_API_KEY",
-            Merlin => "MERLINSCAN_API_KEY",
-            Bitlayer => "BITLAYERSCAN_API_KEY",
-            Vana => "VANASCAN_API_KEY",
-            Zeta => "ZETASCAN_API_KEY",
-            Kaia => "KAIASCAN_API_KEY",
+    #[test]
+    fn token_bridge_pairs_are_sorted() {
+        for chain in NamedChain::iter() {
+            let pairs = chain.token_bridge_pairs();
+            assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0), "{chain:?}");
+            assert!(pairs.is_empty() || chain.is_optimism() || chain.is_arbitrum(), "{chain:?}");
+        }
+    }
 
-            // Explicitly exhaustive. See NB above.
-            Metis
-            | Chiado
-            | Odyssey
-            | Sepolia
-            | Rsk
-            | Sokol
-            | Poa
-            | Oasis
-            | Emerald
-            | EmeraldTestnet
-            | Evmos
-            | EvmosTestnet
-            | AnvilHardhat
-            | Dev
-            | GravityAlphaMainnet
-            | GravityAlphaTestnetSepolia
-            | Bob
-            | BobSepolia
-            | ZkSync
-            | ZkSyncTestnet
-            | FilecoinMainnet
-            | LineaGoerli
-            | FilecoinCalibrationTestnet
-            | Viction
-            | Elastos
-            | Degen
-            | Ronin
-            | RoninTestnet
-            | Cfx
-            | CfxTestnet
-            | Pulsechain
-            | PulsechainTestnet
-            | AutonomysNovaTestnet
-            | Iotex
-            | HappychainTestnet
-            | SonicTestnet
-            | Treasure
-            | TreasureTopaz
-            | BerachainBartio
-            | BerachainArtio => return None,
-        };
+    #[test]
+    fn proof_metadata_only_for_validity_rollups() {
+        for chain in NamedChain::iter() {
+            let validity = chain.rollup_type() == Some(RollupType::Validity);
+            assert!(chain.proof_system().is_none() || validity, "{chain:?}");
+            assert!(chain.proof_latency_hint().is_none() || validity, "{chain:?}");
+        }
+    }
 
-        Some(api_key_name)
+    #[test]
+    fn const_name_matches_as_str() {
+        for chain in NamedChain::iter() {
+            assert_eq!(chain.name(), chain.as_str());
+        }
     }
 
-    /// Returns the chain's blockchain explorer's API key, from the environment variable with the
-    /// name specified in [`etherscan_api_key_name`](NamedChain::etherscan_api_key_name).
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use alloy_chains::NamedChain;
-    ///
-    /// let chain = NamedChain::Mainnet;
-    /// std::env::set_var(chain.etherscan_api_key_name().unwrap(), "KEY");
-    /// assert_eq!(chain.etherscan_api_key().as_deref(), Some("KEY"));
-    /// ```
-    #[cfg(feature = "std")]
-    pub fn etherscan_api_key(self) -> Option<String> {
-        self.etherscan_api_key_name().and_then(|name| std::env::var(name).ok())
+    #[test]
+    #[cfg(not(feature = "slim"))]
+    fn from_explorer_url() {
+        use NamedChain::*;
+
+        for chain in NamedChain::iter() {
+            for explorer in chain.explorers() {
+                let url = format!("{}/tx/0x1234", explorer.base_url);
+                assert_eq!(NamedChain::from_explorer_url(&url), Some(chain), "{url}");
+            }
+        }
+        assert_eq!(NamedChain::from_explorer_url("HTTPS://www.Etherscan.io:443"), Some(Mainnet));
+        assert_eq!(NamedChain::from_explorer_url("https://blockscout.com/poa/sokol"), Some(Sokol));
+        assert_eq!(NamedChain::from_explorer_url("https://blockscout.com/poa/sokolx"), None);
+        assert_eq!(NamedChain::from_explorer_url("https://etherscan.io.evil.com"), None);
+        assert_eq!(NamedChain::from_explorer_url(""), None);
     }
 
-    /// Returns the address of the public DNS node list for the given chain.
-    ///
-    /// See also <https://github.com/ethereum/discv4-dns-lists>.
-    pub fn public_dns_network_protocol(self) -> Option<String> {
-        use NamedChain::*;
+    #[test]
+    fn ui_sort_weight_puts_testnets_last() {
+        let max_mainnet = NamedChain::iter()
+            .filter(|chain| !chain.is_testnet())
+            .map(NamedChain::ui_sort_weight)
+            .max()
+            .unwrap();
+        for chain in NamedChain::iter().filter(|chain| chain.is_testnet()) {
+            assert!(chain.ui_sort_weight() > max_mainnet, "{chain:?}");
+        }
+    }
 
-        const DNS_PREFIX: &str = "enrtree://AKA3AM6LPBYEUDMVNU3BSVQJ5AD45Y7YPOHJLEF6W26QOE4VTUDPE@";
-        if let Mainnet | Goerli | Sepolia | Ropsten | Rinkeby | Holesky = self {
-            // `{DNS_PREFIX}all.{self.lower()}.ethdisco.net`
-            let mut s = String::with_capacity(DNS_PREFIX.len() + 32);
-            s.push_str(DNS_PREFIX);
-            s.push_str("all.");
-            let chain_str = self.as_ref();
-            s.push_str(chain_str);
-            let l = s.len();
-            s[l - chain_str.len()..].make_ascii_lowercase();
-            s.push_str(".ethdisco.net");
-
-            Some(s)
-        } else {
-            None
+    #[test]
+    fn l1_to_l2_alias_roundtrip() {
+        let chain = NamedChain::Optimism;
+        for address in [Address::ZERO, Address::repeat_byte(0xee), Address::repeat_byte(0xff)] {
+            let aliased = chain.apply_l1_to_l2_alias(address).unwrap();
+            assert_eq!(chain.undo_l1_to_l2_alias(aliased), Some(address));
         }
+        assert_eq!(
+            chain.apply_l1_to_l2_alias(Address::ZERO),
+            Some(address!("1111000000000000000000000000000000001111"))
+        );
     }
 
-    /// Returns the address of the most popular wrapped native token address for this chain, if it
-    /// exists.
-    ///
-    /// Example:
-    ///
-    /// ```
-    /// use alloy_chains::NamedChain;
-    /// use alloy_primitives::address;
-    ///
-    /// let chain = NamedChain::Mainnet;
-    /// assert_eq!(
-    ///     chain.wrapped_native_token(),
-    ///     Some(address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"))
-    /// );
-    /// ```
-    pub const fn wrapped_native_token(self) -> Option<Address> {
-        use NamedChain::*;
+    #[test]
+    fn strict_parsing_rejects_deprecated_chains() {
+        for chain in NamedChain::iter().filter(|chain| chain.is_deprecated()) {
+            assert!(NamedChain::from_str_strict(chain.as_str()).is_err());
+            assert!(NamedChain::try_from_id_strict(chain as u64).is_err());
+        }
+    }
 
-        let addr = match self {
-            Mainnet => address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
-            Optimism => address!("4200000000000000000000000000000000000006"),
-            BinanceSmartChain => address!("bb4cdb9cbd36b01bd1cbaebf2de08d9173bc095c"),
-            OpBNBMainnet => address!("4200000000000000000000000000000000000006"),
-            Arbitrum => address!("82af49447d8a07e3bd95bd0d56f35241523fbab1"),
-            Base => address!("4200000000000000000000000000000000000006"),
-            Linea => address!("e5d7c2a44ffddf6b295a15c148167daaaf5cf34f"),
-            Mantle => address!("deaddeaddeaddeaddeaddeaddeaddeaddead1111"),
-            Blast => address!("4300000000000000000000000000000000000004"),
-            Gnosis => address!("e91d153e0b41518a2ce8dd3d7944fa863463a97d"),
-            Scroll => address!("5300000000000000000000000000000000000004"),
-            Taiko => address!("a51894664a773981c6c112c43ce576f315d5b1b6"),
-            Avalanche => address!("b31f66aa3c1e785363f0875a1b74e27b85fd66c7"),
-            Polygon => address!("0d500b1d8e8ef31e21c99d1db9a6444d3adf1270"),
-            Fantom => address!("21be370d5312f44cb42ce377bc9b8a0cef1a4c83"),
-            Iotex => address!("a00744882684c3e4747faefd68d283ea44099d03"),
-            Core => address!("40375C92d9FAf44d2f9db9Bd9ba41a3317a2404f"),
-            Merlin => address!("F6D226f9Dc15d9bB51182815b320D3fBE324e1bA"),
-            Bitlayer => address!("ff204e2681a6fa0e2c3fade68a1b28fb90e4fc5f"),
-            ApeChain => address!("48b62137EdfA95a428D35C09E44256a739F6B557"),
-            Vana => address!("00EDdD9621Fb08436d0331c149D1690909a5906d"),
-            Zeta => address!("5F0b1a82749cb4E2278EC87F8BF6B618dC71a8bf"),
-            Kaia => address!("19aac5f612f524b754ca7e7c41cbfa2e981a4432"),
-            Treasure => address!("263d8f36bb8d0d9526255e205868c26690b04b88"),
-            _ => return None,
-        };
+    #[test]
+    fn rpc_url_templates_have_one_placeholder() {
+        use crate::{RpcProvider, RpcUrlTemplate};
+
+        let providers = [
+            RpcProvider::Alchemy,
+            RpcProvider::Infura,
+            RpcProvider::QuickNode,
+            RpcProvider::Ankr,
+            RpcProvider::Drpc,
+        ];
+        for chain in NamedChain::iter() {
+            for provider in providers {
+                if let Some(template) = chain.rpc_url_template(provider) {
+                    let template = template.as_str();
+                    assert!(template.starts_with("https://"), "{template}");
+                    assert_eq!(template.matches(RpcUrlTemplate::API_KEY).count(), 1, "{template}");
+                }
+            }
+        }
+    }
 
-        Some(addr)
+    #[test]
+    fn bundler_chains_support_erc4337() {
+        for chain in NamedChain::iter() {
+            if chain.bundler_rpc_slugs().next().is_some() {
+                assert!(chain.aa_support().erc4337_bundlers, "{chain}");
+            }
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use strum::{EnumCount, IntoEnumIterator};
+    #[test]
+    fn missing_metadata_reasons() {
+        use crate::MetadataError;
 
-    #[allow(unused_imports)]
-    use alloc::string::ToString;
+        for chain in NamedChain::iter() {
+            match chain.try_etherscan_urls() {
+                Ok(urls) => assert_eq!(Some(urls), chain.etherscan_urls()),
+                Err(MetadataError::Deprecated) => assert!(chain.is_deprecated(), "{chain}"),
+                Err(_) => assert_eq!(chain.etherscan_urls(), None, "{chain}"),
+            }
+        }
+        assert_eq!(NamedChain::Dev.try_native_currency(), Err(MetadataError::NotApplicable));
+        assert_eq!(
+            crate::Chain::from_id(1234567).try_average_blocktime_hint(),
+            Err(MetadataError::Unknown)
+        );
+    }
 
     #[test]
-    #[cfg(feature = "serde")]
-    fn default() {
-        assert_eq!(serde_json::to_string(&NamedChain::default()).unwrap(), "\"mainnet\"");
+    fn superchain_levels_are_op_stack() {
+        for chain in NamedChain::iter() {
+            if chain.superchain_level().is_some() {
+                assert!(chain.is_optimism(), "{chain}");
+                assert!(!chain.is_deprecated(), "{chain}");
+            }
+        }
+        for level in [SuperchainLevel::Frontier, SuperchainLevel::Standard] {
+            assert!(NamedChain::iter().any(|chain| chain.superchain_level() == Some(level)));
+        }
     }
 
     #[test]
-    fn enum_iter() {
-        assert_eq!(NamedChain::COUNT, NamedChain::iter().size_hint().0);
+    fn rollup_mempools_are_private() {
+        for chain in NamedChain::iter().filter(|chain| chain.is_optimism() || chain.is_arbitrum()) {
+            assert!(chain.mempool_profile().is_some_and(|profile| !profile.public), "{chain}");
+        }
     }
 
     #[test]
-    fn roundtrip_string() {
+    fn gas_semantics_match_families() {
         for chain in NamedChain::iter() {
-            let chain_string = chain.to_string();
-            assert_eq!(chain_string, format!("{chain}"));
-            assert_eq!(chain_string.as_str(), chain.as_ref());
-            #[cfg(feature = "serde")]
-            assert_eq!(serde_json::to_string(&chain).unwrap(), format!("\"{chain_string}\""));
+            if chain.is_optimism() {
+                assert_eq!(chain.gas_semantics(), GasSemantics::SeparateL1Fee, "{chain}");
+            }
+            if chain.is_arbitrum() {
+                assert_eq!(chain.gas_semantics(), GasSemantics::L1InclusiveGas, "{chain}");
+            }
+        }
+    }
 
-            assert_eq!(chain_string.parse::<NamedChain>().unwrap(), chain);
+    #[test]
+    fn max_tx_size_hints_match_families() {
+        for chain in NamedChain::iter().filter(|chain| chain.is_ethereum() || chain.is_optimism()) {
+            assert_eq!(chain.max_tx_size_hint(), Some(131_072), "{chain}");
         }
     }
 
     #[test]
-    #[cfg(feature = "serde")]
-    fn roundtrip_serde() {
+    fn rollups_have_centralized_sequencers() {
+        for chain in NamedChain::iter().filter(|chain| chain.is_optimism() || chain.is_arbitrum()) {
+            assert!(
+                chain.compliance_flags().contains(&ComplianceFlag::CentralizedSequencer),
+                "{chain}"
+            );
+        }
+    }
+
+    #[test]
+    fn parent_groups_match_families() {
         for chain in NamedChain::iter() {
-            let chain_string = serde_json::to_string(&chain).unwrap();
-            let chain_string = chain_string.replace('-', "_");
-            assert_eq!(serde_json::from_str::<'_, NamedChain>(&chain_string).unwrap(), chain);
+            let expected = if chain.is_ethereum() {
+                Some(ChainGroup::Ethereum)
+            } else if chain.is_optimism() {
+                Some(ChainGroup::OpStack)
+            } else if chain.is_arbitrum() {
+                Some(ChainGroup::Arbitrum)
+            } else {
+                continue;
+            };
+            assert_eq!(chain.parent_group(), expected, "{chain}");
         }
     }
 
     #[test]
-    fn aliases() {
-        use NamedChain::*;
+    fn op_stack_and_arbitrum_are_optimistic_rollups() {
+        for chain in NamedChain::iter().filter(|chain| chain.is_optimism() || chain.is_arbitrum()) {
+            assert_eq!(chain.rollup_type(), Some(RollupType::Optimistic), "{chain}");
+        }
+    }
 
-        // kebab-case
-        const ALIASES: &[(NamedChain, &[&str])] = &[
-            (Mainnet, &["ethlive"]),
-            (BinanceSmartChain, &["bsc", "bnb-smart-chain", "binance-smart-chain"]),
-            (
-                BinanceSmartChainTestnet,
-                &["bsc-testnet", "bnb-smart-chain-testnet", "binance-smart-chain-testnet"],
-            ),
-            (Gnosis, &["gnosis", "gnosis-chain"]),
-            (PolygonMumbai, &["mumbai"]),
-            (PolygonZkEvm, &["zkevm", "polygon-zkevm"]),
-            (PolygonZkEvmTestnet, &["zkevm-testnet", "polygon-zkevm-testnet"]),
-            (AnvilHardhat, &["anvil", "hardhat"]),
-            (AvalancheFuji, &["fuji"]),
-            (ZkSync, &["zksync"]),
-            (Mantle, &["mantle"]),
-            (MantleTestnet, &["mantle-testnet"]),
-            (MantleSepolia, &["mantle-sepolia"]),
-            (GravityAlphaMainnet, &["gravity-alpha-mainnet"]),
-            (GravityAlphaTestnetSepolia, &["gravity-alpha-testnet-sepolia"]),
-            (Bob, &["bob"]),
-            (BobSepolia, &["bob-sepolia"]),
-            (HappychainTestnet, &["happychain-testnet"]),
-            (Xai, &["xai"]),
-            (XaiSepolia, &["xai-sepolia"]),
-            (Base, &["base"]),
-            (BaseGoerli, &["base-goerli"]),
-            (BaseSepolia, &["base-sepolia"]),
-            (Fraxtal, &["fraxtal"]),
-            (FraxtalTestnet, &["fraxtal-testnet"]),
-            (Ink, &["ink"]),
-            (InkSepolia, &["ink-sepolia"]),
-            (BlastSepolia, &["blast-sepolia"]),
-            (Syndr, &["syndr"]),
-            (SyndrSepolia, &["syndr-sepolia"]),
-            (LineaGoerli, &["linea-goerli"]),
-            (LineaSepolia, &["linea-sepolia"]),
-            (AutonomysNovaTestnet, &["autonomys-nova-testnet"]),
-            (Immutable, &["immutable"]),
-            (ImmutableTestnet, &["immutable-testnet"]),
-            (SoneiumMinatoTestnet, &["soneium-minato-testnet"]),
-            (ApeChain, &["apechain"]),
-            (Curtis, &["apechain-testnet", "curtis"]),
-            (Treasure, &["treasure"]),
-            (TreasureTopaz, &["treasure-topaz-testnet", "treasure-topaz"]),
-            (BerachainArtio, &["berachain-artio-testnet", "berachain-artio"]),
-            (BerachainBartio, &["berachain-bartio-testnet", "berachain-bartio"]),
-        ];
+    #[test]
+    fn settlement_chains_are_l1s() {
+        for chain in NamedChain::iter() {
+            assert_eq!(
+                chain.settlement_chain().is_some(),
+                chain.rollup_type().is_some(),
+                "{chain}"
+            );
+            let Some(settlement) = chain.settlement_chain() else { continue };
+            assert_eq!(settlement.rollup_type(), None, "{chain}");
+            assert_eq!(settlement.is_testnet(), chain.is_testnet(), "{chain}");
+        }
+    }
 
-        for &(chain, aliases) in ALIASES {
-            for &alias in aliases {
-                let named = alias.parse::<NamedChain>().expect(alias);
-                assert_eq!(named, chain);
+    #[test]
+    fn known_ids() {
+        assert_eq!(NamedChain::all_ids().len(), NamedChain::COUNT);
+        for chain in NamedChain::iter() {
+            assert!(NamedChain::is_known_id(chain as u64), "{chain}");
+        }
+        for id in [0, 3, u64::MAX] {
+            assert_eq!(NamedChain::is_known_id(id), NamedChain::try_from(id).is_ok(), "{id}");
+        }
+    }
 
-                #[cfg(feature = "serde")]
-                {
-                    assert_eq!(
-                        serde_json::from_str::<NamedChain>(&format!("\"{alias}\"")).unwrap(),
-                        chain
-                    );
+    #[test]
+    fn chainlist_short_names_are_unique() {
+        for chain in NamedChain::iter() {
+            if let Some(short_name) = chain.chainlist_short_name() {
+                assert_eq!(NamedChain::from_chainlist_short_name(short_name), Some(chain));
+            }
+        }
+    }
 
-                    assert_eq!(
-                        serde_json::from_str::<NamedChain>(&format!("\"{named}\"")).unwrap(),
-                        chain
-                    );
-                }
+    #[test]
+    fn eq_str() {
+        assert_eq!(NamedChain::Base, "base");
+        assert_eq!(NamedChain::Mainnet, "ethlive");
+        assert_ne!(NamedChain::Mainnet, "1");
+        assert_ne!(NamedChain::Mainnet, "base");
+        for chain in NamedChain::iter() {
+            assert!(chain == *chain.as_str(), "{chain}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ui-metadata")]
+    fn brand_colors_are_hex() {
+        for chain in NamedChain::iter() {
+            if let Some(color) = chain.brand_color() {
+                let hex = color.strip_prefix('#').unwrap_or_default();
+                assert!(hex.len() == 6 && hex.bytes().all(|b| b.is_ascii_hexdigit()), "{chain}");
             }
         }
     }
 
     #[test]
-    #[cfg(feature = "serde")]
-    fn serde_to_string_match() {
+    fn precompiles_are_precompiles() {
         for chain in NamedChain::iter() {
-            let chain_serde = serde_json::to_string(&chain).unwrap();
-            let chain_string = format!("\"{chain}\"");
-            assert_eq!(chain_serde, chain_string);
+            for precompile in chain.supported_precompiles() {
+                assert!(chain.is_precompile(precompile.address()), "{chain}: {precompile:?}");
+            }
         }
+        assert!(
+            NamedChain::ZkSync.is_precompile(address!("0000000000000000000000000000000000008006"))
+        );
+        assert!(!NamedChain::ZkSync.is_precompile(Address::ZERO));
+        assert!(!NamedChain::Scroll.is_precompile(Address::with_last_byte(0x03)));
     }
 
     #[test]
-    fn test_dns_network() {
-        let s = "enrtree://AKA3AM6LPBYEUDMVNU3BSVQJ5AD45Y7YPOHJLEF6W26QOE4VTUDPE@all.mainnet.ethdisco.net";
-        assert_eq!(NamedChain::Mainnet.public_dns_network_protocol().unwrap(), s);
+    fn reserved_system_addresses() {
+        for chain in NamedChain::iter() {
+            for range in chain.reserved_system_address_ranges() {
+                assert!(range.start() <= range.end(), "{chain}");
+            }
+            if chain.is_optimism() {
+                let l2_to_l1_message_passer = address!("4200000000000000000000000000000000000016");
+                let reserved = chain.reserved_system_address_ranges();
+                assert!(reserved.iter().any(|range| range.contains(&l2_to_l1_message_passer)));
+            }
+            if let Some(admin) = chain.predeploy_proxy_admin() {
+                let reserved = chain.reserved_system_address_ranges();
+                assert!(reserved.iter().any(|range| range.contains(&admin)), "{chain}");
+            }
+        }
+    }
+
+    #[test]
+    fn sunset_testnets_only() {
+        for chain in NamedChain::iter().filter(|chain| chain.sunset_date().is_some()) {
+            assert!(chain.is_testnet(), "{chain}");
+        }
+    }
+
+    #[test]
+    fn gas_token_pegs_mainnets_only() {
+        for chain in NamedChain::iter() {
+            if chain.gas_token_peg().is_some() {
+                assert!(!chain.is_valueless_currency(), "{chain}");
+            }
+        }
     }
 
     #[test]
@@ -1828,4 +8879,405 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn explorers_sorted_by_priority() {
+        for chain in NamedChain::iter() {
+            let explorers = chain.explorers();
+            for (i, explorer) in explorers.iter().enumerate() {
+                assert_eq!(explorer.priority as usize, i, "{chain:?} explorers are not sorted");
+                assert!(!explorer.api_url.ends_with('/'), "{chain:?} api url has trailing /");
+                assert!(!explorer.base_url.ends_with('/'), "{chain:?} base url has trailing /");
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod data {
+        use super::*;
+        use crate::test_utils::ensure_file_contents;
+        use std::{fmt::Write, fs, path::Path};
+
+        const TOML_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/chains.toml");
+        const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/named_data.rs");
+
+        const KEYS: &[&str] = &[
+            "average_blocktime_ms",
+            "time_to_finality_ms",
+            "proof_latency_ms",
+            "block_gas_limit",
+            "get_logs_max_block_range",
+            "native_currency",
+            "etherscan_api_key_name",
+            "sequencer_rpc_url",
+            "explorers",
+            "mev_endpoints",
+            "beacon_api_url",
+            "token_list_url",
+            "l2beat_slug",
+            "defillama_slug",
+            "chainlist_short_name",
+            "brand_color",
+            "sunset_date",
+            "reviewed_at",
+        ];
+
+        fn metadata() -> toml::Table {
+            fs::read_to_string(TOML_PATH).unwrap().parse().unwrap()
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore = "no fs")]
+        fn every_chain_has_metadata() {
+            let metadata = metadata();
+            for chain in NamedChain::iter() {
+                assert!(
+                    metadata.contains_key(&format!("{chain:?}")),
+                    "{chain:?} is missing from assets/chains.toml"
+                );
+            }
+            for (name, entry) in &metadata {
+                assert!(
+                    NamedChain::iter().any(|chain| format!("{chain:?}") == *name),
+                    "unknown chain `{name}` in assets/chains.toml"
+                );
+                for key in entry.as_table().unwrap().keys() {
+                    assert!(KEYS.contains(&key.as_str()), "unknown key `{name}.{key}`");
+                }
+            }
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore = "no fs")]
+        fn data_up_to_date() {
+            ensure_file_contents(Path::new(DATA_PATH), &generate(&metadata()));
+        }
+
+        /// Generates the current version's snapshot for [`crate::changelog`].
+        #[test]
+        #[cfg_attr(miri, ignore = "no fs")]
+        fn changelog_snapshot_up_to_date() {
+            let path = format!(
+                "{}/assets/changelog/{}.txt",
+                env!("CARGO_MANIFEST_DIR"),
+                env!("CARGO_PKG_VERSION")
+            );
+            let metadata = metadata();
+            let mut out = String::from(
+                "# This file is @generated from `assets/chains.toml` by the \
+                 `changelog_snapshot_up_to_date` test.\n\
+                 # Each line is a chain ID, name and metadata fingerprint.\n",
+            );
+            for chain in NamedChain::iter_sorted_by_id() {
+                let entry = metadata[&format!("{chain:?}")].to_string();
+                let fingerprint = alloy_primitives::keccak256(entry);
+                writeln!(
+                    out,
+                    "{} {chain} {}",
+                    chain as u64,
+                    alloy_primitives::hex::encode(&fingerprint[..8])
+                )
+                .unwrap();
+            }
+            ensure_file_contents(Path::new(&path), &out);
+        }
+
+        /// Returns the entries for `key`, in enum order.
+        fn entries<'a>(
+            metadata: &'a toml::Table,
+            key: &'a str,
+        ) -> impl Iterator<Item = (NamedChain, &'a toml::Value)> + 'a {
+            NamedChain::iter().filter_map(move |chain| {
+                Some((chain, metadata.get(&format!("{chain:?}"))?.get(key)?))
+            })
+        }
+
+        fn generate(metadata: &toml::Table) -> String {
+            let mut out = String::from(
+                "// This file is @generated from `assets/chains.toml` by the `data_up_to_date` test.\n\
+                 // Do not edit it manually.\n",
+            );
+
+            optional_table(&mut out, metadata, "average_blocktime_ms", "u64", false, |ms| {
+                separated(ms.as_integer().unwrap())
+            });
+            optional_table(&mut out, metadata, "time_to_finality_ms", "u64", false, |ms| {
+                separated(ms.as_integer().unwrap())
+            });
+            optional_table(&mut out, metadata, "proof_latency_ms", "u64", false, |ms| {
+                separated(ms.as_integer().unwrap())
+            });
+            optional_table(&mut out, metadata, "block_gas_limit", "u64", false, |gas| {
+                separated(gas.as_integer().unwrap())
+            });
+            optional_table(
+                &mut out,
+                metadata,
+                "get_logs_max_block_range",
+                "u64",
+                false,
+                |blocks| separated(blocks.as_integer().unwrap()),
+            );
+            optional_table(&mut out, metadata, "native_currency", "NativeCurrency", true, |c| {
+                let decimals = c.get("decimals").map_or(18, |d| d.as_integer().unwrap());
+                format!(
+                    "NativeCurrency {{ name: {}, symbol: {}, decimals: {decimals} }}",
+                    c["name"], c["symbol"]
+                )
+            });
+
+            slice_table(
+                &mut out,
+                metadata,
+                "explorers",
+                "ExplorerInfo",
+                true,
+                |chain, i, explorer| {
+                    let kind = match explorer["kind"].as_str().unwrap() {
+                        "etherscan" => ExplorerKind::Etherscan,
+                        "blockscout" => ExplorerKind::Blockscout,
+                        "routescan" => ExplorerKind::Routescan,
+                        "oklink" => ExplorerKind::OkLink,
+                        "custom" => ExplorerKind::Custom,
+                        kind => panic!("{chain:?}: unknown explorer kind `{kind}`"),
+                    };
+                    let rate_limit_hint = match explorer.get("rate_limit") {
+                        Some(rps) => Some(rps.as_integer().unwrap()),
+                        None => kind.default_rate_limit_hint().map(i64::from),
+                    };
+                    vec![
+                        ("kind", format!("crate::ExplorerKind::{kind:?}")),
+                        ("api_url", explorer["api_url"].to_string()),
+                        ("base_url", explorer["base_url"].to_string()),
+                        ("priority", i.to_string()),
+                        ("rate_limit_hint", format!("{rate_limit_hint:?}")),
+                    ]
+                },
+            );
+
+            slice_table(
+                &mut out,
+                metadata,
+                "mev_endpoints",
+                "MevEndpoint",
+                false,
+                |chain, _, endpoint| {
+                    let kind = match endpoint["kind"].as_str().unwrap() {
+                        "protect" => "Protect",
+                        "bundle" => "Bundle",
+                        kind => panic!("{chain:?}: unknown MEV endpoint kind `{kind}`"),
+                    };
+                    vec![
+                        ("name", endpoint["name"].to_string()),
+                        ("kind", format!("crate::MevEndpointKind::{kind}")),
+                        ("url", endpoint["url"].to_string()),
+                    ]
+                },
+            );
+
+            optional_table(
+                &mut out,
+                metadata,
+                "etherscan_api_key_name",
+                "&'static str",
+                false,
+                |s| s.to_string(),
+            );
+            optional_table(&mut out, metadata, "beacon_api_url", "&'static str", false, |s| {
+                s.to_string()
+            });
+            optional_table(&mut out, metadata, "token_list_url", "&'static str", false, |s| {
+                s.to_string()
+            });
+            optional_table(&mut out, metadata, "sequencer_rpc_url", "&'static str", false, |s| {
+                s.to_string()
+            });
+            optional_table(&mut out, metadata, "l2beat_slug", "&'static str", false, |s| {
+                s.to_string()
+            });
+            optional_table(&mut out, metadata, "defillama_slug", "&'static str", false, |s| {
+                s.to_string()
+            });
+            optional_table(
+                &mut out,
+                metadata,
+                "chainlist_short_name",
+                "&'static str",
+                false,
+                |s| s.to_string(),
+            );
+            feature_table(&mut out, metadata, "brand_color", "&'static str", "ui-metadata", |s| {
+                s.to_string()
+            });
+            optional_table(&mut out, metadata, "sunset_date", "Date", false, |d| {
+                let d = d.as_datetime().unwrap().date.unwrap();
+                format!("Date::new({}, {}, {})", d.year, d.month, d.day)
+            });
+            optional_table(&mut out, metadata, "reviewed_at", "Date", false, |d| {
+                let d = d.as_datetime().unwrap().date.unwrap();
+                format!("Date::new({}, {}, {})", d.year, d.month, d.day)
+            });
+
+            names(&mut out);
+            ids(&mut out);
+
+            out
+        }
+
+        /// Generates the `IDS` table of every chain ID, sorted in ascending order.
+        fn ids(out: &mut String) {
+            out.push_str("\npub(super) const IDS: &[u64] = &[\n");
+            for chain in NamedChain::iter_sorted_by_id() {
+                writeln!(out, "    {},", separated(chain as i64)).unwrap();
+            }
+            out.push_str("];\n");
+        }
+
+        /// Generates the `NAMES` table of every chain name and alias, sorted by name, and the
+        /// `const fn name` lookup of each chain's canonical name.
+        fn names(out: &mut String) {
+            use strum::EnumMessage;
+
+            let mut names: Vec<_> = NamedChain::iter()
+                .flat_map(|chain| chain.get_serializations().iter().map(move |&name| (name, chain)))
+                .collect();
+            names.sort_unstable();
+            out.push_str("\npub(super) const NAMES: &[(&str, NamedChain)] = &[\n");
+            for (name, chain) in names {
+                writeln!(out, "    ({name:?}, NamedChain::{chain:?}),").unwrap();
+            }
+            out.push_str("];\n");
+
+            out.push_str("\npub(super) const fn name(chain: NamedChain) -> &'static str {\n");
+            out.push_str("    use NamedChain::*;\n\n    match chain {\n");
+            for chain in NamedChain::iter() {
+                writeln!(out, "        {chain:?} => {:?},", chain.as_str()).unwrap();
+            }
+            out.push_str("    }\n}\n");
+        }
+
+        /// Generates a `const fn {key}(chain: NamedChain) -> &'static [{ty}]` lookup table, where
+        /// each element is a struct literal built from the `fields` of the TOML array's entries.
+        /// If `slim` is set, the table is replaced by a function always returning an empty slice
+        /// under the `slim` feature.
+        fn slice_table(
+            out: &mut String,
+            metadata: &toml::Table,
+            key: &str,
+            ty: &str,
+            slim: bool,
+            fields: impl Fn(NamedChain, usize, &toml::Value) -> Vec<(&'static str, String)>,
+        ) {
+            out.push('\n');
+            if slim {
+                out.push_str("#[cfg(not(feature = \"slim\"))]\n");
+            }
+            writeln!(out, "pub(super) const fn {key}(chain: NamedChain) -> &'static [{ty}] {{")
+                .unwrap();
+            out.push_str("    use NamedChain::*;\n\n    match chain {\n");
+            for (chain, elements) in entries(metadata, key) {
+                testnet_cfg(out, chain);
+                writeln!(out, "        {chain:?} => &[").unwrap();
+                for (i, element) in elements.as_array().unwrap().iter().enumerate() {
+                    writeln!(out, "            {ty} {{").unwrap();
+                    for (field, value) in fields(chain, i, element) {
+                        writeln!(out, "                {field}: {value},").unwrap();
+                    }
+                    out.push_str("            },\n");
+                }
+                out.push_str("        ],\n");
+            }
+            out.push_str("        _ => &[],\n    }\n}\n");
+            if slim {
+                out.push_str("\n#[cfg(feature = \"slim\")]\n");
+                writeln!(
+                    out,
+                    "pub(super) const fn {key}(_chain: NamedChain) -> &'static [{ty}] {{"
+                )
+                .unwrap();
+                out.push_str("    &[]\n}\n");
+            }
+        }
+
+        /// Generates a `const fn {key}(chain: NamedChain) -> Option<{ty}>` lookup table. If `slim`
+        /// is set, the table is replaced by a function always returning `None` under the `slim`
+        /// feature. Likewise, a table of testnets only is replaced under the `mainnets-only`
+        /// feature.
+        fn optional_table(
+            out: &mut String,
+            metadata: &toml::Table,
+            key: &str,
+            ty: &str,
+            slim: bool,
+            value: impl Fn(&toml::Value) -> String,
+        ) {
+            let testnets_only = entries(metadata, key).all(|(chain, _)| chain.is_testnet());
+            let features: Vec<_> = [(slim, "slim"), (testnets_only, "mainnets-only")]
+                .into_iter()
+                .filter(|&(gated, _)| gated)
+                .map(|(_, feature)| format!("feature = {feature:?}"))
+                .collect();
+            let gate = match &features[..] {
+                [] => None,
+                [feature] => Some(feature.clone()),
+                features => Some(format!("any({})", features.join(", "))),
+            };
+
+            out.push('\n');
+            if let Some(gate) = &gate {
+                writeln!(out, "#[cfg(not({gate}))]").unwrap();
+            }
+            writeln!(out, "pub(super) const fn {key}(chain: NamedChain) -> Option<{ty}> {{")
+                .unwrap();
+            out.push_str("    use NamedChain::*;\n\n    Some(match chain {\n");
+            for (chain, v) in entries(metadata, key) {
+                if !testnets_only {
+                    testnet_cfg(out, chain);
+                }
+                writeln!(out, "        {chain:?} => {},", value(v)).unwrap();
+            }
+            out.push_str("        _ => return None,\n    })\n}\n");
+            if let Some(gate) = &gate {
+                writeln!(out, "\n#[cfg({gate})]").unwrap();
+                writeln!(out, "pub(super) const fn {key}(_chain: NamedChain) -> Option<{ty}> {{")
+                    .unwrap();
+                out.push_str("    None\n}\n");
+            }
+        }
+
+        /// Generates an [`optional_table`] that only exists under `feature`.
+        fn feature_table(
+            out: &mut String,
+            metadata: &toml::Table,
+            key: &str,
+            ty: &str,
+            feature: &str,
+            value: impl Fn(&toml::Value) -> String,
+        ) {
+            let start = out.len();
+            optional_table(out, metadata, key, ty, false, value);
+            // Skip the empty line separating the table from the previous one.
+            out.insert_str(start + 1, &format!("#[cfg(feature = \"{feature}\")]\n"));
+        }
+
+        /// Strips testnet entries from the tables under the `mainnets-only` feature.
+        fn testnet_cfg(out: &mut String, chain: NamedChain) {
+            if chain.is_testnet() {
+                out.push_str("        #[cfg(not(feature = \"mainnets-only\"))]\n");
+            }
+        }
+
+        /// Formats an integer with `_` thousands separators.
+        fn separated(n: i64) -> String {
+            let digits = n.to_string();
+            let mut out = String::new();
+            for (i, c) in digits.chars().enumerate() {
+                if i > 0 && (digits.len() - i) % 3 == 0 {
+                    out.push('_');
+                }
+                out.push(c);
+            }
+            out
+        }
+    }
 }
@@ -0,0 +1,2721 @@
+// This file is @generated from `assets/chains.toml` by the `data_up_to_date` test.
+// Do not edit it manually.
+
+pub(super) const fn average_blocktime_ms(chain: NamedChain) -> Option<u64> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => 12_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        Hoodi => 12_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        Odyssey => 1_000,
+        Optimism => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismGoerli => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismSepolia => 2_000,
+        Bob => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        BobSepolia => 2_000,
+        Arbitrum => 260,
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumTestnet => 260,
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumGoerli => 260,
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumSepolia => 260,
+        ArbitrumNova => 260,
+        Cronos => 5_700,
+        #[cfg(not(feature = "mainnets-only"))]
+        CronosTestnet => 5_700,
+        Crab => 6_000,
+        Darwinia => 6_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        Koi => 6_000,
+        BinanceSmartChain => 3_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        BinanceSmartChainTestnet => 3_000,
+        Scroll => 3_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        ScrollSepolia => 3_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        CfxTestnet => 500,
+        Cfx => 500,
+        Gnosis => 5_000,
+        Polygon => 2_100,
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonMumbai => 2_100,
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonAmoy => 2_100,
+        Fantom => 1_200,
+        #[cfg(not(feature = "mainnets-only"))]
+        FantomTestnet => 1_200,
+        Moonbeam => 12_500,
+        Moonriver => 12_500,
+        #[cfg(not(feature = "mainnets-only"))]
+        Dev => 200,
+        #[cfg(not(feature = "mainnets-only"))]
+        AnvilHardhat => 200,
+        GravityAlphaMainnet => 260,
+        #[cfg(not(feature = "mainnets-only"))]
+        GravityAlphaTestnetSepolia => 260,
+        Evmos => 1_900,
+        #[cfg(not(feature = "mainnets-only"))]
+        EvmosTestnet => 1_900,
+        Chiado => 5_000,
+        Oasis => 5_500,
+        Emerald => 6_000,
+        FilecoinMainnet => 30_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        FilecoinCalibrationTestnet => 30_000,
+        Avalanche => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        AvalancheFuji => 2_000,
+        Celo => 5_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        CeloAlfajores => 5_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        CeloBaklava => 5_000,
+        Aurora => 1_100,
+        #[cfg(not(feature = "mainnets-only"))]
+        AuroraTestnet => 1_100,
+        Canto => 5_700,
+        #[cfg(not(feature = "mainnets-only"))]
+        CantoTestnet => 5_700,
+        Base => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseGoerli => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseSepolia => 2_000,
+        Syndr => 260,
+        #[cfg(not(feature = "mainnets-only"))]
+        SyndrSepolia => 260,
+        #[cfg(not(feature = "mainnets-only"))]
+        Shimmer => 5_000,
+        Ink => 1_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        InkSepolia => 1_000,
+        Fraxtal => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        FraxtalTestnet => 2_000,
+        Blast => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        BlastSepolia => 2_000,
+        Mantle => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        MantleSepolia => 2_000,
+        Xai => 260,
+        #[cfg(not(feature = "mainnets-only"))]
+        XaiSepolia => 260,
+        #[cfg(not(feature = "mainnets-only"))]
+        HappychainTestnet => 2_000,
+        Viction => 2_000,
+        Zora => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        ZoraGoerli => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        ZoraSepolia => 2_000,
+        Pgn => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        PgnSepolia => 2_000,
+        Mode => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        ModeSepolia => 2_000,
+        Elastos => 5_000,
+        Etherlink => 5_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        EtherlinkTestnet => 5_000,
+        Degen => 600,
+        OpBNBMainnet => 1_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        OpBNBTestnet => 1_000,
+        Ronin => 3_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        RoninTestnet => 3_000,
+        Taiko => 12_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        TaikoHekla => 12_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        AutonomysNovaTestnet => 1_000,
+        Flare => 1_800,
+        #[cfg(not(feature = "mainnets-only"))]
+        FlareCoston2 => 2_500,
+        Acala => 12_500,
+        #[cfg(not(feature = "mainnets-only"))]
+        AcalaMandalaTestnet => 12_500,
+        #[cfg(not(feature = "mainnets-only"))]
+        AcalaTestnet => 12_500,
+        Karura => 12_500,
+        #[cfg(not(feature = "mainnets-only"))]
+        KaruraTestnet => 12_500,
+        Pulsechain => 10_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        PulsechainTestnet => 10_101,
+        Immutable => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        ImmutableTestnet => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        SoneiumMinatoTestnet => 2_000,
+        World => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        WorldSepolia => 2_000,
+        Iotex => 5_000,
+        Core => 3_000,
+        Merlin => 3_000,
+        Bitlayer => 3_000,
+        Vana => 6_000,
+        Zeta => 6_000,
+        Kaia => 1_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        UnichainSepolia => 1_000,
+        ApeChain => 260,
+        #[cfg(not(feature = "mainnets-only"))]
+        Curtis => 260,
+        #[cfg(not(feature = "mainnets-only"))]
+        BerachainBartio => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        BerachainArtio => 2_000,
+        _ => return None,
+    })
+}
+
+pub(super) const fn time_to_finality_ms(chain: NamedChain) -> Option<u64> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => 768_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        Holesky => 768_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        Hoodi => 768_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        Sepolia => 768_000,
+        Avalanche => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        AvalancheFuji => 2_000,
+        _ => return None,
+    })
+}
+
+pub(super) const fn proof_latency_ms(chain: NamedChain) -> Option<u64> {
+    use NamedChain::*;
+
+    Some(match chain {
+        ZkSync => 3_600_000,
+        _ => return None,
+    })
+}
+
+pub(super) const fn block_gas_limit(chain: NamedChain) -> Option<u64> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => 60_000_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        Hoodi => 60_000_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        Sepolia => 60_000_000,
+        Optimism => 40_000_000,
+        Arbitrum => 1_125_899_906_842_624,
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumSepolia => 1_125_899_906_842_624,
+        ArbitrumNova => 1_125_899_906_842_624,
+        Gnosis => 17_000_000,
+        _ => return None,
+    })
+}
+
+pub(super) const fn get_logs_max_block_range(chain: NamedChain) -> Option<u64> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Cronos => 2_000,
+        #[cfg(not(feature = "mainnets-only"))]
+        CronosTestnet => 2_000,
+        Moonbeam => 1_024,
+        Moonriver => 1_024,
+        Moonbase => 1_024,
+        Avalanche => 2_048,
+        #[cfg(not(feature = "mainnets-only"))]
+        AvalancheFuji => 2_048,
+        _ => return None,
+    })
+}
+
+#[cfg(not(feature = "slim"))]
+pub(super) const fn native_currency(chain: NamedChain) -> Option<NativeCurrency> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Morden => NativeCurrency { name: "Morden Ether", symbol: "MordenETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Ropsten => NativeCurrency { name: "Ropsten Ether", symbol: "RopstenETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Rinkeby => NativeCurrency { name: "Rinkeby Ether", symbol: "RinkebyETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Goerli => NativeCurrency { name: "Goerli Ether", symbol: "GoerliETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Kovan => NativeCurrency { name: "Kovan Ether", symbol: "KovanETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Holesky => NativeCurrency { name: "Holesky Ether", symbol: "HoleskyETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Hoodi => NativeCurrency { name: "Hoodi Ether", symbol: "HoodiETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Sepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Odyssey => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Optimism => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismKovan => NativeCurrency { name: "Kovan Ether", symbol: "KovanETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismGoerli => NativeCurrency { name: "Goerli Ether", symbol: "GoerliETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Bob => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        BobSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Arbitrum => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumTestnet => NativeCurrency { name: "Rinkeby Ether", symbol: "RinkebyETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumGoerli => NativeCurrency { name: "Goerli Ether", symbol: "GoerliETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        ArbitrumNova => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        Cronos => NativeCurrency { name: "Cronos", symbol: "CRO", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        CronosTestnet => NativeCurrency { name: "Cronos Test Coin", symbol: "tCRO", decimals: 18 },
+        Rsk => NativeCurrency { name: "Smart Bitcoin", symbol: "RBTC", decimals: 18 },
+        Crab => NativeCurrency { name: "Crab Network Native Token", symbol: "CRAB", decimals: 18 },
+        Darwinia => NativeCurrency { name: "Darwinia Network Native Token", symbol: "RING", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Koi => NativeCurrency { name: "Koi Network Native Token", symbol: "KRING", decimals: 18 },
+        BinanceSmartChain => NativeCurrency { name: "BNB", symbol: "BNB", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        BinanceSmartChainTestnet => NativeCurrency { name: "Test BNB", symbol: "tBNB", decimals: 18 },
+        Poa => NativeCurrency { name: "POA", symbol: "POA", decimals: 18 },
+        Sokol => NativeCurrency { name: "Sokol POA", symbol: "tPOA", decimals: 18 },
+        Scroll => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        ScrollSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Metis => NativeCurrency { name: "Metis", symbol: "METIS", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        CfxTestnet => NativeCurrency { name: "Test CFX", symbol: "tCFX", decimals: 18 },
+        Cfx => NativeCurrency { name: "CFX", symbol: "CFX", decimals: 18 },
+        Gnosis => NativeCurrency { name: "xDAI", symbol: "XDAI", decimals: 18 },
+        Polygon => NativeCurrency { name: "POL", symbol: "POL", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonMumbai => NativeCurrency { name: "Mumbai MATIC", symbol: "tMATIC", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonAmoy => NativeCurrency { name: "Amoy POL", symbol: "tPOL", decimals: 18 },
+        PolygonZkEvm => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonZkEvmTestnet => NativeCurrency { name: "Goerli Ether", symbol: "GoerliETH", decimals: 18 },
+        Fantom => NativeCurrency { name: "Fantom", symbol: "FTM", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        FantomTestnet => NativeCurrency { name: "Fantom Testnet", symbol: "tFTM", decimals: 18 },
+        Moonbeam => NativeCurrency { name: "Glimmer", symbol: "GLMR", decimals: 18 },
+        Moonriver => NativeCurrency { name: "Moonriver", symbol: "MOVR", decimals: 18 },
+        Moonbase => NativeCurrency { name: "Dev", symbol: "DEV", decimals: 18 },
+        GravityAlphaMainnet => NativeCurrency { name: "Gravity", symbol: "G", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        GravityAlphaTestnetSepolia => NativeCurrency { name: "Test Gravity", symbol: "tG", decimals: 18 },
+        Evmos => NativeCurrency { name: "Evmos", symbol: "EVMOS", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        EvmosTestnet => NativeCurrency { name: "Evmos Testnet", symbol: "tEVMOS", decimals: 18 },
+        Chiado => NativeCurrency { name: "Chiado xDAI", symbol: "tXDAI", decimals: 18 },
+        Emerald => NativeCurrency { name: "Rose", symbol: "ROSE", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        EmeraldTestnet => NativeCurrency { name: "Testnet Rose", symbol: "tROSE", decimals: 18 },
+        FilecoinMainnet => NativeCurrency { name: "Filecoin", symbol: "FIL", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        FilecoinCalibrationTestnet => NativeCurrency { name: "Testnet Filecoin", symbol: "tFIL", decimals: 18 },
+        Avalanche => NativeCurrency { name: "Avalanche", symbol: "AVAX", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        AvalancheFuji => NativeCurrency { name: "Avalanche Fuji", symbol: "tAVAX", decimals: 18 },
+        Celo => NativeCurrency { name: "Celo", symbol: "CELO", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        CeloAlfajores => NativeCurrency { name: "Alfajores Celo", symbol: "tCELO", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        CeloBaklava => NativeCurrency { name: "Baklava Celo", symbol: "tCELO", decimals: 18 },
+        Aurora => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        Canto => NativeCurrency { name: "Canto", symbol: "CANTO", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        CantoTestnet => NativeCurrency { name: "Testnet Canto", symbol: "tCANTO", decimals: 18 },
+        Boba => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        Base => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseGoerli => NativeCurrency { name: "Goerli Ether", symbol: "GoerliETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Syndr => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        SyndrSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Shimmer => NativeCurrency { name: "Shimmer", symbol: "SMR", decimals: 18 },
+        Ink => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        InkSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Blast => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        BlastSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Linea => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        LineaGoerli => NativeCurrency { name: "Goerli Ether", symbol: "GoerliETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        LineaSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        ZkSync => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        ZkSyncTestnet => NativeCurrency { name: "Goerli Ether", symbol: "GoerliETH", decimals: 18 },
+        Mantle => NativeCurrency { name: "Mantle", symbol: "MNT", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        MantleTestnet => NativeCurrency { name: "Testnet Mantle", symbol: "tMNT", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        MantleSepolia => NativeCurrency { name: "Test Mantle", symbol: "tMNT", decimals: 18 },
+        Xai => NativeCurrency { name: "Xai", symbol: "XAI", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        XaiSepolia => NativeCurrency { name: "Test Xai", symbol: "tXAI", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        HappychainTestnet => NativeCurrency { name: "Test Happy", symbol: "tHAPPY", decimals: 18 },
+        Viction => NativeCurrency { name: "Viction", symbol: "VIC", decimals: 18 },
+        Zora => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        ZoraGoerli => NativeCurrency { name: "Goerli Ether", symbol: "GoerliETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        ZoraSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Pgn => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        PgnSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Mode => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        ModeSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Elastos => NativeCurrency { name: "Elastos", symbol: "ELA", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        KakarotSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        Etherlink => NativeCurrency { name: "Tez", symbol: "XTZ", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        EtherlinkTestnet => NativeCurrency { name: "Test Tez", symbol: "tXTZ", decimals: 18 },
+        Degen => NativeCurrency { name: "Degen", symbol: "DEGEN", decimals: 18 },
+        OpBNBMainnet => NativeCurrency { name: "BNB", symbol: "BNB", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        OpBNBTestnet => NativeCurrency { name: "Test BNB", symbol: "tBNB", decimals: 18 },
+        Ronin => NativeCurrency { name: "Ronin", symbol: "RON", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        RoninTestnet => NativeCurrency { name: "Test Ronin", symbol: "tRON", decimals: 18 },
+        Taiko => NativeCurrency { name: "Ether", symbol: "ETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        TaikoHekla => NativeCurrency { name: "Holesky Ether", symbol: "HoleskyETH", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        AutonomysNovaTestnet => NativeCurrency { name: "Testnet AI3", symbol: "tAI3", decimals: 18 },
+        Flare => NativeCurrency { name: "Flare", symbol: "FLR", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        FlareCoston2 => NativeCurrency { name: "Coston2 Flare", symbol: "C2FLR", decimals: 18 },
+        Acala => NativeCurrency { name: "Acala", symbol: "ACA", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        AcalaMandalaTestnet => NativeCurrency { name: "Mandala Acala", symbol: "tACA", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        AcalaTestnet => NativeCurrency { name: "Testnet Acala", symbol: "tACA", decimals: 18 },
+        Karura => NativeCurrency { name: "Karura", symbol: "KAR", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        KaruraTestnet => NativeCurrency { name: "Testnet Karura", symbol: "tKAR", decimals: 18 },
+        Pulsechain => NativeCurrency { name: "Pulse", symbol: "PLS", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        PulsechainTestnet => NativeCurrency { name: "Test Pulse", symbol: "tPLS", decimals: 18 },
+        Immutable => NativeCurrency { name: "IMX", symbol: "IMX", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        ImmutableTestnet => NativeCurrency { name: "Test IMX", symbol: "tIMX", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        SoneiumMinatoTestnet => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        World => NativeCurrency { name: "WRLD", symbol: "WRLD", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        WorldSepolia => NativeCurrency { name: "Test WRLD", symbol: "tWRLD", decimals: 18 },
+        Iotex => NativeCurrency { name: "IoTeX", symbol: "IOTX", decimals: 18 },
+        Core => NativeCurrency { name: "Core", symbol: "CORE", decimals: 18 },
+        Merlin => NativeCurrency { name: "Bitcoin", symbol: "BTC", decimals: 18 },
+        Bitlayer => NativeCurrency { name: "Bitcoin", symbol: "BTC", decimals: 18 },
+        Vana => NativeCurrency { name: "Vana", symbol: "VANA", decimals: 18 },
+        Zeta => NativeCurrency { name: "Zeta", symbol: "ZETA", decimals: 18 },
+        Kaia => NativeCurrency { name: "KAIA", symbol: "KAIA", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        UnichainSepolia => NativeCurrency { name: "Sepolia Ether", symbol: "SepoliaETH", decimals: 18 },
+        ApeChain => NativeCurrency { name: "ApeCoin", symbol: "APE", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        Curtis => NativeCurrency { name: "Test ApeCoin", symbol: "tAPE", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        SonicTestnet => NativeCurrency { name: "Testnet Sonic", symbol: "tS", decimals: 18 },
+        Treasure => NativeCurrency { name: "MAGIC", symbol: "MAGIC", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        TreasureTopaz => NativeCurrency { name: "Test MAGIC", symbol: "tMAGIC", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        BerachainBartio => NativeCurrency { name: "Test Berachain BERA", symbol: "tBERA", decimals: 18 },
+        #[cfg(not(feature = "mainnets-only"))]
+        BerachainArtio => NativeCurrency { name: "Test Berachain BERA", symbol: "tBERA", decimals: 18 },
+        _ => return None,
+    })
+}
+
+#[cfg(feature = "slim")]
+pub(super) const fn native_currency(_chain: NamedChain) -> Option<NativeCurrency> {
+    None
+}
+
+#[cfg(not(feature = "slim"))]
+pub(super) const fn explorers(chain: NamedChain) -> &'static [ExplorerInfo] {
+    use NamedChain::*;
+
+    match chain {
+        Mainnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.etherscan.io/api",
+                base_url: "https://etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://eth.blockscout.com/api",
+                base_url: "https://eth.blockscout.com",
+                priority: 1,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Ropsten => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-ropsten.etherscan.io/api",
+                base_url: "https://ropsten.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Rinkeby => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-rinkeby.etherscan.io/api",
+                base_url: "https://rinkeby.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Goerli => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-goerli.etherscan.io/api",
+                base_url: "https://goerli.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Kovan => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-kovan.etherscan.io/api",
+                base_url: "https://kovan.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Holesky => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-holesky.etherscan.io/api",
+                base_url: "https://holesky.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://eth-holesky.blockscout.com/api",
+                base_url: "https://eth-holesky.blockscout.com",
+                priority: 1,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Hoodi => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-hoodi.etherscan.io/api",
+                base_url: "https://hoodi.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Sepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-sepolia.etherscan.io/api",
+                base_url: "https://sepolia.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://eth-sepolia.blockscout.com/api",
+                base_url: "https://eth-sepolia.blockscout.com",
+                priority: 1,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Odyssey => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://odyssey-explorer.ithaca.xyz/api",
+                base_url: "https://odyssey-explorer.ithaca.xyz",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Optimism => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-optimistic.etherscan.io/api",
+                base_url: "https://optimistic.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://optimism.blockscout.com/api",
+                base_url: "https://optimism.blockscout.com",
+                priority: 1,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismKovan => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-kovan-optimistic.etherscan.io/api",
+                base_url: "https://kovan-optimistic.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismGoerli => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-goerli-optimistic.etherscan.io/api",
+                base_url: "https://goerli-optimism.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-sepolia-optimistic.etherscan.io/api",
+                base_url: "https://sepolia-optimism.etherscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://optimism-sepolia.blockscout.com/api",
+                base_url: "https://optimism-sepolia.blockscout.com",
+                priority: 1,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Bob => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.gobob.xyz/api",
+                base_url: "https://explorer.gobob.xyz",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        BobSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://bob-sepolia.explorer.gobob.xyz/api",
+                base_url: "https://bob-sepolia.explorer.gobob.xyz",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Arbitrum => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.arbiscan.io/api",
+                base_url: "https://arbiscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://arbitrum.blockscout.com/api",
+                base_url: "https://arbitrum.blockscout.com",
+                priority: 1,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-testnet.arbiscan.io/api",
+                base_url: "https://testnet.arbiscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumGoerli => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-goerli.arbiscan.io/api",
+                base_url: "https://goerli.arbiscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-sepolia.arbiscan.io/api",
+                base_url: "https://sepolia.arbiscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        ArbitrumNova => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-nova.arbiscan.io/api",
+                base_url: "https://nova.arbiscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Cronos => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.cronoscan.com/api",
+                base_url: "https://cronoscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        CronosTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-testnet.cronoscan.com/api",
+                base_url: "https://testnet.cronoscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Rsk => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://blockscout.com/rsk/mainnet/api",
+                base_url: "https://blockscout.com/rsk/mainnet",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Crab => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://crab-scan.darwinia.network/api",
+                base_url: "https://crab-scan.darwinia.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Darwinia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.darwinia.network/api",
+                base_url: "https://explorer.darwinia.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Koi => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://koi-scan.darwinia.network/api",
+                base_url: "https://koi-scan.darwinia.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        BinanceSmartChain => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.bscscan.com/api",
+                base_url: "https://bscscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        BinanceSmartChainTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-testnet.bscscan.com/api",
+                base_url: "https://testnet.bscscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Poa => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://blockscout.com/poa/core/api",
+                base_url: "https://blockscout.com/poa/core",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Sokol => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://blockscout.com/poa/sokol/api",
+                base_url: "https://blockscout.com/poa/sokol",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Scroll => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.scrollscan.com/api",
+                base_url: "https://scrollscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        ScrollSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-sepolia.scrollscan.com/api",
+                base_url: "https://sepolia.scrollscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Metis => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Routescan,
+                api_url: "https://api.routescan.io/v2/network/mainnet/evm/1088/etherscan",
+                base_url: "https://explorer.metis.io",
+                priority: 0,
+                rate_limit_hint: Some(2),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        CfxTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://evmapi-testnet.confluxscan.net/api",
+                base_url: "https://evmtestnet.confluxscan.io",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        Cfx => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://evmapi.confluxscan.net/api",
+                base_url: "https://evm.confluxscan.io",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        Gnosis => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.gnosisscan.io/api",
+                base_url: "https://gnosisscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://gnosis.blockscout.com/api",
+                base_url: "https://gnosis.blockscout.com",
+                priority: 1,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Polygon => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.polygonscan.com/api",
+                base_url: "https://polygonscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://polygon.blockscout.com/api",
+                base_url: "https://polygon.blockscout.com",
+                priority: 1,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonMumbai => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-testnet.polygonscan.com/api",
+                base_url: "https://mumbai.polygonscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonAmoy => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-amoy.polygonscan.com/api",
+                base_url: "https://amoy.polygonscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        PolygonZkEvm => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-zkevm.polygonscan.com/api",
+                base_url: "https://zkevm.polygonscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonZkEvmTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-testnet-zkevm.polygonscan.com/api",
+                base_url: "https://testnet-zkevm.polygonscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Fantom => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.ftmscan.com/api",
+                base_url: "https://ftmscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        FantomTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-testnet.ftmscan.com/api",
+                base_url: "https://testnet.ftmscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Moonbeam => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-moonbeam.moonscan.io/api",
+                base_url: "https://moonbeam.moonscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Moonriver => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-moonriver.moonscan.io/api",
+                base_url: "https://moonriver.moonscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Moonbase => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-moonbase.moonscan.io/api",
+                base_url: "https://moonbase.moonscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        GravityAlphaMainnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.gravity.xyz/api",
+                base_url: "https://explorer.gravity.xyz",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        GravityAlphaTestnetSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer-sepolia.gravity.xyz/api",
+                base_url: "https://explorer-sepolia.gravity.xyz",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Evmos => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://evm.evmos.org/api",
+                base_url: "https://evm.evmos.org",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        EvmosTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://evm.evmos.dev/api",
+                base_url: "https://evm.evmos.dev",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Chiado => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://blockscout.chiadochain.net/api",
+                base_url: "https://blockscout.chiadochain.net",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Oasis => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://scan.oasischain.io/api",
+                base_url: "https://scan.oasischain.io",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        Emerald => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.emerald.oasis.dev/api",
+                base_url: "https://explorer.emerald.oasis.dev",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        EmeraldTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://testnet.explorer.emerald.oasis.dev/api",
+                base_url: "https://testnet.explorer.emerald.oasis.dev",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        FilecoinCalibrationTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://api.calibration.node.glif.io/rpc/v1",
+                base_url: "https://calibration.filfox.info/en",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        Avalanche => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Routescan,
+                api_url: "https://api.snowtrace.io/api",
+                base_url: "https://snowtrace.io",
+                priority: 0,
+                rate_limit_hint: Some(2),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        AvalancheFuji => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Routescan,
+                api_url: "https://api-testnet.snowtrace.io/api",
+                base_url: "https://testnet.snowtrace.io",
+                priority: 0,
+                rate_limit_hint: Some(2),
+            },
+        ],
+        Celo => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.celoscan.io/api",
+                base_url: "https://celoscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        CeloAlfajores => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-alfajores.celoscan.io/api",
+                base_url: "https://alfajores.celoscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        CeloBaklava => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.celo.org/baklava/api",
+                base_url: "https://explorer.celo.org/baklava",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Aurora => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.aurorascan.dev/api",
+                base_url: "https://aurorascan.dev",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        AuroraTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://testnet.aurorascan.dev/api",
+                base_url: "https://testnet.aurorascan.dev",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Canto => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://evm.explorer.canto.io/api",
+                base_url: "https://evm.explorer.canto.io",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        CantoTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://testnet-explorer.canto.neobase.one/api",
+                base_url: "https://testnet-explorer.canto.neobase.one",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Boba => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.bobascan.com/api",
+                base_url: "https://bobascan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Base => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.basescan.org/api",
+                base_url: "https://basescan.org",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://base.blockscout.com/api",
+                base_url: "https://base.blockscout.com",
+                priority: 1,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseGoerli => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-goerli.basescan.org/api",
+                base_url: "https://goerli.basescan.org",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-sepolia.basescan.org/api",
+                base_url: "https://sepolia.basescan.org",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://base-sepolia.blockscout.com/api",
+                base_url: "https://base-sepolia.blockscout.com",
+                priority: 1,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Syndr => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.syndr.com/api",
+                base_url: "https://explorer.syndr.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        SyndrSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://sepolia-explorer.syndr.com/api",
+                base_url: "https://sepolia-explorer.syndr.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Shimmer => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.evm.shimmer.network/api",
+                base_url: "https://explorer.evm.shimmer.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Ink => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.inkonchain.com/api/v2",
+                base_url: "https://explorer.inkonchain.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        InkSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer-sepolia.inkonchain.com/api/v2",
+                base_url: "https://explorer-sepolia.inkonchain.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Fraxtal => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.fraxscan.com/api",
+                base_url: "https://fraxscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        FraxtalTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-holesky.fraxscan.com/api",
+                base_url: "https://holesky.fraxscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Blast => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.blastscan.io/api",
+                base_url: "https://blastscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        BlastSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-sepolia.blastscan.io/api",
+                base_url: "https://sepolia.blastscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Linea => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.lineascan.build/api",
+                base_url: "https://lineascan.build",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        LineaGoerli => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://explorer.goerli.linea.build/api",
+                base_url: "https://explorer.goerli.linea.build",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        LineaSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-sepolia.lineascan.build/api",
+                base_url: "https://sepolia.lineascan.build",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        ZkSync => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-era.zksync.network/api",
+                base_url: "https://era.zksync.network",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        ZkSyncTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-sepolia-era.zksync.network/api",
+                base_url: "https://sepolia-era.zksync.network",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Mantle => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.mantle.xyz/api",
+                base_url: "https://explorer.mantle.xyz",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        MantleTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.testnet.mantle.xyz/api",
+                base_url: "https://explorer.testnet.mantle.xyz",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        MantleSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.sepolia.mantle.xyz/api",
+                base_url: "https://explorer.sepolia.mantle.xyz",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Xai => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://xaiscan.io/api",
+                base_url: "https://xaiscan.io",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        XaiSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://sepolia.xaiscan.io/api",
+                base_url: "https://sepolia.xaiscan.io",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        HappychainTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://happy-testnet-sepolia.explorer.caldera.xyz/api",
+                base_url: "https://happy-testnet-sepolia.explorer.caldera.xyz",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Viction => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://www.vicscan.xyz/api",
+                base_url: "https://www.vicscan.xyz",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        Zora => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.zora.energy/api",
+                base_url: "https://explorer.zora.energy",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        ZoraGoerli => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://testnet.explorer.zora.energy/api",
+                base_url: "https://testnet.explorer.zora.energy",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        ZoraSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://sepolia.explorer.zora.energy/api",
+                base_url: "https://sepolia.explorer.zora.energy",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Pgn => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.publicgoods.network/api",
+                base_url: "https://explorer.publicgoods.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        PgnSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.sepolia.publicgoods.network/api",
+                base_url: "https://explorer.sepolia.publicgoods.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Mode => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.mode.network/api",
+                base_url: "https://explorer.mode.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        ModeSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://sepolia.explorer.mode.network/api",
+                base_url: "https://sepolia.explorer.mode.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Elastos => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://esc.elastos.io/api",
+                base_url: "https://esc.elastos.io",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        KakarotSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://sepolia.kakarotscan.org/api",
+                base_url: "https://sepolia.kakarotscan.org",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Etherlink => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.etherlink.com/api",
+                base_url: "https://explorer.etherlink.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        EtherlinkTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://testnet-explorer.etherlink.com/api",
+                base_url: "https://testnet-explorer.etherlink.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Degen => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.degen.tips/api",
+                base_url: "https://explorer.degen.tips",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        OpBNBMainnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://opbnb.bscscan.com/api",
+                base_url: "https://opbnb.bscscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        OpBNBTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://opbnb-testnet.bscscan.com/api",
+                base_url: "https://opbnb-testnet.bscscan.com",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Ronin => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://skynet-api.roninchain.com/ronin",
+                base_url: "https://app.roninchain.com",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        RoninTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://api-gateway.skymavis.com/rpc/testnet",
+                base_url: "https://saigon-app.roninchain.com",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        Taiko => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.taikoscan.io/api",
+                base_url: "https://taikoscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        TaikoHekla => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-testnet.taikoscan.io/api",
+                base_url: "https://hekla.taikoscan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Flare => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://flare-explorer.flare.network/api",
+                base_url: "https://flare-explorer.flare.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        FlareCoston2 => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://coston2-explorer.flare.network/api",
+                base_url: "https://coston2-explorer.flare.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Acala => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://blockscout.acala.network/api",
+                base_url: "https://blockscout.acala.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        AcalaMandalaTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://blockscout.mandala.aca-staging.network/api",
+                base_url: "https://blockscout.mandala.aca-staging.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        AcalaTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://blockscout.acala-testnet.aca-staging.network/api",
+                base_url: "https://blockscout.acala-testnet.aca-staging.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Karura => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://blockscout.karura.network/api",
+                base_url: "https://blockscout.karura.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        KaruraTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://blockscout.karura-testnet.aca-staging.network/api",
+                base_url: "https://blockscout.karura-testnet.aca-staging.network",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Pulsechain => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://api.scan.pulsechain.com",
+                base_url: "https://scan.pulsechain.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        PulsechainTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://api.scan.v4.testnet.pulsechain.com",
+                base_url: "https://scan.v4.testnet.pulsechain.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Immutable => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.immutable.com/api",
+                base_url: "https://explorer.immutable.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        ImmutableTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer.testnet.immutable.com/api",
+                base_url: "https://explorer.testnet.immutable.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        SoneiumMinatoTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://explorer-testnet.soneium.org/api",
+                base_url: "https://explorer-testnet.soneium.org",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        World => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.worldscan.org/api",
+                base_url: "https://worldscan.org",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        WorldSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-sepolia.worldscan.org/api",
+                base_url: "https://sepolia.worldscan.org",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        Core => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://openapi.coredao.org/api",
+                base_url: "https://scan.coredao.org",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        Merlin => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://scan.merlinchain.io/api",
+                base_url: "https://scan.merlinchain.io",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        Bitlayer => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://api.btrscan.com/scan/api",
+                base_url: "https://www.btrscan.com",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        Vana => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://vanascan.io/api",
+                base_url: "https://vanascan.io",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Zeta => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Blockscout,
+                api_url: "https://zetachain.blockscout.com/api",
+                base_url: "https://zetachain.blockscout.com",
+                priority: 0,
+                rate_limit_hint: Some(5),
+            },
+        ],
+        Kaia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://mainnet-oapi.kaiascan.io/api",
+                base_url: "https://kaiascan.io",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        UnichainSepolia => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api-sepolia.uniscan.xyz/api",
+                base_url: "https://sepolia.uniscan.xyz",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        ApeChain => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://api.apescan.io/api",
+                base_url: "https://apescan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Curtis => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Etherscan,
+                api_url: "https://curtis.explorer.caldera.xyz/api/v2",
+                base_url: "https://curtis.apescan.io",
+                priority: 0,
+                rate_limit_hint: Some(3),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        SonicTestnet => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Routescan,
+                api_url: "https://api.routescan.io/v2/network/testnet/evm/64165/etherscan/api",
+                base_url: "https://scan.soniclabs.com",
+                priority: 0,
+                rate_limit_hint: Some(2),
+            },
+        ],
+        Treasure => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://block-explorer.treasurescan.io/api",
+                base_url: "https://treasurescan.io",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        TreasureTopaz => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Custom,
+                api_url: "https://block-explorer.topaz.treasurescan.io/api",
+                base_url: "https://topaz.treasurescan.io",
+                priority: 0,
+                rate_limit_hint: None,
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        BerachainBartio => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Routescan,
+                api_url: "https://bartio.beratrail.io/api",
+                base_url: "https://bartio.beratrail.io",
+                priority: 0,
+                rate_limit_hint: Some(2),
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        BerachainArtio => &[
+            ExplorerInfo {
+                kind: crate::ExplorerKind::Routescan,
+                api_url: "https://artio.beratrail.io/api",
+                base_url: "https://artio.beratrail.io",
+                priority: 0,
+                rate_limit_hint: Some(2),
+            },
+        ],
+        _ => &[],
+    }
+}
+
+#[cfg(feature = "slim")]
+pub(super) const fn explorers(_chain: NamedChain) -> &'static [ExplorerInfo] {
+    &[]
+}
+
+pub(super) const fn mev_endpoints(chain: NamedChain) -> &'static [MevEndpoint] {
+    use NamedChain::*;
+
+    match chain {
+        Mainnet => &[
+            MevEndpoint {
+                name: "Flashbots Protect",
+                kind: crate::MevEndpointKind::Protect,
+                url: "https://rpc.flashbots.net",
+            },
+            MevEndpoint {
+                name: "MEV Blocker",
+                kind: crate::MevEndpointKind::Protect,
+                url: "https://rpc.mevblocker.io",
+            },
+            MevEndpoint {
+                name: "Flashbots",
+                kind: crate::MevEndpointKind::Bundle,
+                url: "https://relay.flashbots.net",
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Holesky => &[
+            MevEndpoint {
+                name: "Flashbots Protect",
+                kind: crate::MevEndpointKind::Protect,
+                url: "https://rpc-holesky.flashbots.net",
+            },
+            MevEndpoint {
+                name: "Flashbots",
+                kind: crate::MevEndpointKind::Bundle,
+                url: "https://relay-holesky.flashbots.net",
+            },
+        ],
+        #[cfg(not(feature = "mainnets-only"))]
+        Sepolia => &[
+            MevEndpoint {
+                name: "Flashbots Protect",
+                kind: crate::MevEndpointKind::Protect,
+                url: "https://rpc-sepolia.flashbots.net",
+            },
+            MevEndpoint {
+                name: "Flashbots",
+                kind: crate::MevEndpointKind::Bundle,
+                url: "https://relay-sepolia.flashbots.net",
+            },
+        ],
+        BinanceSmartChain => &[
+            MevEndpoint {
+                name: "bloXroute BSC Protect",
+                kind: crate::MevEndpointKind::Protect,
+                url: "https://bsc.rpc.blxrbdn.com",
+            },
+        ],
+        _ => &[],
+    }
+}
+
+pub(super) const fn etherscan_api_key_name(chain: NamedChain) -> Option<&'static str> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        Morden => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        Ropsten => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        Rinkeby => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        Goerli => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        Kovan => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        Holesky => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        Hoodi => "ETHERSCAN_API_KEY",
+        Optimism => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismKovan => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismGoerli => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismSepolia => "ETHERSCAN_API_KEY",
+        Arbitrum => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumTestnet => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumGoerli => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumSepolia => "ETHERSCAN_API_KEY",
+        ArbitrumNova => "ETHERSCAN_API_KEY",
+        Cronos => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        CronosTestnet => "ETHERSCAN_API_KEY",
+        Crab => "BLOCKSCOUT_API_KEY",
+        Darwinia => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        Koi => "BLOCKSCOUT_API_KEY",
+        BinanceSmartChain => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        BinanceSmartChainTestnet => "ETHERSCAN_API_KEY",
+        Scroll => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        ScrollSepolia => "ETHERSCAN_API_KEY",
+        Gnosis => "ETHERSCAN_API_KEY",
+        Polygon => "POLYGONSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonMumbai => "POLYGONSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonAmoy => "POLYGONSCAN_API_KEY",
+        PolygonZkEvm => "POLYGONSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        PolygonZkEvmTestnet => "POLYGONSCAN_API_KEY",
+        Fantom => "FTMSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        FantomTestnet => "FTMSCAN_API_KEY",
+        Moonbeam => "MOONSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        MoonbeamDev => "MOONSCAN_API_KEY",
+        Moonriver => "MOONSCAN_API_KEY",
+        Moonbase => "MOONSCAN_API_KEY",
+        Avalanche => "SNOWTRACE_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        AvalancheFuji => "SNOWTRACE_API_KEY",
+        Celo => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        CeloAlfajores => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        CeloBaklava => "BLOCKSCOUT_API_KEY",
+        Aurora => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        AuroraTestnet => "ETHERSCAN_API_KEY",
+        Canto => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        CantoTestnet => "BLOCKSCOUT_API_KEY",
+        Boba => "BOBASCAN_API_KEY",
+        Base => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseGoerli => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseSepolia => "ETHERSCAN_API_KEY",
+        Syndr => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        SyndrSepolia => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        Shimmer => "BLOCKSCOUT_API_KEY",
+        Ink => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        InkSepolia => "BLOCKSCOUT_API_KEY",
+        Fraxtal => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        FraxtalTestnet => "ETHERSCAN_API_KEY",
+        Blast => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        BlastSepolia => "ETHERSCAN_API_KEY",
+        Linea => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        LineaSepolia => "ETHERSCAN_API_KEY",
+        Mantle => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        MantleTestnet => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        MantleSepolia => "ETHERSCAN_API_KEY",
+        Xai => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        XaiSepolia => "ETHERSCAN_API_KEY",
+        Zora => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        ZoraGoerli => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        ZoraSepolia => "BLOCKSCOUT_API_KEY",
+        Pgn => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        PgnSepolia => "BLOCKSCOUT_API_KEY",
+        Mode => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        ModeSepolia => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        KakarotSepolia => "BLOCKSCOUT_API_KEY",
+        Etherlink => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        EtherlinkTestnet => "BLOCKSCOUT_API_KEY",
+        OpBNBMainnet => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        OpBNBTestnet => "ETHERSCAN_API_KEY",
+        Taiko => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        TaikoHekla => "ETHERSCAN_API_KEY",
+        Flare => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        FlareCoston2 => "BLOCKSCOUT_API_KEY",
+        Acala => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        AcalaMandalaTestnet => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        AcalaTestnet => "BLOCKSCOUT_API_KEY",
+        Karura => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        KaruraTestnet => "BLOCKSCOUT_API_KEY",
+        Immutable => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        ImmutableTestnet => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        SoneiumMinatoTestnet => "BLOCKSCOUT_API_KEY",
+        World => "BLOCKSCOUT_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        WorldSepolia => "BLOCKSCOUT_API_KEY",
+        Core => "CORESCAN_API_KEY",
+        Merlin => "MERLINSCAN_API_KEY",
+        Bitlayer => "BITLAYERSCAN_API_KEY",
+        Vana => "VANASCAN_API_KEY",
+        Zeta => "ZETASCAN_API_KEY",
+        Kaia => "KAIASCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        UnichainSepolia => "ETHERSCAN_API_KEY",
+        ApeChain => "ETHERSCAN_API_KEY",
+        #[cfg(not(feature = "mainnets-only"))]
+        Curtis => "BLOCKSCOUT_API_KEY",
+        _ => return None,
+    })
+}
+
+pub(super) const fn beacon_api_url(chain: NamedChain) -> Option<&'static str> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => "https://ethereum-beacon-api.publicnode.com",
+        #[cfg(not(feature = "mainnets-only"))]
+        Holesky => "https://ethereum-holesky-beacon-api.publicnode.com",
+        #[cfg(not(feature = "mainnets-only"))]
+        Hoodi => "https://ethereum-hoodi-beacon-api.publicnode.com",
+        #[cfg(not(feature = "mainnets-only"))]
+        Sepolia => "https://ethereum-sepolia-beacon-api.publicnode.com",
+        Gnosis => "https://rpc-gbc.gnosischain.com",
+        _ => return None,
+    })
+}
+
+pub(super) const fn token_list_url(chain: NamedChain) -> Option<&'static str> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => "https://tokens.uniswap.org",
+        Optimism => "https://static.optimism.io/optimism.tokenlist.json",
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismSepolia => "https://static.optimism.io/optimism.tokenlist.json",
+        Arbitrum => "https://tokens.uniswap.org",
+        BinanceSmartChain => "https://tokens.uniswap.org",
+        Polygon => "https://tokens.uniswap.org",
+        Avalanche => "https://tokens.uniswap.org",
+        Celo => "https://tokens.uniswap.org",
+        Base => "https://static.optimism.io/optimism.tokenlist.json",
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseSepolia => "https://static.optimism.io/optimism.tokenlist.json",
+        _ => return None,
+    })
+}
+
+pub(super) const fn sequencer_rpc_url(chain: NamedChain) -> Option<&'static str> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Optimism => "https://mainnet-sequencer.optimism.io",
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismSepolia => "https://sepolia-sequencer.optimism.io",
+        Arbitrum => "https://arb1-sequencer.arbitrum.io/rpc",
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumSepolia => "https://sepolia-rollup-sequencer.arbitrum.io/rpc",
+        ArbitrumNova => "https://nova-sequencer.arbitrum.io/rpc",
+        Base => "https://mainnet-sequencer.base.org",
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseSepolia => "https://sepolia-sequencer.base.org",
+        _ => return None,
+    })
+}
+
+pub(super) const fn l2beat_slug(chain: NamedChain) -> Option<&'static str> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Optimism => "op-mainnet",
+        Arbitrum => "arbitrum",
+        ArbitrumNova => "nova",
+        Scroll => "scroll",
+        Base => "base",
+        Blast => "blast",
+        Linea => "linea",
+        ZkSync => "zksync-era",
+        Mantle => "mantle",
+        Zora => "zora",
+        Mode => "mode",
+        Taiko => "taiko",
+        _ => return None,
+    })
+}
+
+pub(super) const fn defillama_slug(chain: NamedChain) -> Option<&'static str> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => "ethereum",
+        Optimism => "optimism",
+        Arbitrum => "arbitrum",
+        ArbitrumNova => "arbitrum_nova",
+        Cronos => "cronos",
+        BinanceSmartChain => "bsc",
+        Scroll => "scroll",
+        Metis => "metis",
+        Gnosis => "xdai",
+        Polygon => "polygon",
+        PolygonZkEvm => "polygon_zkevm",
+        Fantom => "fantom",
+        Moonbeam => "moonbeam",
+        Moonriver => "moonriver",
+        Avalanche => "avax",
+        Celo => "celo",
+        Aurora => "aurora",
+        Boba => "boba",
+        Base => "base",
+        Blast => "blast",
+        Linea => "linea",
+        ZkSync => "era",
+        Mantle => "mantle",
+        Zora => "zora",
+        Mode => "mode",
+        Taiko => "taiko",
+        _ => return None,
+    })
+}
+
+pub(super) const fn chainlist_short_name(chain: NamedChain) -> Option<&'static str> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => "eth",
+        #[cfg(not(feature = "mainnets-only"))]
+        Goerli => "gor",
+        #[cfg(not(feature = "mainnets-only"))]
+        Holesky => "holesky",
+        #[cfg(not(feature = "mainnets-only"))]
+        Sepolia => "sep",
+        Optimism => "oeth",
+        Arbitrum => "arb1",
+        ArbitrumNova => "arb-nova",
+        Cronos => "cro",
+        BinanceSmartChain => "bnb",
+        #[cfg(not(feature = "mainnets-only"))]
+        BinanceSmartChainTestnet => "bnbt",
+        Scroll => "scr",
+        Gnosis => "gno",
+        Polygon => "pol",
+        PolygonZkEvm => "zkevm",
+        Fantom => "ftm",
+        Moonbeam => "mbeam",
+        Moonriver => "mriver",
+        Avalanche => "avax",
+        Celo => "celo",
+        Aurora => "aurora",
+        Base => "base",
+        Fraxtal => "fraxtal",
+        Linea => "linea",
+        ZkSync => "zksync",
+        Mantle => "mantle",
+        Zora => "zora",
+        Mode => "mode",
+        _ => return None,
+    })
+}
+
+#[cfg(feature = "ui-metadata")]
+pub(super) const fn brand_color(chain: NamedChain) -> Option<&'static str> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => "#627EEA",
+        Optimism => "#FF0420",
+        Arbitrum => "#28A0F0",
+        BinanceSmartChain => "#F0B90B",
+        Polygon => "#8247E5",
+        Fantom => "#1969FF",
+        Avalanche => "#E84142",
+        Base => "#0052FF",
+        _ => return None,
+    })
+}
+
+#[cfg(not(feature = "mainnets-only"))]
+pub(super) const fn sunset_date(chain: NamedChain) -> Option<Date> {
+    use NamedChain::*;
+
+    Some(match chain {
+        PolygonMumbai => Date::new(2024, 4, 13),
+        _ => return None,
+    })
+}
+
+#[cfg(feature = "mainnets-only")]
+pub(super) const fn sunset_date(_chain: NamedChain) -> Option<Date> {
+    None
+}
+
+pub(super) const fn reviewed_at(chain: NamedChain) -> Option<Date> {
+    use NamedChain::*;
+
+    Some(match chain {
+        Mainnet => Date::new(2026, 10, 16),
+        #[cfg(not(feature = "mainnets-only"))]
+        Holesky => Date::new(2026, 10, 16),
+        #[cfg(not(feature = "mainnets-only"))]
+        Hoodi => Date::new(2026, 10, 16),
+        #[cfg(not(feature = "mainnets-only"))]
+        Sepolia => Date::new(2026, 10, 16),
+        Optimism => Date::new(2026, 10, 16),
+        #[cfg(not(feature = "mainnets-only"))]
+        OptimismSepolia => Date::new(2026, 10, 16),
+        Arbitrum => Date::new(2026, 10, 16),
+        #[cfg(not(feature = "mainnets-only"))]
+        ArbitrumSepolia => Date::new(2026, 10, 16),
+        ArbitrumNova => Date::new(2026, 10, 16),
+        Base => Date::new(2026, 10, 16),
+        #[cfg(not(feature = "mainnets-only"))]
+        BaseSepolia => Date::new(2026, 10, 16),
+        _ => return None,
+    })
+}
+
+pub(super) const NAMES: &[(&str, NamedChain)] = &[
+    ("acala", NamedChain::Acala),
+    ("acala-mandala-testnet", NamedChain::AcalaMandalaTestnet),
+    ("acala-testnet", NamedChain::AcalaTestnet),
+    ("amoy", NamedChain::PolygonAmoy),
+    ("anvil", NamedChain::AnvilHardhat),
+    ("anvil-hardhat", NamedChain::AnvilHardhat),
+    ("apechain", NamedChain::ApeChain),
+    ("apechain-testnet", NamedChain::Curtis),
+    ("arbitrum", NamedChain::Arbitrum),
+    ("arbitrum-goerli", NamedChain::ArbitrumGoerli),
+    ("arbitrum-nova", NamedChain::ArbitrumNova),
+    ("arbitrum-sepolia", NamedChain::ArbitrumSepolia),
+    ("arbitrum-testnet", NamedChain::ArbitrumTestnet),
+    ("aurora", NamedChain::Aurora),
+    ("aurora-testnet", NamedChain::AuroraTestnet),
+    ("autonomys-nova-testnet", NamedChain::AutonomysNovaTestnet),
+    ("avalanche", NamedChain::Avalanche),
+    ("avalanche-fuji", NamedChain::AvalancheFuji),
+    ("avax", NamedChain::Avalanche),
+    ("base", NamedChain::Base),
+    ("base-goerli", NamedChain::BaseGoerli),
+    ("base-sepolia", NamedChain::BaseSepolia),
+    ("berachain-artio", NamedChain::BerachainArtio),
+    ("berachain-artio-testnet", NamedChain::BerachainArtio),
+    ("berachain-bartio", NamedChain::BerachainBartio),
+    ("berachain-bartio-testnet", NamedChain::BerachainBartio),
+    ("binance-smart-chain", NamedChain::BinanceSmartChain),
+    ("binance-smart-chain-testnet", NamedChain::BinanceSmartChainTestnet),
+    ("bitlayer", NamedChain::Bitlayer),
+    ("blast", NamedChain::Blast),
+    ("blast-sepolia", NamedChain::BlastSepolia),
+    ("bnb-smart-chain", NamedChain::BinanceSmartChain),
+    ("bnb-smart-chain-testnet", NamedChain::BinanceSmartChainTestnet),
+    ("bob", NamedChain::Bob),
+    ("bob-sepolia", NamedChain::BobSepolia),
+    ("boba", NamedChain::Boba),
+    ("bsc", NamedChain::BinanceSmartChain),
+    ("bsc-testnet", NamedChain::BinanceSmartChainTestnet),
+    ("canto", NamedChain::Canto),
+    ("canto-testnet", NamedChain::CantoTestnet),
+    ("celo", NamedChain::Celo),
+    ("celo-alfajores", NamedChain::CeloAlfajores),
+    ("celo-baklava", NamedChain::CeloBaklava),
+    ("cfx", NamedChain::Cfx),
+    ("cfx-testnet", NamedChain::CfxTestnet),
+    ("chiado", NamedChain::Chiado),
+    ("core", NamedChain::Core),
+    ("crab", NamedChain::Crab),
+    ("cronos", NamedChain::Cronos),
+    ("cronos-testnet", NamedChain::CronosTestnet),
+    ("curtis", NamedChain::Curtis),
+    ("darwinia", NamedChain::Darwinia),
+    ("degen", NamedChain::Degen),
+    ("dev", NamedChain::Dev),
+    ("elastos", NamedChain::Elastos),
+    ("emerald", NamedChain::Emerald),
+    ("emerald-testnet", NamedChain::EmeraldTestnet),
+    ("etherlink", NamedChain::Etherlink),
+    ("etherlink-testnet", NamedChain::EtherlinkTestnet),
+    ("ethlive", NamedChain::Mainnet),
+    ("evmos", NamedChain::Evmos),
+    ("evmos-testnet", NamedChain::EvmosTestnet),
+    ("fantom", NamedChain::Fantom),
+    ("fantom-testnet", NamedChain::FantomTestnet),
+    ("filecoin-calibration-testnet", NamedChain::FilecoinCalibrationTestnet),
+    ("filecoin-mainnet", NamedChain::FilecoinMainnet),
+    ("flare", NamedChain::Flare),
+    ("flare-coston2", NamedChain::FlareCoston2),
+    ("fraxtal", NamedChain::Fraxtal),
+    ("fraxtal-testnet", NamedChain::FraxtalTestnet),
+    ("ftm", NamedChain::Fantom),
+    ("fuji", NamedChain::AvalancheFuji),
+    ("gnosis", NamedChain::Gnosis),
+    ("gnosis-chain", NamedChain::Gnosis),
+    ("goerli", NamedChain::Goerli),
+    ("gravity-alpha-mainnet", NamedChain::GravityAlphaMainnet),
+    ("gravity-alpha-testnet-sepolia", NamedChain::GravityAlphaTestnetSepolia),
+    ("happychain-testnet", NamedChain::HappychainTestnet),
+    ("hardhat", NamedChain::AnvilHardhat),
+    ("holesky", NamedChain::Holesky),
+    ("hoodi", NamedChain::Hoodi),
+    ("immutable", NamedChain::Immutable),
+    ("immutable-testnet", NamedChain::ImmutableTestnet),
+    ("ink", NamedChain::Ink),
+    ("ink-sepolia", NamedChain::InkSepolia),
+    ("iotex", NamedChain::Iotex),
+    ("kaia", NamedChain::Kaia),
+    ("kakarot-sepolia", NamedChain::KakarotSepolia),
+    ("karura", NamedChain::Karura),
+    ("karura-testnet", NamedChain::KaruraTestnet),
+    ("klay", NamedChain::Kaia),
+    ("klaytn", NamedChain::Kaia),
+    ("koi", NamedChain::Koi),
+    ("kovan", NamedChain::Kovan),
+    ("linea", NamedChain::Linea),
+    ("linea-goerli", NamedChain::LineaGoerli),
+    ("linea-sepolia", NamedChain::LineaSepolia),
+    ("mainnet", NamedChain::Mainnet),
+    ("mantle", NamedChain::Mantle),
+    ("mantle-sepolia", NamedChain::MantleSepolia),
+    ("mantle-testnet", NamedChain::MantleTestnet),
+    ("matic", NamedChain::Polygon),
+    ("merlin", NamedChain::Merlin),
+    ("metis", NamedChain::Metis),
+    ("mode", NamedChain::Mode),
+    ("mode-sepolia", NamedChain::ModeSepolia),
+    ("moonbase", NamedChain::Moonbase),
+    ("moonbeam", NamedChain::Moonbeam),
+    ("moonbeam-dev", NamedChain::MoonbeamDev),
+    ("moonriver", NamedChain::Moonriver),
+    ("morden", NamedChain::Morden),
+    ("mumbai", NamedChain::PolygonMumbai),
+    ("oasis", NamedChain::Oasis),
+    ("odyssey", NamedChain::Odyssey),
+    ("opbnb-mainnet", NamedChain::OpBNBMainnet),
+    ("opbnb-testnet", NamedChain::OpBNBTestnet),
+    ("optimism", NamedChain::Optimism),
+    ("optimism-goerli", NamedChain::OptimismGoerli),
+    ("optimism-kovan", NamedChain::OptimismKovan),
+    ("optimism-sepolia", NamedChain::OptimismSepolia),
+    ("pgn", NamedChain::Pgn),
+    ("pgn-sepolia", NamedChain::PgnSepolia),
+    ("poa", NamedChain::Poa),
+    ("polygon", NamedChain::Polygon),
+    ("polygon-amoy", NamedChain::PolygonAmoy),
+    ("polygon-mumbai", NamedChain::PolygonMumbai),
+    ("polygon-zkevm", NamedChain::PolygonZkEvm),
+    ("polygon-zkevm-testnet", NamedChain::PolygonZkEvmTestnet),
+    ("pulsechain", NamedChain::Pulsechain),
+    ("pulsechain-testnet", NamedChain::PulsechainTestnet),
+    ("rinkeby", NamedChain::Rinkeby),
+    ("ronin", NamedChain::Ronin),
+    ("ronin-testnet", NamedChain::RoninTestnet),
+    ("ropsten", NamedChain::Ropsten),
+    ("rsk", NamedChain::Rsk),
+    ("scroll", NamedChain::Scroll),
+    ("scroll-sepolia", NamedChain::ScrollSepolia),
+    ("sepolia", NamedChain::Sepolia),
+    ("shimmer", NamedChain::Shimmer),
+    ("sokol", NamedChain::Sokol),
+    ("soneium-minato-testnet", NamedChain::SoneiumMinatoTestnet),
+    ("sonic-testnet", NamedChain::SonicTestnet),
+    ("syndr", NamedChain::Syndr),
+    ("syndr-sepolia", NamedChain::SyndrSepolia),
+    ("taiko", NamedChain::Taiko),
+    ("taiko-hekla", NamedChain::TaikoHekla),
+    ("treasure", NamedChain::Treasure),
+    ("treasure-topaz", NamedChain::TreasureTopaz),
+    ("treasure-topaz-testnet", NamedChain::TreasureTopaz),
+    ("unichain-sepolia", NamedChain::UnichainSepolia),
+    ("vana", NamedChain::Vana),
+    ("viction", NamedChain::Viction),
+    ("world", NamedChain::World),
+    ("world-sepolia", NamedChain::WorldSepolia),
+    ("xai", NamedChain::Xai),
+    ("xai-sepolia", NamedChain::XaiSepolia),
+    ("xdai", NamedChain::Gnosis),
+    ("zeta", NamedChain::Zeta),
+    ("zkevm", NamedChain::PolygonZkEvm),
+    ("zkevm-testnet", NamedChain::PolygonZkEvmTestnet),
+    ("zksync", NamedChain::ZkSync),
+    ("zksync-testnet", NamedChain::ZkSyncTestnet),
+    ("zora", NamedChain::Zora),
+    ("zora-goerli", NamedChain::ZoraGoerli),
+    ("zora-sepolia", NamedChain::ZoraSepolia),
+];
+
+pub(super) const fn name(chain: NamedChain) -> &'static str {
+    use NamedChain::*;
+
+    match chain {
+        Mainnet => "mainnet",
+        Morden => "morden",
+        Ropsten => "ropsten",
+        Rinkeby => "rinkeby",
+        Goerli => "goerli",
+        Kovan => "kovan",
+        Holesky => "holesky",
+        Hoodi => "hoodi",
+        Sepolia => "sepolia",
+        Odyssey => "odyssey",
+        Optimism => "optimism",
+        OptimismKovan => "optimism-kovan",
+        OptimismGoerli => "optimism-goerli",
+        OptimismSepolia => "optimism-sepolia",
+        Bob => "bob",
+        BobSepolia => "bob-sepolia",
+        Arbitrum => "arbitrum",
+        ArbitrumTestnet => "arbitrum-testnet",
+        ArbitrumGoerli => "arbitrum-goerli",
+        ArbitrumSepolia => "arbitrum-sepolia",
+        ArbitrumNova => "arbitrum-nova",
+        Cronos => "cronos",
+        CronosTestnet => "cronos-testnet",
+        Rsk => "rsk",
+        Crab => "crab",
+        Darwinia => "darwinia",
+        Koi => "koi",
+        BinanceSmartChain => "bsc",
+        BinanceSmartChainTestnet => "bsc-testnet",
+        Poa => "poa",
+        Sokol => "sokol",
+        Scroll => "scroll",
+        ScrollSepolia => "scroll-sepolia",
+        Metis => "metis",
+        CfxTestnet => "cfx-testnet",
+        Cfx => "cfx",
+        Gnosis => "xdai",
+        Polygon => "polygon",
+        PolygonMumbai => "mumbai",
+        PolygonAmoy => "amoy",
+        PolygonZkEvm => "polygon-zkevm",
+        PolygonZkEvmTestnet => "polygon-zkevm-testnet",
+        Fantom => "fantom",
+        FantomTestnet => "fantom-testnet",
+        Moonbeam => "moonbeam",
+        MoonbeamDev => "moonbeam-dev",
+        Moonriver => "moonriver",
+        Moonbase => "moonbase",
+        Dev => "dev",
+        AnvilHardhat => "anvil-hardhat",
+        GravityAlphaMainnet => "gravity-alpha-mainnet",
+        GravityAlphaTestnetSepolia => "gravity-alpha-testnet-sepolia",
+        Evmos => "evmos",
+        EvmosTestnet => "evmos-testnet",
+        Chiado => "chiado",
+        Oasis => "oasis",
+        Emerald => "emerald",
+        EmeraldTestnet => "emerald-testnet",
+        FilecoinMainnet => "filecoin-mainnet",
+        FilecoinCalibrationTestnet => "filecoin-calibration-testnet",
+        Avalanche => "avalanche",
+        AvalancheFuji => "fuji",
+        Celo => "celo",
+        CeloAlfajores => "celo-alfajores",
+        CeloBaklava => "celo-baklava",
+        Aurora => "aurora",
+        AuroraTestnet => "aurora-testnet",
+        Canto => "canto",
+        CantoTestnet => "canto-testnet",
+        Boba => "boba",
+        Base => "base",
+        BaseGoerli => "base-goerli",
+        BaseSepolia => "base-sepolia",
+        Syndr => "syndr",
+        SyndrSepolia => "syndr-sepolia",
+        Shimmer => "shimmer",
+        Ink => "ink",
+        InkSepolia => "ink-sepolia",
+        Fraxtal => "fraxtal",
+        FraxtalTestnet => "fraxtal-testnet",
+        Blast => "blast",
+        BlastSepolia => "blast-sepolia",
+        Linea => "linea",
+        LineaGoerli => "linea-goerli",
+        LineaSepolia => "linea-sepolia",
+        ZkSync => "zksync",
+        ZkSyncTestnet => "zksync-testnet",
+        Mantle => "mantle",
+        MantleTestnet => "mantle-testnet",
+        MantleSepolia => "mantle-sepolia",
+        Xai => "xai",
+        XaiSepolia => "xai-sepolia",
+        HappychainTestnet => "happychain-testnet",
+        Viction => "viction",
+        Zora => "zora",
+        ZoraGoerli => "zora-goerli",
+        ZoraSepolia => "zora-sepolia",
+        Pgn => "pgn",
+        PgnSepolia => "pgn-sepolia",
+        Mode => "mode",
+        ModeSepolia => "mode-sepolia",
+        Elastos => "elastos",
+        KakarotSepolia => "kakarot-sepolia",
+        Etherlink => "etherlink",
+        EtherlinkTestnet => "etherlink-testnet",
+        Degen => "degen",
+        OpBNBMainnet => "opbnb-mainnet",
+        OpBNBTestnet => "opbnb-testnet",
+        Ronin => "ronin",
+        RoninTestnet => "ronin-testnet",
+        Taiko => "taiko",
+        TaikoHekla => "taiko-hekla",
+        AutonomysNovaTestnet => "autonomys-nova-testnet",
+        Flare => "flare",
+        FlareCoston2 => "flare-coston2",
+        Acala => "acala",
+        AcalaMandalaTestnet => "acala-mandala-testnet",
+        AcalaTestnet => "acala-testnet",
+        Karura => "karura",
+        KaruraTestnet => "karura-testnet",
+        Pulsechain => "pulsechain",
+        PulsechainTestnet => "pulsechain-testnet",
+        Immutable => "immutable",
+        ImmutableTestnet => "immutable-testnet",
+        SoneiumMinatoTestnet => "soneium-minato-testnet",
+        World => "world",
+        WorldSepolia => "world-sepolia",
+        Iotex => "iotex",
+        Core => "core",
+        Merlin => "merlin",
+        Bitlayer => "bitlayer",
+        Vana => "vana",
+        Zeta => "zeta",
+        Kaia => "kaia",
+        UnichainSepolia => "unichain-sepolia",
+        ApeChain => "apechain",
+        Curtis => "curtis",
+        SonicTestnet => "sonic-testnet",
+        Treasure => "treasure",
+        TreasureTopaz => "treasure-topaz",
+        BerachainBartio => "berachain-bartio",
+        BerachainArtio => "berachain-artio",
+    }
+}
+
+pub(super) const IDS: &[u64] = &[
+    1,
+    2,
+    3,
+    4,
+    5,
+    10,
+    14,
+    20,
+    25,
+    30,
+    42,
+    44,
+    46,
+    56,
+    69,
+    71,
+    77,
+    88,
+    97,
+    99,
+    100,
+    114,
+    137,
+    148,
+    204,
+    216,
+    250,
+    252,
+    288,
+    300,
+    314,
+    324,
+    338,
+    369,
+    404,
+    420,
+    424,
+    480,
+    595,
+    596,
+    597,
+    686,
+    701,
+    740,
+    787,
+    919,
+    943,
+    999,
+    1_030,
+    1_088,
+    1_101,
+    1_116,
+    1_281,
+    1_284,
+    1_285,
+    1_287,
+    1_301,
+    1_337,
+    1_442,
+    1_480,
+    1_625,
+    1_946,
+    2_020,
+    2_021,
+    2_522,
+    4_002,
+    4_200,
+    4_689,
+    4_801,
+    5_000,
+    5_001,
+    5_003,
+    5_611,
+    7_000,
+    7_700,
+    8_217,
+    8_453,
+    9_000,
+    9_001,
+    10_200,
+    13_371,
+    13_473,
+    13_505,
+    17_000,
+    26_863,
+    31_337,
+    33_111,
+    33_139,
+    34_443,
+    42_161,
+    42_170,
+    42_220,
+    42_261,
+    42_262,
+    42_793,
+    43_113,
+    43_114,
+    44_787,
+    57_073,
+    58_008,
+    59_140,
+    59_141,
+    59_144,
+    60_808,
+    61_166,
+    62_320,
+    64_165,
+    80_001,
+    80_002,
+    80_084,
+    80_085,
+    81_457,
+    84_531,
+    84_532,
+    128_123,
+    167_000,
+    167_009,
+    200_901,
+    314_159,
+    421_611,
+    421_613,
+    421_614,
+    444_444,
+    490_000,
+    534_351,
+    534_352,
+    560_048,
+    660_279,
+    763_373,
+    808_813,
+    911_867,
+    978_658,
+    7_777_777,
+    11_155_111,
+    11_155_420,
+    168_587_773,
+    666_666_666,
+    999_999_999,
+    1_313_161_554,
+    1_313_161_555,
+    37_714_555_429,
+    920_637_907_288_165,
+];
@@ -0,0 +1,80 @@
+//! EVM opcodes introduced by network upgrades.
+
+use crate::hardfork::Hardfork;
+
+/// An EVM opcode whose availability or semantics differ across chains.
+///
+/// Used with [`NamedChain::opcode_support`](crate::NamedChain::opcode_support).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Opcode {
+    /// `CLZ`: [EIP-7939](https://eips.ethereum.org/EIPS/eip-7939).
+    Clz = 0x1e,
+    /// `CHAINID`: [EIP-1344](https://eips.ethereum.org/EIPS/eip-1344).
+    ChainId = 0x46,
+    /// `SELFBALANCE`: [EIP-1884](https://eips.ethereum.org/EIPS/eip-1884).
+    SelfBalance = 0x47,
+    /// `BASEFEE`: [EIP-3198](https://eips.ethereum.org/EIPS/eip-3198).
+    BaseFee = 0x48,
+    /// `BLOBHASH`: [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+    BlobHash = 0x49,
+    /// `BLOBBASEFEE`: [EIP-7516](https://eips.ethereum.org/EIPS/eip-7516).
+    BlobBaseFee = 0x4a,
+    /// `TLOAD`: [EIP-1153](https://eips.ethereum.org/EIPS/eip-1153).
+    TLoad = 0x5c,
+    /// `TSTORE`: [EIP-1153](https://eips.ethereum.org/EIPS/eip-1153).
+    TStore = 0x5d,
+    /// `MCOPY`: [EIP-5656](https://eips.ethereum.org/EIPS/eip-5656).
+    MCopy = 0x5e,
+    /// `PUSH0`: [EIP-3855](https://eips.ethereum.org/EIPS/eip-3855).
+    Push0 = 0x5f,
+    /// `SELFDESTRUCT`, restricted by [EIP-6780](https://eips.ethereum.org/EIPS/eip-6780) since
+    /// Cancun.
+    SelfDestruct = 0xff,
+}
+
+impl Opcode {
+    /// Returns the opcode's byte value.
+    #[inline]
+    pub const fn byte(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the Ethereum network upgrade that introduced the opcode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{hardfork::Hardfork, Opcode};
+    ///
+    /// assert_eq!(Opcode::Push0.introduced_in(), Hardfork::Shanghai);
+    /// assert_eq!(Opcode::TStore.introduced_in(), Hardfork::Cancun);
+    /// ```
+    pub const fn introduced_in(self) -> Hardfork {
+        match self {
+            Self::SelfDestruct => Hardfork::Frontier,
+            Self::ChainId | Self::SelfBalance => Hardfork::Istanbul,
+            Self::BaseFee => Hardfork::London,
+            Self::Push0 => Hardfork::Shanghai,
+            Self::BlobHash | Self::BlobBaseFee | Self::TLoad | Self::TStore | Self::MCopy => {
+                Hardfork::Cancun
+            }
+            Self::Clz => Hardfork::Osaka,
+        }
+    }
+}
+
+/// Whether a chain supports an [`Opcode`].
+///
+/// Returned by [`NamedChain::opcode_support`](crate::NamedChain::opcode_support).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OpcodeSupport {
+    /// The opcode is supported with its original semantics.
+    Supported,
+    /// The opcode is supported with restricted semantics, e.g. `SELFDESTRUCT` after
+    /// [EIP-6780](https://eips.ethereum.org/EIPS/eip-6780).
+    Restricted,
+    /// The opcode is not supported.
+    Unsupported,
+}
@@ -0,0 +1,74 @@
+//! Precompiled contracts.
+
+use alloy_primitives::Address;
+
+/// A precompiled contract.
+///
+/// Returned by [`NamedChain::supported_precompiles`](crate::NamedChain::supported_precompiles).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Precompile {
+    /// `ecrecover`, at `0x01`.
+    EcRecover,
+    /// `sha256`, at `0x02`.
+    Sha256,
+    /// `ripemd160`, at `0x03`.
+    Ripemd160,
+    /// `identity`, at `0x04`.
+    Identity,
+    /// `modexp`, at `0x05`: [EIP-198](https://eips.ethereum.org/EIPS/eip-198).
+    ModExp,
+    /// BN254 point addition, at `0x06`: [EIP-196](https://eips.ethereum.org/EIPS/eip-196).
+    Bn254Add,
+    /// BN254 scalar multiplication, at `0x07`: [EIP-196](https://eips.ethereum.org/EIPS/eip-196).
+    Bn254Mul,
+    /// BN254 pairing check, at `0x08`: [EIP-197](https://eips.ethereum.org/EIPS/eip-197).
+    Bn254Pairing,
+    /// `blake2f`, at `0x09`: [EIP-152](https://eips.ethereum.org/EIPS/eip-152).
+    Blake2F,
+    /// KZG point evaluation, at `0x0a`: [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+    PointEvaluation,
+    /// BLS12-381 G1 addition, at `0x0b`: [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537).
+    Bls12G1Add,
+    /// BLS12-381 G1 multi-scalar multiplication, at `0x0c`.
+    Bls12G1Msm,
+    /// BLS12-381 G2 addition, at `0x0d`.
+    Bls12G2Add,
+    /// BLS12-381 G2 multi-scalar multiplication, at `0x0e`.
+    Bls12G2Msm,
+    /// BLS12-381 pairing check, at `0x0f`.
+    Bls12PairingCheck,
+    /// BLS12-381 field element to G1 mapping, at `0x10`.
+    Bls12MapFpToG1,
+    /// BLS12-381 field element to G2 mapping, at `0x11`.
+    Bls12MapFp2ToG2,
+    /// Celo's native token `transfer`, at `0xfd`.
+    CeloTransfer,
+    /// secp256r1 signature verification, at `0x0100`:
+    /// [RIP-7212](https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md), standardized as
+    /// [EIP-7951](https://eips.ethereum.org/EIPS/eip-7951).
+    P256Verify,
+}
+
+impl Precompile {
+    /// Returns the precompile's address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::Precompile;
+    /// use alloy_primitives::Address;
+    ///
+    /// assert_eq!(Precompile::EcRecover.address(), Address::with_last_byte(1));
+    /// assert_eq!(Precompile::P256Verify.address().to_string(), "0x0000000000000000000000000000000000000100");
+    /// ```
+    pub const fn address(self) -> Address {
+        let mut bytes = [0; 20];
+        match self {
+            Self::CeloTransfer => bytes[19] = 0xfd,
+            Self::P256Verify => bytes[18] = 0x01,
+            _ => bytes[19] = self as u8 + 1,
+        }
+        Address::new(bytes)
+    }
+}
@@ -0,0 +1,218 @@
+//! Runtime verification of a chain's RPC endpoint against the static tables.
+//!
+//! Capability tables such as [`NamedChain::supports_push0`] are curated by hand and can go stale
+//! when a chain upgrades. [`NamedChain::verify_rpc`] probes a live endpoint instead, over any
+//! HTTP client implementing [`RpcTransport`].
+
+use crate::NamedChain;
+use alloc::string::{String, ToString};
+use core::fmt;
+use serde_json::{json, Value};
+
+/// A blocking JSON-RPC transport, used by [`NamedChain::verify_rpc`].
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::probe::RpcTransport;
+///
+/// /// A transport replaying canned responses, for tests.
+/// struct Canned;
+///
+/// impl RpcTransport for Canned {
+///     type Error = std::convert::Infallible;
+///
+///     fn post(&mut self, _url: &str, body: &str) -> Result<String, Self::Error> {
+///         Ok(if body.contains("eth_chainId") {
+///             r#"{"jsonrpc":"2.0","id":1,"result":"0x1"}"#.into()
+///         } else {
+///             r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"not found"}}"#.into()
+///         })
+///     }
+/// }
+/// ```
+pub trait RpcTransport {
+    /// The error returned when the request could not be delivered.
+    type Error;
+
+    /// Sends `body`, a JSON-RPC request, to `url` with an HTTP `POST` and returns the response
+    /// body.
+    fn post(&mut self, url: &str, body: &str) -> Result<String, Self::Error>;
+}
+
+impl<T: RpcTransport + ?Sized> RpcTransport for &mut T {
+    type Error = T::Error;
+
+    #[inline]
+    fn post(&mut self, url: &str, body: &str) -> Result<String, Self::Error> {
+        (**self).post(url, body)
+    }
+}
+
+/// The capabilities of an RPC endpoint, as observed by [`NamedChain::verify_rpc`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct RpcReport {
+    /// The chain ID returned by `eth_chainId`.
+    pub chain_id: u64,
+    /// Whether a call executing `PUSH0` succeeds.
+    pub push0: bool,
+    /// Whether `eth_feeHistory` returns base fees, i.e. the chain has an
+    /// [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559) fee market.
+    pub eip1559: bool,
+}
+
+/// An error returned by [`NamedChain::verify_rpc`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VerifyRpcError<E> {
+    /// The transport failed to deliver a request.
+    Transport(E),
+    /// The endpoint returned a JSON-RPC error for `eth_chainId`.
+    Rpc {
+        /// The JSON-RPC error code.
+        code: i64,
+        /// The JSON-RPC error message.
+        message: String,
+    },
+    /// The endpoint returned a response that is not a valid JSON-RPC response.
+    InvalidResponse(String),
+    /// The endpoint serves a different chain.
+    ChainIdMismatch {
+        /// The ID of the chain being verified.
+        expected: u64,
+        /// The ID returned by the endpoint.
+        actual: u64,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for VerifyRpcError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transport(err) => write!(f, "transport error: {err}"),
+            Self::Rpc { code, message } => write!(f, "RPC error {code}: {message}"),
+            Self::InvalidResponse(response) => write!(f, "invalid JSON-RPC response: {response}"),
+            Self::ChainIdMismatch { expected, actual } => {
+                write!(f, "expected chain ID {expected}, but the endpoint serves {actual}")
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for VerifyRpcError<E> {}
+
+/// A JSON-RPC result, or the error's code and message.
+type Response = Result<Value, (i64, String)>;
+
+/// Calls `method` and returns its result, or the JSON-RPC error as `Ok(Err(_))`.
+fn call<T: RpcTransport>(
+    transport: &mut T,
+    url: &str,
+    method: &str,
+    params: Value,
+) -> Result<Response, VerifyRpcError<T::Error>> {
+    let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+    let response = transport.post(url, &body.to_string()).map_err(VerifyRpcError::Transport)?;
+    let invalid = || VerifyRpcError::InvalidResponse(response.clone());
+    let Ok(Value::Object(mut object)) = serde_json::from_str(&response) else {
+        return Err(invalid());
+    };
+    if let Some(result) = object.remove("result") {
+        return Ok(Ok(result));
+    }
+    let error = object.get("error").ok_or_else(invalid)?;
+    let code = error["code"].as_i64().unwrap_or_default();
+    let message = error["message"].as_str().unwrap_or_default().to_string();
+    Ok(Err((code, message)))
+}
+
+pub(crate) fn verify_rpc<T: RpcTransport>(
+    chain: NamedChain,
+    mut transport: T,
+    url: &str,
+) -> Result<RpcReport, VerifyRpcError<T::Error>> {
+    let chain_id = match call(&mut transport, url, "eth_chainId", json!([]))? {
+        Ok(Value::String(id)) => id
+            .strip_prefix("0x")
+            .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+            .ok_or(VerifyRpcError::InvalidResponse(id))?,
+        Ok(result) => return Err(VerifyRpcError::InvalidResponse(result.to_string())),
+        Err((code, message)) => return Err(VerifyRpcError::Rpc { code, message }),
+    };
+    if chain_id != chain as u64 {
+        return Err(VerifyRpcError::ChainIdMismatch { expected: chain as u64, actual: chain_id });
+    }
+
+    // Init code that executes `PUSH0` and stops, so the call only fails if the opcode is invalid.
+    let push0 =
+        call(&mut transport, url, "eth_call", json!([{ "data": "0x5f" }, "latest"]))?.is_ok();
+
+    let eip1559 = call(&mut transport, url, "eth_feeHistory", json!(["0x1", "latest", []]))?
+        .is_ok_and(|history| {
+            history["baseFeePerGas"].as_array().is_some_and(|fees| !fees.is_empty())
+        });
+
+    Ok(RpcReport { chain_id, push0, eip1559 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::Infallible;
+
+    struct Mock(fn(&str) -> Value);
+
+    impl RpcTransport for Mock {
+        type Error = Infallible;
+
+        fn post(&mut self, _url: &str, body: &str) -> Result<String, Infallible> {
+            let request: Value = serde_json::from_str(body).unwrap();
+            Ok(self.0(request["method"].as_str().unwrap()).to_string())
+        }
+    }
+
+    fn error() -> Value {
+        json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": -32000, "message": "invalid opcode" } })
+    }
+
+    fn result(result: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "id": 1, "result": result })
+    }
+
+    #[test]
+    fn reports_capabilities() {
+        let up_to_date = Mock(|method| match method {
+            "eth_chainId" => result(json!("0x1")),
+            "eth_call" => result(json!("0x")),
+            _ => result(json!({ "baseFeePerGas": ["0x1", "0x1"] })),
+        });
+        let report = NamedChain::Mainnet.verify_rpc(up_to_date, "").unwrap();
+        assert_eq!(report, RpcReport { chain_id: 1, push0: true, eip1559: true });
+
+        let legacy = Mock(|method| match method {
+            "eth_chainId" => result(json!("0x1")),
+            _ => error(),
+        });
+        let report = NamedChain::Mainnet.verify_rpc(legacy, "").unwrap();
+        assert_eq!(report, RpcReport { chain_id: 1, push0: false, eip1559: false });
+
+        let merged = NamedChain::Mainnet.capabilities().merge(report.into());
+        assert_eq!(merged.push0, Some(false));
+        assert_eq!(merged.eip1559, Some(false));
+        assert_eq!(merged.blobs, NamedChain::Mainnet.capabilities().blobs);
+    }
+
+    #[test]
+    fn rejects_other_chains() {
+        let base = Mock(|_| result(json!("0x2105")));
+        assert_eq!(
+            NamedChain::Mainnet.verify_rpc(base, ""),
+            Err(VerifyRpcError::ChainIdMismatch { expected: 1, actual: 8453 })
+        );
+
+        let broken = Mock(|_| json!("not a response"));
+        assert!(matches!(
+            NamedChain::Mainnet.verify_rpc(broken, ""),
+            Err(VerifyRpcError::InvalidResponse(_))
+        ));
+    }
+}
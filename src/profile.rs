@@ -0,0 +1,88 @@
+//! Aggregated chain metadata.
+
+use crate::{
+    AaSupport, ChainCapabilities, ChainMetadata, Date, GasSemantics, MempoolProfile, MevEndpoint,
+    NamedChain, RollupType, WrappedNativeToken,
+};
+use core::time::Duration;
+
+/// All of a named chain's metadata, fetched in a single call so that it can be cached at once.
+///
+/// Returned by [`NamedChain::profile`]. Each field mirrors the [`NamedChain`] method of the same
+/// name, apart from [`metadata`](Self::metadata), which holds the basic metadata returned by
+/// [`NamedChain::metadata`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ChainProfile {
+    /// The chain's basic metadata: name, ID, native currency, block time and explorers.
+    pub metadata: ChainMetadata,
+    /// Whether the chain is deprecated.
+    pub is_deprecated: bool,
+    /// Whether the chain only supports legacy transactions.
+    pub is_legacy: bool,
+    /// Whether the chain supports the Shanghai hardfork.
+    pub supports_shanghai: bool,
+    /// The chain's rollup type, if it is a rollup.
+    pub rollup_type: Option<RollupType>,
+    /// The chain the rollup settles on, if it is a rollup.
+    pub settlement_chain: Option<NamedChain>,
+    /// How the chain accounts for gas.
+    pub gas_semantics: GasSemantics,
+    /// How the chain's mempool handles pending transactions, if known.
+    pub mempool_profile: Option<MempoolProfile>,
+    /// The chain's known capabilities.
+    pub capabilities: ChainCapabilities,
+    /// The chain's account abstraction mechanisms.
+    pub aa_support: AaSupport,
+    /// A hint for the chain's time to finality, if known.
+    pub time_to_finality_hint: Option<Duration>,
+    /// A hint for the chain's block gas limit, if known.
+    pub block_gas_limit_hint: Option<u64>,
+    /// The chain's wrapped native token, if known.
+    pub wrapped_native_token_info: Option<WrappedNativeToken>,
+    /// The chain's beacon API URL, if any.
+    pub beacon_api_url: Option<&'static str>,
+    /// The URL of a token list covering the chain, if any.
+    pub token_list_url: Option<&'static str>,
+    /// The chain's sequencer RPC URL, if any.
+    pub sequencer_rpc_url: Option<&'static str>,
+    /// The chain's MEV-protection and bundle endpoints.
+    pub mev_endpoints: &'static [MevEndpoint],
+    /// The date the testnet was, or will be, shut down, if announced.
+    pub sunset_date: Option<Date>,
+    /// The date the chain's metadata was last reviewed, if tracked.
+    pub metadata_reviewed_at: Option<Date>,
+}
+
+impl ChainProfile {
+    /// Returns the profile of the given chain.
+    pub(crate) const fn new(chain: NamedChain) -> Self {
+        Self {
+            metadata: chain.metadata(),
+            is_deprecated: chain.is_deprecated(),
+            is_legacy: chain.is_legacy(),
+            supports_shanghai: chain.supports_shanghai(),
+            rollup_type: chain.rollup_type(),
+            settlement_chain: chain.settlement_chain(),
+            gas_semantics: chain.gas_semantics(),
+            mempool_profile: chain.mempool_profile(),
+            capabilities: chain.capabilities(),
+            aa_support: chain.aa_support(),
+            time_to_finality_hint: chain.time_to_finality_hint(),
+            block_gas_limit_hint: chain.block_gas_limit_hint(),
+            wrapped_native_token_info: chain.wrapped_native_token_info(),
+            beacon_api_url: chain.beacon_api_url(),
+            token_list_url: chain.token_list_url(),
+            sequencer_rpc_url: chain.sequencer_rpc_url(),
+            mev_endpoints: chain.mev_endpoints(),
+            sunset_date: chain.sunset_date(),
+            metadata_reviewed_at: chain.metadata_reviewed_at(),
+        }
+    }
+
+    /// Returns the chain.
+    #[inline]
+    pub const fn chain(&self) -> NamedChain {
+        self.metadata.chain
+    }
+}
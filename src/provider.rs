@@ -0,0 +1,65 @@
+//! RPC provider endpoint templates.
+
+use alloc::string::String;
+use core::fmt;
+
+/// A hosted RPC provider.
+///
+/// See [`NamedChain::rpc_url_template`](crate::NamedChain::rpc_url_template).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RpcProvider {
+    /// [Alchemy](https://www.alchemy.com).
+    Alchemy,
+    /// [Infura](https://www.infura.io).
+    Infura,
+    /// [QuickNode](https://www.quicknode.com).
+    ///
+    /// QuickNode URLs embed a per-endpoint subdomain, so no templates are provided.
+    QuickNode,
+    /// [Ankr](https://www.ankr.com).
+    Ankr,
+    /// [dRPC](https://drpc.org).
+    Drpc,
+}
+
+/// An RPC endpoint URL with an [`API_KEY`](Self::API_KEY) placeholder for the caller's API key.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::{NamedChain, RpcProvider};
+///
+/// let template = NamedChain::Base.rpc_url_template(RpcProvider::Alchemy).unwrap();
+/// assert_eq!(template.as_str(), "https://base-mainnet.g.alchemy.com/v2/{API_KEY}");
+/// assert_eq!(template.fill("secret"), "https://base-mainnet.g.alchemy.com/v2/secret");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RpcUrlTemplate(&'static str);
+
+impl RpcUrlTemplate {
+    /// The placeholder replaced by [`fill`](Self::fill).
+    pub const API_KEY: &'static str = "{API_KEY}";
+
+    pub(crate) const fn new(template: &'static str) -> Self {
+        Self(template)
+    }
+
+    /// Returns the template, including the placeholder.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        self.0
+    }
+
+    /// Returns the URL with the placeholder replaced by `api_key`.
+    pub fn fill(self, api_key: &str) -> String {
+        self.0.replace(Self::API_KEY, api_key)
+    }
+}
+
+impl fmt::Display for RpcUrlTemplate {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
@@ -0,0 +1,67 @@
+//! Rollup security models.
+
+/// How a rollup proves the validity of its state to its parent chain.
+///
+/// Returned by [`NamedChain::rollup_type`](crate::NamedChain::rollup_type).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RollupType {
+    /// State is assumed valid unless challenged with a fraud proof during a challenge period
+    /// (e.g. OP-stack and Arbitrum chains).
+    Optimistic,
+    /// Every state transition is accompanied by a validity (zero-knowledge) proof verified on
+    /// the parent chain (e.g. ZKsync Era, Linea and Scroll).
+    Validity,
+}
+
+impl RollupType {
+    /// Returns `true` if this is an optimistic rollup.
+    #[inline]
+    pub const fn is_optimistic(self) -> bool {
+        matches!(self, Self::Optimistic)
+    }
+
+    /// Returns `true` if this is a validity-proof (zk) rollup.
+    #[inline]
+    pub const fn is_zk(self) -> bool {
+        matches!(self, Self::Validity)
+    }
+}
+
+/// The proof system a validity rollup uses to prove its state transitions.
+///
+/// Returned by [`NamedChain::proof_system`](crate::NamedChain::proof_system).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProofSystem {
+    /// [Boojum](https://github.com/matter-labs/zksync-crypto), ZKsync's STARK-based prover whose
+    /// proofs are wrapped in a SNARK for verification on L1.
+    Boojum,
+    /// A PLONK SNARK, verified directly on L1.
+    Plonk,
+    /// A STARK, wrapped in a SNARK for verification on L1.
+    Stark,
+}
+
+/// The standardization status of an OP-stack chain in the
+/// [superchain registry](https://github.com/ethereum-optimism/superchain-registry).
+///
+/// Returned by [`NamedChain::superchain_level`](crate::NamedChain::superchain_level).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum SuperchainLevel {
+    /// Listed in the registry, but not verified against the standard configuration, e.g. it may
+    /// use custom contracts or a non-standard upgrade key.
+    Frontier,
+    /// Conforms to the standard rollup configuration, contracts and governance of the Superchain,
+    /// and receives protocol upgrades through Optimism governance.
+    Standard,
+}
+
+impl SuperchainLevel {
+    /// Returns `true` if this is a standard chain.
+    #[inline]
+    pub const fn is_standard(self) -> bool {
+        matches!(self, Self::Standard)
+    }
+}
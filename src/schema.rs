@@ -0,0 +1,39 @@
+//! The canonical chain dataset and its JSON schema, for consumers in other languages.
+//!
+//! The dataset is the JSON serialization of the builtin [`ChainRegistry`], ordered by chain ID.
+//! Both files are embedded from `assets/`, where they are kept up to date by this crate's tests,
+//! so they are identical for a given version regardless of enabled features.
+//!
+//! [`ChainRegistry`]: crate::spec::ChainRegistry
+
+/// The JSON serialization of the builtin chains.
+const DATASET: &str = include_str!("../assets/chains.json");
+
+/// The JSON schema of [`DATASET`].
+const SCHEMA: &str = include_str!("../assets/chains.schema.json");
+
+/// Returns the JSON serialization of the builtin chains.
+///
+/// # Examples
+///
+/// ```
+/// let dataset = alloy_chains::schema::dataset();
+/// assert!(dataset.contains(r#""name": "mainnet""#));
+/// ```
+#[inline]
+pub const fn dataset() -> &'static str {
+    DATASET
+}
+
+/// Returns the JSON schema that [`dataset`] conforms to.
+///
+/// # Examples
+///
+/// ```
+/// let schema = alloy_chains::schema::json_schema();
+/// assert!(schema.contains(r#""title": "Chains""#));
+/// ```
+#[inline]
+pub const fn json_schema() -> &'static str {
+    SCHEMA
+}
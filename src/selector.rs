@@ -0,0 +1,295 @@
+//! Declarative chain selection expressions.
+
+use crate::{Chain, ChainGroup, NamedChain};
+use alloc::{boxed::Box, string::String};
+use core::{fmt, str::FromStr};
+
+/// A boolean expression over chain metadata, e.g. `mainnets & op-stack`, for expressing chain
+/// scopes in configuration files.
+///
+/// Terms are combined with `!` (not), `&` (and) and `|` (or), in decreasing order of precedence,
+/// and grouped with parentheses. The terms are:
+/// - `mainnets` and `testnets` (or `testnet`): see [`NamedChain::is_testnet`];
+/// - `deprecated`: see [`NamedChain::is_deprecated`];
+/// - `rollups`: see [`NamedChain::rollup_type`];
+/// - `group:<group>`: see [`NamedChain::parent_group`], where `<group>` is one of `ethereum`,
+///   `op-stack`, `arbitrum`, `polygon`, `bnb` or `zksync`. `op-stack` is short for
+///   `group:op-stack`;
+/// - `chain:<name>`: a chain name or alias;
+/// - `id:<id>`: a decimal chain ID, which may be unnamed.
+///
+/// Unnamed chains only match `id:` terms.
+///
+/// With the `serde` feature, selectors are (de)serialized as strings.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::{ChainSelector, NamedChain};
+///
+/// let selector: ChainSelector = "mainnets & op-stack".parse().unwrap();
+/// assert!(selector.matches(NamedChain::Base));
+/// assert!(!selector.matches(NamedChain::BaseSepolia));
+/// assert!(!selector.matches(NamedChain::Arbitrum));
+///
+/// let selector: ChainSelector = "testnet | id:98765".parse().unwrap();
+/// assert!(selector.matches(NamedChain::Sepolia));
+/// assert!(selector.matches(98765));
+///
+/// let selector: ChainSelector = "!deprecated & (chain:goerli | chain:sepolia)".parse().unwrap();
+/// assert_eq!(selector.select().collect::<Vec<_>>(), [NamedChain::Sepolia]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainSelector {
+    source: String,
+    expr: Expr,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Expr {
+    Mainnets,
+    Testnets,
+    Deprecated,
+    Rollups,
+    Group(ChainGroup),
+    Chain(NamedChain),
+    Id(u64),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl ChainSelector {
+    /// Returns `true` if `chain` is selected.
+    pub fn matches(&self, chain: impl Into<Chain>) -> bool {
+        self.expr.matches(chain.into())
+    }
+
+    /// Returns an iterator over the selected named chains, in enum order.
+    pub fn select(&self) -> impl Iterator<Item = NamedChain> + '_ {
+        use strum::IntoEnumIterator;
+
+        NamedChain::iter().filter(|&chain| self.matches(chain))
+    }
+
+    /// Returns the expression the selector was parsed from.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+}
+
+impl Expr {
+    fn matches(&self, chain: Chain) -> bool {
+        let named = |f: fn(NamedChain) -> bool| chain.named().is_some_and(f);
+        match self {
+            Self::Mainnets => named(|chain| !chain.is_testnet()),
+            Self::Testnets => named(NamedChain::is_testnet),
+            Self::Deprecated => named(NamedChain::is_deprecated),
+            Self::Rollups => named(|chain| chain.rollup_type().is_some()),
+            Self::Group(group) => chain.named().is_some_and(|c| c.parent_group() == Some(*group)),
+            Self::Chain(other) => chain.named() == Some(*other),
+            Self::Id(id) => chain.id() == *id,
+            Self::Not(expr) => !expr.matches(chain),
+            Self::And(a, b) => a.matches(chain) && b.matches(chain),
+            Self::Or(a, b) => a.matches(chain) || b.matches(chain),
+        }
+    }
+}
+
+impl fmt::Display for ChainSelector {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl FromStr for ChainSelector {
+    type Err = SelectorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { input: s, pos: 0 };
+        let expr = parser.or()?;
+        parser.skip_whitespace();
+        if parser.pos < s.len() {
+            return Err(SelectorParseError::Unexpected { position: parser.pos });
+        }
+        Ok(Self { source: s.into(), expr })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChainSelector {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChainSelector {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <alloc::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The error returned when parsing a [`ChainSelector`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SelectorParseError {
+    /// The expression ended unexpectedly, e.g. after an operator.
+    UnexpectedEnd,
+    /// An unexpected character was found at the given byte position.
+    Unexpected {
+        /// The byte position of the character.
+        position: usize,
+    },
+    /// A term is not a known keyword, or names an unknown chain or group.
+    UnknownTerm(String),
+}
+
+impl fmt::Display for SelectorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of chain selector"),
+            Self::Unexpected { position } => {
+                write!(f, "unexpected character at position {position} in chain selector")
+            }
+            Self::UnknownTerm(term) => write!(f, "unknown chain selector term `{term}`"),
+        }
+    }
+}
+
+impl core::error::Error for SelectorParseError {}
+
+/// A recursive descent parser for [`ChainSelector`] expressions.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `op` if it is the next non-whitespace character.
+    fn eat(&mut self, op: char) -> bool {
+        self.skip_whitespace();
+        let eaten = self.input[self.pos..].starts_with(op);
+        if eaten {
+            self.pos += op.len_utf8();
+        }
+        eaten
+    }
+
+    fn or(&mut self) -> Result<Expr, SelectorParseError> {
+        let mut expr = self.and()?;
+        while self.eat('|') {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, SelectorParseError> {
+        let mut expr = self.not()?;
+        while self.eat('&') {
+            expr = Expr::And(Box::new(expr), Box::new(self.not()?));
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> Result<Expr, SelectorParseError> {
+        if self.eat('!') {
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        if self.eat('(') {
+            let expr = self.or()?;
+            if !self.eat(')') {
+                return Err(self.unexpected());
+            }
+            return Ok(expr);
+        }
+        self.term()
+    }
+
+    fn term(&mut self) -> Result<Expr, SelectorParseError> {
+        self.skip_whitespace();
+        let rest = &self.input[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.')))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.unexpected());
+        }
+        let term = &rest[..len];
+        self.pos += len;
+
+        let unknown = || SelectorParseError::UnknownTerm(term.into());
+        Ok(match term {
+            "mainnets" => Expr::Mainnets,
+            "testnets" | "testnet" => Expr::Testnets,
+            "deprecated" => Expr::Deprecated,
+            "rollups" => Expr::Rollups,
+            "op-stack" => Expr::Group(ChainGroup::OpStack),
+            _ => match term.split_once(':') {
+                Some(("id", id)) => Expr::Id(id.parse().map_err(|_| unknown())?),
+                Some(("chain", name)) => Expr::Chain(name.parse().map_err(|_| unknown())?),
+                Some(("group", group)) => Expr::Group(match group {
+                    "ethereum" => ChainGroup::Ethereum,
+                    "op-stack" => ChainGroup::OpStack,
+                    "arbitrum" => ChainGroup::Arbitrum,
+                    "polygon" => ChainGroup::Polygon,
+                    "bnb" => ChainGroup::Bnb,
+                    "zksync" => ChainGroup::ZkSync,
+                    _ => return Err(unknown()),
+                }),
+                _ => return Err(unknown()),
+            },
+        })
+    }
+
+    const fn unexpected(&self) -> SelectorParseError {
+        if self.pos < self.input.len() {
+            SelectorParseError::Unexpected { position: self.pos }
+        } else {
+            SelectorParseError::UnexpectedEnd
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precedence() {
+        let selector: ChainSelector = "chain:base | chain:optimism & testnets".parse().unwrap();
+        assert!(selector.matches(NamedChain::Base));
+        assert!(!selector.matches(NamedChain::Optimism));
+
+        let selector: ChainSelector = "!!group:arbitrum&mainnets".parse().unwrap();
+        assert!(selector.matches(NamedChain::Arbitrum));
+        assert!(!selector.matches(NamedChain::ArbitrumSepolia));
+        assert!(!selector.matches(1234567));
+    }
+
+    #[test]
+    fn parse_errors() {
+        use SelectorParseError::*;
+
+        for (input, err) in [
+            ("", UnexpectedEnd),
+            ("mainnets &", UnexpectedEnd),
+            ("(mainnets", UnexpectedEnd),
+            ("mainnets testnets", Unexpected { position: 9 }),
+            ("mainnets)", Unexpected { position: 8 }),
+            ("l2s", UnknownTerm("l2s".into())),
+            ("chain:unknown", UnknownTerm("chain:unknown".into())),
+            ("id:0x1", UnknownTerm("id:0x1".into())),
+        ] {
+            assert_eq!(input.parse::<ChainSelector>(), Err(err), "{input}");
+        }
+    }
+}
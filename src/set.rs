@@ -0,0 +1,212 @@
+//! A set of chains.
+
+use crate::{Chain, ChainKind, NamedChain};
+use alloy_primitives::map::HashSet;
+use strum::EnumCount;
+
+/// The number of words of the [`NamedChain`] bitset.
+const WORDS: usize = NamedChain::COUNT.div_ceil(64);
+
+/// A set of chains, e.g. the chains allowed or denied by a configuration.
+///
+/// Named chains are stored in a bitset and other chain IDs in a hash set, so membership checks
+/// are `O(1)`.
+///
+/// With the `serde` feature, the set is (de)serialized as a sequence of [`Chain`]s, ordered by
+/// chain ID.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::{Chain, ChainSet, NamedChain};
+///
+/// let allowed: ChainSet = [Chain::mainnet(), Chain::base_mainnet(), Chain::from_id(1234567)]
+///     .into_iter()
+///     .collect();
+/// assert!(allowed.contains(NamedChain::Base));
+/// assert!(allowed.contains(1234567));
+/// assert!(!allowed.contains(NamedChain::Optimism));
+/// assert_eq!(allowed.len(), 3);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChainSet {
+    /// The named chains, indexed by their position in [`NamedChain::all_ids`].
+    named: [u64; WORDS],
+    /// The chain IDs that are not [`NamedChain`]s.
+    ids: HashSet<u64>,
+}
+
+impl ChainSet {
+    /// Creates an empty set.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of chains in the set.
+    pub fn len(&self) -> usize {
+        self.named.iter().map(|word| word.count_ones() as usize).sum::<usize>() + self.ids.len()
+    }
+
+    /// Returns `true` if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.named.iter().all(|&word| word == 0) && self.ids.is_empty()
+    }
+
+    /// Returns `true` if the set contains `chain`.
+    pub fn contains(&self, chain: impl Into<Chain>) -> bool {
+        match chain.into().into_kind() {
+            ChainKind::Named(named) => {
+                let (word, bit) = position(named);
+                self.named[word] & bit != 0
+            }
+            ChainKind::Id(id) => self.ids.contains(&id),
+        }
+    }
+
+    /// Adds `chain` to the set. Returns `true` if it was not already present.
+    pub fn insert(&mut self, chain: impl Into<Chain>) -> bool {
+        match chain.into().into_kind() {
+            ChainKind::Named(named) => {
+                let (word, bit) = position(named);
+                let inserted = self.named[word] & bit == 0;
+                self.named[word] |= bit;
+                inserted
+            }
+            ChainKind::Id(id) => self.ids.insert(id),
+        }
+    }
+
+    /// Removes `chain` from the set. Returns `true` if it was present.
+    pub fn remove(&mut self, chain: impl Into<Chain>) -> bool {
+        match chain.into().into_kind() {
+            ChainKind::Named(named) => {
+                let (word, bit) = position(named);
+                let removed = self.named[word] & bit != 0;
+                self.named[word] &= !bit;
+                removed
+            }
+            ChainKind::Id(id) => self.ids.remove(&id),
+        }
+    }
+
+    /// Returns the chains in either set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{ChainSet, NamedChain};
+    ///
+    /// let a = ChainSet::from_iter([NamedChain::Mainnet, NamedChain::Base]);
+    /// let b = ChainSet::from_iter([NamedChain::Base, NamedChain::Optimism]);
+    /// assert_eq!(a.union(&b).len(), 3);
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        let mut named = self.named;
+        for (word, other) in named.iter_mut().zip(other.named) {
+            *word |= other;
+        }
+        Self { named, ids: self.ids.union(&other.ids).copied().collect() }
+    }
+
+    /// Returns the chains in both sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{ChainSet, NamedChain};
+    ///
+    /// let a = ChainSet::from_iter([NamedChain::Mainnet, NamedChain::Base]);
+    /// let b = ChainSet::from_iter([NamedChain::Base, NamedChain::Optimism]);
+    /// assert_eq!(a.intersection(&b), ChainSet::from_iter([NamedChain::Base]));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut named = self.named;
+        for (word, other) in named.iter_mut().zip(other.named) {
+            *word &= other;
+        }
+        Self { named, ids: self.ids.intersection(&other.ids).copied().collect() }
+    }
+
+    /// Returns an iterator over the chains in the set: the named chains ordered by chain ID,
+    /// followed by the other chains in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = Chain> + '_ {
+        let ids = NamedChain::all_ids();
+        let named = (0..ids.len())
+            .filter(|&i| self.named[i / 64] & (1 << (i % 64)) != 0)
+            .map(move |i| Chain::from_id_unobserved(ids[i]));
+        named.chain(self.ids.iter().map(|&id| Chain::from_id_unobserved(id)))
+    }
+}
+
+/// Returns the word and bit of `chain` in the bitset.
+fn position(chain: NamedChain) -> (usize, u64) {
+    let index = NamedChain::all_ids()
+        .binary_search(&(chain as u64))
+        .expect("all named chains are in `NamedChain::all_ids`");
+    (index / 64, 1 << (index % 64))
+}
+
+impl<C: Into<Chain>> FromIterator<C> for ChainSet {
+    fn from_iter<I: IntoIterator<Item = C>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<C: Into<Chain>> Extend<C> for ChainSet {
+    fn extend<I: IntoIterator<Item = C>>(&mut self, iter: I) {
+        for chain in iter {
+            self.insert(chain);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChainSet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut chains: alloc::vec::Vec<_> = self.iter().collect();
+        chains.sort_unstable_by_key(|chain| chain.id());
+        serializer.collect_seq(chains)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChainSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        alloc::vec::Vec::<Chain>::deserialize(deserializer).map(Self::from_iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn every_named_chain() {
+        let mut set = ChainSet::new();
+        for chain in NamedChain::iter() {
+            assert!(set.insert(chain), "{chain}");
+            assert!(!set.insert(chain), "{chain}");
+            assert!(set.contains(chain), "{chain}");
+        }
+        assert_eq!(set.len(), NamedChain::COUNT);
+        assert!(set.iter().map(|chain| chain.id()).eq(NamedChain::all_ids().iter().copied()));
+        for chain in NamedChain::iter() {
+            assert!(set.remove(chain), "{chain}");
+        }
+        assert!(set.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let set =
+            ChainSet::from_iter([Chain::from_id(1234567), Chain::base_mainnet(), Chain::mainnet()]);
+        let json = r#"["mainnet","base",1234567]"#;
+        assert_eq!(serde_json::to_string(&set).unwrap(), json);
+        assert_eq!(serde_json::from_str::<ChainSet>(json).unwrap(), set);
+    }
+}
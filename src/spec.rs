@@ -7,16 +7,143 @@ use strum::IntoEnumIterator;
 use alloc::{
     collections::BTreeMap,
     string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
 };
+use alloy_primitives::map::HashMap;
+use core::fmt;
 
+/// A registry of chains, seeded with the builtin [`NamedChain`]s and extensible at runtime.
+///
+/// See [`Chains`] for more info.
+pub type ChainRegistry = Chains;
+
+/// A storage backend for persisting the custom chains of a [`ChainRegistry`].
+///
+/// Implementations only move [`Chains`] values in and out of storage; with the `serde` feature,
+/// these serialize to the same JSON format as `assets/chains.json`.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::spec::{Chain, ChainRegistry, Chains, RegistryStore};
+///
+/// #[derive(Default)]
+/// struct MemoryStore(Option<Chains>);
+///
+/// impl RegistryStore for MemoryStore {
+///     type Error = core::convert::Infallible;
+///
+///     fn load(&mut self) -> Result<Option<Chains>, Self::Error> {
+///         Ok(self.0.clone())
+///     }
+///
+///     fn save(&mut self, chains: &Chains) -> Result<(), Self::Error> {
+///         self.0 = Some(chains.clone());
+///         Ok(())
+///     }
+/// }
+///
+/// let mut store = MemoryStore::default();
+///
+/// let mut registry = ChainRegistry::new();
+/// let mut devnet = Chain::custom("devnet");
+/// devnet.rpc_urls.push("http://localhost:8545".into());
+/// registry.register(31338, devnet);
+/// registry.save(&mut store).unwrap();
+///
+/// let registry = ChainRegistry::load(&mut store).unwrap();
+/// assert_eq!(registry.get(31338).unwrap().name, "devnet");
+/// assert_eq!(registry.get(1).unwrap().name, "mainnet");
+/// ```
+pub trait RegistryStore {
+    /// The error returned by the storage backend.
+    type Error;
+
+    /// Loads the previously saved chains, if any.
+    fn load(&mut self) -> Result<Option<Chains>, Self::Error>;
+
+    /// Saves the given chains, replacing any previously saved chains.
+    fn save(&mut self, chains: &Chains) -> Result<(), Self::Error>;
+}
+
+/// Ethereum EIP-155 chains.
+///
+/// Chains are indexed both by [key](ChainKey) and by name, so lookups through either are
+/// constant-time. Names are matched case-insensitively, with `_` and `-` treated alike, against
+/// both the chain's [`name`](Chain::name) and its [`internal_id`](Chain::internal_id).
+///
+/// Besides EIP-155 chains, which are keyed by their chain ID, the registry can hold chains of
+/// other ecosystems keyed by their [CAIP-2] ID. Methods taking a `u64` ID are shorthands for the
+/// `eip155` namespace.
+///
+/// [CAIP-2]: https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-2.md
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "ChainsRepr", into = "ChainsRepr"))]
+pub struct Chains {
+    chains: HashMap<ChainKey, Chain>,
+    /// The keys of the chains with each normalized name or internal ID, in registration order.
+    names: HashMap<String, Vec<ChainKey>>,
+    /// The builtin chains explicitly unregistered, which [`Chains::get_key`] must not fall back to.
+    #[cfg(feature = "std")]
+    unregistered: alloy_primitives::map::HashSet<u64>,
+    #[cfg(feature = "std")]
+    custom_ids: CustomIds,
+    subscribers: Subscribers,
+}
+
+// The serialized form of `Chains`, ordered by chain ID. The doc comment is the schema's
+// description.
 /// Ethereum EIP-155 chains.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "Chains"))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
-pub struct Chains {
+struct ChainsRepr {
     /// Map of chain IDs to chain definitions.
-    pub chains: BTreeMap<u64, Chain>,
+    chains: BTreeMap<u64, Chain>,
+    /// Map of CAIP-2 IDs to chain definitions, for chains outside of the `eip155` namespace.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "BTreeMap::is_empty"))]
+    namespaced_chains: BTreeMap<String, Chain>,
+}
+
+impl TryFrom<ChainsRepr> for Chains {
+    type Error = ParseChainKeyError;
+
+    fn try_from(repr: ChainsRepr) -> Result<Self, Self::Error> {
+        let mut chains = Self::empty();
+        chains.extend(repr.chains.into_iter().map(|(id, chain)| (ChainKey::Eip155(id), chain)));
+        for (key, chain) in repr.namespaced_chains {
+            chains.insert(key.parse()?, chain);
+        }
+        Ok(chains)
+    }
+}
+
+impl From<Chains> for ChainsRepr {
+    fn from(chains: Chains) -> Self {
+        let mut repr = Self { chains: BTreeMap::new(), namespaced_chains: BTreeMap::new() };
+        for (key, chain) in chains.chains {
+            match key {
+                ChainKey::Eip155(id) => repr.chains.insert(id, chain),
+                key => repr.namespaced_chains.insert(key.to_string(), chain),
+            };
+        }
+        repr
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Chains {
+    fn schema_name() -> String {
+        ChainsRepr::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        ChainsRepr::json_schema(gen)
+    }
 }
 
 impl Default for Chains {
@@ -30,17 +157,918 @@ impl Chains {
     /// Constructs an empty set of chains.
     #[inline]
     pub fn empty() -> Self {
-        Self { chains: Default::default() }
+        Self {
+            chains: HashMap::default(),
+            names: HashMap::default(),
+            #[cfg(feature = "std")]
+            unregistered: Default::default(),
+            #[cfg(feature = "std")]
+            custom_ids: CustomIds::default(),
+            subscribers: Subscribers::default(),
+        }
     }
 
     /// Returns the default chains.
     pub fn new() -> Self {
-        Self { chains: NamedChain::iter().map(|c| (c as u64, Chain::new(c))).collect() }
+        let mut chains = Self::empty();
+        chains.extend(NamedChain::iter().map(|c| (ChainKey::Eip155(c as u64), Chain::new(c))));
+        chains
+    }
+
+    /// Returns the EIP-155 chain with the given ID.
+    ///
+    /// See [`get_key`](Self::get_key), including how builtin chains that are not registered are
+    /// returned.
+    #[inline]
+    pub fn get(&self, id: u64) -> Option<&Chain> {
+        self.get_key(&ChainKey::Eip155(id))
+    }
+
+    /// Returns the chain with the given key.
+    ///
+    /// With the `std` feature, the specification of a [`NamedChain`] that is not registered, e.g.
+    /// because the registry was [loaded](Self::load) from an older dataset, is built from its
+    /// builtin metadata on first use and cached. Chains removed with
+    /// [`unregister_key`](Self::unregister_key) are not returned. [`get_by_name`](Self::get_by_name),
+    /// [`resolve`](Self::resolve) and [`contains_key`](Self::contains_key) fall back the same way,
+    /// while [`len`](Self::len) and [`iter`](Self::iter) only cover the registered chains.
+    ///
+    /// Without the `std` feature, only registered chains are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::spec::{Chain, ChainKey, ChainRegistry};
+    ///
+    /// let mut registry = ChainRegistry::new();
+    /// let solana: ChainKey = "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp".parse().unwrap();
+    /// registry.register_key(solana.clone(), Chain::custom("solana"));
+    ///
+    /// assert_eq!(registry.get_key(&solana).unwrap().name, "solana");
+    /// assert_eq!(registry.get_key(&"eip155:1".parse().unwrap()).unwrap().name, "mainnet");
+    ///
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// let mut registry = ChainRegistry::empty();
+    /// assert_eq!(registry.get(8453).unwrap().name, "base");
+    /// assert_eq!(registry.resolve("base").unwrap().0, &ChainKey::Eip155(8453));
+    /// assert!(registry.contains(8453) && registry.is_empty());
+    /// registry.unregister(8453);
+    /// assert_eq!(registry.get(8453), None);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn get_key(&self, key: &ChainKey) -> Option<&Chain> {
+        self.get_key_value(key).map(|(_, chain)| chain)
+    }
+
+    /// Returns the registered chain with the given key, or the builtin one as described in
+    /// [`get_key`](Self::get_key).
+    fn get_key_value(&self, key: &ChainKey) -> Option<(&ChainKey, &Chain)> {
+        self.chains.get_key_value(key).or_else(|| self.builtin(key))
+    }
+
+    /// Returns the builtin chain with the given key, unless a chain is registered under the key
+    /// or it was unregistered.
+    #[cfg(feature = "std")]
+    fn builtin(&self, key: &ChainKey) -> Option<(&'static ChainKey, &'static Chain)> {
+        match *key {
+            ChainKey::Eip155(id)
+                if !self.unregistered.contains(&id) && !self.chains.contains_key(key) =>
+            {
+                builtin(id)
+            }
+            _ => None,
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    const fn builtin(&self, _key: &ChainKey) -> Option<(&'static ChainKey, &'static Chain)> {
+        None
+    }
+
+    /// Returns the key and the chain with the given name.
+    ///
+    /// If several chains share the name, the most recently registered one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::spec::ChainRegistry;
+    ///
+    /// let registry = ChainRegistry::new();
+    /// assert_eq!(registry.get_by_name("Base").unwrap().0.eip155_id(), Some(8453));
+    /// assert_eq!(registry.get_by_name("arbitrum_sepolia").unwrap().0.eip155_id(), Some(421614));
+    /// assert!(registry.get_by_name("unknown").is_none());
+    /// ```
+    pub fn get_by_name(&self, name: &str) -> Option<(&ChainKey, &Chain)> {
+        let name = normalize_name(name);
+        if let Some(key) = self.names.get(&name).and_then(|keys| keys.last()) {
+            return Some((key, &self.chains[key]));
+        }
+        self.builtin_by_name(&name)
+    }
+
+    /// Returns the builtin chain with the given normalized name, as described in
+    /// [`get_key`](Self::get_key).
+    #[cfg(feature = "std")]
+    fn builtin_by_name(&self, name: &str) -> Option<(&ChainKey, &Chain)> {
+        self.builtin(&ChainKey::from(builtin_by_name(name)?))
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    const fn builtin_by_name(&self, _name: &str) -> Option<(&ChainKey, &Chain)> {
+        None
+    }
+
+    /// Resolves user input, e.g. a CLI argument, to a registered chain.
+    ///
+    /// The input is tried, in order, as:
+    /// - a chain name or internal ID, as in [`get_by_name`](Self::get_by_name);
+    /// - a [`NamedChain`] alias (e.g. `bsc`);
+    /// - a decimal chain ID (e.g. `8453`);
+    /// - a `0x`-prefixed hexadecimal chain ID (e.g. `0x2105`);
+    /// - a [`ChainKey`] (e.g. `eip155:8453`).
+    ///
+    /// Chain IDs that are neither registered nor a [`NamedChain`] are reported to the
+    /// [unknown chain observer](crate::Chain::set_unknown_chain_observer).
+    ///
+    /// See also [`resolve_with_env`](Self::resolve_with_env).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::spec::{ChainKey, ChainRegistry, ResolveError};
+    ///
+    /// let registry = ChainRegistry::new();
+    /// for input in ["base", "Base", "8453", "0x2105", "eip155:8453"] {
+    ///     assert_eq!(registry.resolve(input).unwrap().1.name, "base");
+    /// }
+    /// assert_eq!(registry.resolve("bsc").unwrap().0, &ChainKey::Eip155(56));
+    ///
+    /// assert_eq!(registry.resolve("12345678"), Err(ResolveError::Unregistered(ChainKey::Eip155(12345678))));
+    /// assert_eq!(registry.resolve("nope"), Err(ResolveError::Unknown("nope".into())));
+    /// ```
+    pub fn resolve(&self, input: &str) -> Result<(&ChainKey, &Chain), ResolveError> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(ResolveError::Missing);
+        }
+        if let Some(found) = self.get_by_name(input) {
+            return Ok(found);
+        }
+
+        let key = if let Ok(chain) = input.parse::<NamedChain>() {
+            ChainKey::from(chain)
+        } else if let Ok(id) = input.parse::<u64>() {
+            ChainKey::Eip155(id)
+        } else if let Some(id) = crate::chain::parse_hex_id(input) {
+            ChainKey::Eip155(id)
+        } else if let Ok(key) = input.parse::<ChainKey>() {
+            key
+        } else {
+            return Err(ResolveError::Unknown(input.to_string()));
+        };
+        let Some(found) = self.get_key_value(&key) else {
+            #[cfg(feature = "std")]
+            if let ChainKey::Eip155(id) = key {
+                if NamedChain::try_from(id).is_err() {
+                    crate::chain::unknown_chain_observer::notify(id);
+                }
+            }
+            return Err(ResolveError::Unregistered(key));
+        };
+        Ok(found)
+    }
+
+    /// Resolves user input like [`resolve`](Self::resolve), falling back to the
+    /// [`RESOLVE_ENV_VAR`] environment variable if the input is `None` or empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::spec::{ChainRegistry, RESOLVE_ENV_VAR};
+    ///
+    /// let registry = ChainRegistry::new();
+    /// std::env::set_var(RESOLVE_ENV_VAR, "optimism");
+    /// assert_eq!(registry.resolve_with_env(None).unwrap().1.name, "optimism");
+    /// assert_eq!(registry.resolve_with_env(Some("base")).unwrap().1.name, "base");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn resolve_with_env(
+        &self,
+        input: Option<&str>,
+    ) -> Result<(&ChainKey, &Chain), ResolveError> {
+        match input.map(str::trim) {
+            Some(input) if !input.is_empty() => self.resolve(input),
+            _ => match std::env::var(RESOLVE_ENV_VAR) {
+                Ok(input) => self.resolve(&input),
+                Err(_) => Err(ResolveError::Missing),
+            },
+        }
+    }
+
+    /// Returns `true` if there is an EIP-155 chain with the given ID.
+    ///
+    /// See [`contains_key`](Self::contains_key).
+    #[inline]
+    pub fn contains(&self, id: u64) -> bool {
+        self.contains_key(&ChainKey::Eip155(id))
+    }
+
+    /// Returns `true` if there is a chain with the given key, either registered or builtin as
+    /// described in [`get_key`](Self::get_key).
+    #[inline]
+    pub fn contains_key(&self, key: &ChainKey) -> bool {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Returns the number of registered chains.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.chains.len()
+    }
+
+    /// Returns `true` if there are no registered chains.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.chains.is_empty()
+    }
+
+    /// Returns `true` if the given chains are the same network: either they have the same ID, or
+    /// they are both registered and share a name, e.g. a runtime-registered chain mirroring a
+    /// builtin one under another ID.
+    ///
+    /// Names are compared like in [`get_by_name`](Self::get_by_name).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{spec::{Chain, ChainRegistry}, NamedChain};
+    ///
+    /// let mut registry = ChainRegistry::new();
+    /// let mut mirror = Chain::new(NamedChain::Base);
+    /// mirror.name = "base-mirror".into();
+    /// registry.register(84530, mirror);
+    ///
+    /// assert!(registry.is_same_network(84530.into(), 8453.into()));
+    /// assert!(registry.is_same_network(1.into(), NamedChain::Mainnet.into()));
+    /// assert!(!registry.is_same_network(84530.into(), 1.into()));
+    /// ```
+    pub fn is_same_network(&self, a: crate::Chain, b: crate::Chain) -> bool {
+        if a.is_same_network(&b) {
+            return true;
+        }
+        let (Some(a), Some(b)) = (self.get(a.id()), self.get(b.id())) else { return false };
+        let a_names = [normalize_name(&a.name), normalize_name(&a.internal_id)];
+        [&b.name, &b.internal_id].into_iter().any(|name| a_names.contains(&normalize_name(name)))
+    }
+
+    /// Returns an iterator over the chains and their keys, in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&ChainKey, &Chain)> + '_ {
+        self.chains.iter()
+    }
+
+    /// Inserts chains without notifying subscribers.
+    fn extend(&mut self, chains: impl IntoIterator<Item = (ChainKey, Chain)>) {
+        for (key, chain) in chains {
+            self.insert(key, chain);
+        }
+    }
+
+    /// Inserts a chain and updates the name index, returning the chain it replaced, if any.
+    fn insert(&mut self, key: ChainKey, chain: Chain) -> Option<Chain> {
+        let old = self.remove(&key);
+        #[cfg(feature = "std")]
+        if let ChainKey::Eip155(id) = key {
+            self.unregistered.remove(&id);
+            self.custom_ids.insert(id);
+        }
+        for name in [&chain.name, &chain.internal_id] {
+            let keys = self.names.entry(normalize_name(name)).or_default();
+            if !keys.contains(&key) {
+                keys.push(key.clone());
+            }
+        }
+        self.chains.insert(key, chain);
+        old
+    }
+
+    /// Removes a chain and its names from the index.
+    fn remove(&mut self, key: &ChainKey) -> Option<Chain> {
+        let chain = self.chains.remove(key)?;
+        #[cfg(feature = "std")]
+        if let ChainKey::Eip155(id) = *key {
+            self.custom_ids.remove(id);
+        }
+        for name in [&chain.name, &chain.internal_id] {
+            let name = normalize_name(name);
+            if let Some(keys) = self.names.get_mut(&name) {
+                keys.retain(|other| other != key);
+                if keys.is_empty() {
+                    self.names.remove(&name);
+                }
+            }
+        }
+        Some(chain)
+    }
+
+    /// Registers an EIP-155 chain under the given ID, returning the chain it replaced, if any.
+    ///
+    /// See [`register_key`](Self::register_key).
+    #[inline]
+    pub fn register(&mut self, id: u64, chain: Chain) -> Option<Chain> {
+        self.register_key(ChainKey::Eip155(id), chain)
+    }
+
+    /// Registers a chain under the given key, returning the chain it replaced, if any.
+    ///
+    /// Notifies subscribers with [`RegistryEvent::Added`] or [`RegistryEvent::Updated`].
+    pub fn register_key(&mut self, key: ChainKey, chain: Chain) -> Option<Chain> {
+        let old = self.insert(key.clone(), chain);
+        let new = &self.chains[&key];
+        match &old {
+            Some(old) => self.subscribers.notify(&RegistryEvent::Updated { key: &key, old, new }),
+            None => self.subscribers.notify(&RegistryEvent::Added { key: &key, chain: new }),
+        }
+        old
+    }
+
+    /// Sets the Etherscan-compatible explorer of the EIP-155 chain with the given ID, returning
+    /// the chain it replaced, if any.
+    ///
+    /// If there is no chain with the ID, a [custom](Chain::custom) chain named after the ID is
+    /// registered, so that explorers can be configured for private chains (e.g. Orbit or
+    /// OP-stack appchains) without describing them further.
+    ///
+    /// Notifies subscribers like [`register_key`](Self::register_key). See also
+    /// [`etherscan_urls`](Self::etherscan_urls).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{spec::ChainRegistry, Chain};
+    ///
+    /// let mut registry = ChainRegistry::new();
+    /// registry.set_explorer(98765, "https://explorer.example.com/api", "https://explorer.example.com");
+    ///
+    /// assert_eq!(registry.get(98765).unwrap().name, "98765");
+    /// assert_eq!(
+    ///     registry.etherscan_urls(Chain::from_id(98765)),
+    ///     Some(("https://explorer.example.com/api", "https://explorer.example.com"))
+    /// );
+    /// ```
+    pub fn set_explorer(
+        &mut self,
+        id: u64,
+        api_url: impl Into<String>,
+        base_url: impl Into<String>,
+    ) -> Option<Chain> {
+        let mut chain = self.get(id).cloned().unwrap_or_else(|| Chain::custom(id.to_string()));
+        chain.etherscan_api_url = Some(api_url.into());
+        chain.etherscan_base_url = Some(base_url.into());
+        self.register(id, chain)
+    }
+
+    /// Returns the explorer API and base URLs of the given chain, preferring the ones registered
+    /// in this registry over the builtin [`Chain::etherscan_urls`](crate::Chain::etherscan_urls).
+    pub fn etherscan_urls(&self, chain: crate::Chain) -> Option<(&str, &str)> {
+        let registered = self.get(chain.id()).and_then(|chain| {
+            Some((chain.etherscan_api_url.as_deref()?, chain.etherscan_base_url.as_deref()?))
+        });
+        registered.or_else(|| chain.etherscan_urls())
+    }
+
+    /// Removes the EIP-155 chain with the given ID, returning it if it was registered.
+    ///
+    /// See [`unregister_key`](Self::unregister_key).
+    #[inline]
+    pub fn unregister(&mut self, id: u64) -> Option<Chain> {
+        self.unregister_key(&ChainKey::Eip155(id))
+    }
+
+    /// Removes the chain with the given key, returning it if it was registered.
+    ///
+    /// Notifies subscribers with [`RegistryEvent::Removed`].
+    pub fn unregister_key(&mut self, key: &ChainKey) -> Option<Chain> {
+        #[cfg(feature = "std")]
+        if let ChainKey::Eip155(id) = *key {
+            if NamedChain::is_known_id(id) {
+                self.unregistered.insert(id);
+            }
+        }
+        let chain = self.remove(key)?;
+        self.subscribers.notify(&RegistryEvent::Removed { key, chain: &chain });
+        Some(chain)
+    }
+
+    /// Registers a callback to be notified of every subsequent change made through
+    /// [`register_key`](Self::register_key) and [`unregister_key`](Self::unregister_key).
+    ///
+    /// Callbacks are not carried over when the registry is cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::spec::{Chain, ChainRegistry, RegistryEvent};
+    /// use std::sync::mpsc;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let mut registry = ChainRegistry::new();
+    /// registry.subscribe(move |event| {
+    ///     if let RegistryEvent::Added { key, .. } = event {
+    ///         tx.send(key.eip155_id()).unwrap();
+    ///     }
+    /// });
+    ///
+    /// registry.register(31338, Chain::custom("devnet"));
+    /// assert_eq!(rx.try_recv(), Ok(Some(31338)));
+    /// ```
+    pub fn subscribe(
+        &mut self,
+        callback: impl Fn(&RegistryEvent<'_>) + Send + Sync + 'static,
+    ) -> SubscriptionId {
+        self.subscribers.next_id += 1;
+        let id = SubscriptionId(self.subscribers.next_id);
+        self.subscribers.callbacks.push((id, Arc::new(callback)));
+        id
+    }
+
+    /// Registers a callback like [`subscribe`](Self::subscribe), but only notified of changes to
+    /// the chains matching `predicate`, e.g. all testnets.
+    ///
+    /// The callback is immediately notified with [`RegistryEvent::Added`] for every matching chain
+    /// already registered, in key order, and then of every subsequent change to a matching chain,
+    /// including chains added later by [`merge`](Self::merge). An update is notified if either the
+    /// old or the new chain matches, so the callback can tell when a chain leaves the selection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::spec::{Chain, ChainKey, ChainRegistry, RegistryEvent};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let testnets = Arc::new(Mutex::new(Vec::new()));
+    /// let mut registry = ChainRegistry::new();
+    /// let sink = testnets.clone();
+    /// registry.subscribe_matching(
+    ///     |_, chain| chain.is_testnet,
+    ///     move |event| {
+    ///         if let RegistryEvent::Added { key, .. } = event {
+    ///             sink.lock().unwrap().push((*key).clone());
+    ///         }
+    ///     },
+    /// );
+    /// assert!(testnets.lock().unwrap().contains(&ChainKey::Eip155(11155111)));
+    ///
+    /// let mut devnet = Chain::custom("devnet");
+    /// devnet.is_testnet = true;
+    /// registry.register(31338, devnet);
+    /// registry.register(31339, Chain::custom("other-mainnet"));
+    /// assert!(testnets.lock().unwrap().contains(&ChainKey::Eip155(31338)));
+    /// assert!(!testnets.lock().unwrap().contains(&ChainKey::Eip155(31339)));
+    /// ```
+    pub fn subscribe_matching(
+        &mut self,
+        predicate: impl Fn(&ChainKey, &Chain) -> bool + Send + Sync + 'static,
+        callback: impl Fn(&RegistryEvent<'_>) + Send + Sync + 'static,
+    ) -> SubscriptionId {
+        let mut existing: Vec<_> =
+            self.iter().filter(|(key, chain)| predicate(key, chain)).collect();
+        existing.sort_unstable_by_key(|(key, _)| *key);
+        for (key, chain) in existing {
+            callback(&RegistryEvent::Added { key, chain });
+        }
+
+        self.subscribe(move |event| {
+            let matches = match *event {
+                RegistryEvent::Added { key, chain } | RegistryEvent::Removed { key, chain } => {
+                    predicate(key, chain)
+                }
+                RegistryEvent::Updated { key, old, new } => {
+                    predicate(key, old) || predicate(key, new)
+                }
+            };
+            if matches {
+                callback(event);
+            }
+        })
+    }
+
+    /// Removes a callback registered with [`subscribe`](Self::subscribe), returning `true` if it
+    /// was found.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let len = self.subscribers.callbacks.len();
+        self.subscribers.callbacks.retain(|(other, _)| *other != id);
+        self.subscribers.callbacks.len() != len
+    }
+
+    /// Merges the chains of `other` into this registry, resolving chains registered under the same
+    /// key in both with different metadata according to `policy`.
+    ///
+    /// This registry is the base (e.g. the builtin chains), and `other` holds the imported ones
+    /// (e.g. from a chainlist JSON or user configuration). Chains only present in `other` are always
+    /// added. Subscribers are notified of every added or updated chain.
+    ///
+    /// If `policy` is [`MergePolicy::Error`] and there is a conflict, the registry is left
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::spec::{Chain, ChainRegistry, MergePolicy};
+    ///
+    /// let mut user = ChainRegistry::empty();
+    /// user.register(1, Chain::custom("my-mainnet"));
+    /// user.register(31338, Chain::custom("devnet"));
+    ///
+    /// let mut registry = ChainRegistry::new();
+    /// assert_eq!(registry.merge(user.clone(), MergePolicy::Error).unwrap_err().key.eip155_id(), Some(1));
+    ///
+    /// registry.merge(user.clone(), MergePolicy::PreferBuiltin).unwrap();
+    /// assert_eq!(registry.get(1).unwrap().name, "mainnet");
+    /// assert_eq!(registry.get(31338).unwrap().name, "devnet");
+    ///
+    /// registry.merge(user, MergePolicy::PreferUser).unwrap();
+    /// assert_eq!(registry.get(1).unwrap().name, "my-mainnet");
+    /// ```
+    pub fn merge(&mut self, other: Self, policy: MergePolicy) -> Result<(), MergeConflict> {
+        let mut chains: Vec<_> = other.chains.into_iter().collect();
+        chains.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        if policy == MergePolicy::Error {
+            for (key, chain) in &chains {
+                if self.chains.get(key).is_some_and(|existing| existing != chain) {
+                    return Err(MergeConflict { key: key.clone() });
+                }
+            }
+        }
+
+        for (key, chain) in chains {
+            match self.chains.get(&key) {
+                Some(existing) if *existing == chain => {}
+                Some(_) if policy == MergePolicy::PreferBuiltin => {}
+                _ => {
+                    self.register_key(key, chain);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the chains that differ from the builtin ones: those registered under a key that is
+    /// not a [`NamedChain`], and builtin chains replaced at runtime, e.g. by [`merge`](Self::merge).
+    pub fn custom_chains(&self) -> impl Iterator<Item = (&ChainKey, &Chain)> + '_ {
+        self.iter().filter(|(key, chain)| {
+            match key.eip155_id().and_then(|id| NamedChain::try_from(id).ok()) {
+                Some(named) => **chain != Chain::new(named),
+                None => true,
+            }
+        })
+    }
+
+    /// Saves the [custom chains](Self::custom_chains) to the given store.
+    pub fn save<S: RegistryStore>(&self, store: &mut S) -> Result<(), S::Error> {
+        let mut chains = Self::empty();
+        chains.extend(self.custom_chains().map(|(key, chain)| (key.clone(), chain.clone())));
+        store.save(&chains)
+    }
+
+    /// Returns the default chains, extended with the chains previously saved to the given store.
+    pub fn load<S: RegistryStore>(store: &mut S) -> Result<Self, S::Error> {
+        let mut registry = Self::new();
+        if let Some(saved) = store.load()? {
+            registry.extend(saved.chains);
+        }
+        Ok(registry)
+    }
+}
+
+/// The environment variable read by [`ChainRegistry::resolve_with_env`] when no chain is given.
+pub const RESOLVE_ENV_VAR: &str = "ALLOY_CHAIN";
+
+/// The error returned by [`ChainRegistry::resolve`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResolveError {
+    /// No chain was given.
+    Missing,
+    /// The input is a valid chain ID or key, but no chain is registered under it.
+    Unregistered(ChainKey),
+    /// The input is neither a known name or alias, nor a chain ID or key.
+    Unknown(String),
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => f.write_str("no chain given"),
+            Self::Unregistered(key) => write!(f, "no chain registered for {key}"),
+            Self::Unknown(input) => write!(
+                f,
+                "unknown chain `{input}`: expected a chain name, alias, decimal or hex chain ID, \
+                 or CAIP-2 chain ID"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ResolveError {}
+
+/// How [`ChainRegistry::merge`] resolves a chain registered under the same key in both
+/// registries, with different metadata.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MergePolicy {
+    /// Keep the chain of the registry being merged into.
+    #[default]
+    PreferBuiltin,
+    /// Replace it with the chain being merged in.
+    PreferUser,
+    /// Fail with a [`MergeConflict`].
+    Error,
+}
+
+/// The error returned by [`ChainRegistry::merge`] with [`MergePolicy::Error`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct MergeConflict {
+    /// The key of the first conflicting chain.
+    pub key: ChainKey,
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "conflicting metadata for chain {}", self.key)
+    }
+}
+
+impl core::error::Error for MergeConflict {}
+
+/// A [CAIP-2] chain ID, which identifies a chain across ecosystems as `<namespace>:<reference>`.
+///
+/// EIP-155 chains use the `eip155` namespace, with the decimal chain ID as reference.
+///
+/// [CAIP-2]: https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-2.md
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::spec::ChainKey;
+///
+/// let key: ChainKey = "eip155:8453".parse().unwrap();
+/// assert_eq!(key, ChainKey::Eip155(8453));
+/// assert_eq!(key.to_string(), "eip155:8453");
+///
+/// let key: ChainKey = "cosmos:cosmoshub-4".parse().unwrap();
+/// assert_eq!((key.namespace(), key.eip155_id()), ("cosmos", None));
+///
+/// assert!("eip155:base".parse::<ChainKey>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ChainKey {
+    /// An EIP-155 chain, in the `eip155` namespace.
+    Eip155(u64),
+    /// A chain in any other namespace.
+    Other {
+        /// The namespace (e.g. `solana` or `cosmos`).
+        namespace: String,
+        /// The chain's reference within the namespace.
+        reference: String,
+    },
+}
+
+impl ChainKey {
+    /// The namespace of EIP-155 chains.
+    pub const EIP155: &'static str = "eip155";
+
+    /// Creates a new key, validating the namespace and reference according to CAIP-2.
+    pub fn new(namespace: &str, reference: &str) -> Result<Self, ParseChainKeyError> {
+        let valid_namespace = (3..=8).contains(&namespace.len())
+            && namespace.bytes().all(|b| matches!(b, b'-' | b'a'..=b'z' | b'0'..=b'9'));
+        let valid_reference = (1..=32).contains(&reference.len())
+            && reference.bytes().all(|b| b == b'-' || b == b'_' || b.is_ascii_alphanumeric());
+        if !valid_namespace || !valid_reference {
+            return Err(ParseChainKeyError(()));
+        }
+
+        if namespace == Self::EIP155 {
+            return reference.parse().map(Self::Eip155).map_err(|_| ParseChainKeyError(()));
+        }
+        Ok(Self::Other { namespace: namespace.into(), reference: reference.into() })
+    }
+
+    /// Returns the key's namespace.
+    pub fn namespace(&self) -> &str {
+        match self {
+            Self::Eip155(_) => Self::EIP155,
+            Self::Other { namespace, .. } => namespace,
+        }
+    }
+
+    /// Returns the EIP-155 chain ID, if this is an `eip155` key.
+    #[inline]
+    pub const fn eip155_id(&self) -> Option<u64> {
+        match self {
+            Self::Eip155(id) => Some(*id),
+            Self::Other { .. } => None,
+        }
+    }
+}
+
+impl From<u64> for ChainKey {
+    #[inline]
+    fn from(id: u64) -> Self {
+        Self::Eip155(id)
+    }
+}
+
+impl From<NamedChain> for ChainKey {
+    #[inline]
+    fn from(chain: NamedChain) -> Self {
+        Self::Eip155(chain as u64)
+    }
+}
+
+impl core::str::FromStr for ChainKey {
+    type Err = ParseChainKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (namespace, reference) = s.split_once(':').ok_or(ParseChainKeyError(()))?;
+        Self::new(namespace, reference)
+    }
+}
+
+impl fmt::Display for ChainKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Eip155(id) => write!(f, "{}:{id}", Self::EIP155),
+            Self::Other { namespace, reference } => write!(f, "{namespace}:{reference}"),
+        }
+    }
+}
+
+/// The error returned when parsing an invalid [`ChainKey`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseChainKeyError(());
+
+impl fmt::Display for ParseChainKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid CAIP-2 chain ID")
+    }
+}
+
+impl core::error::Error for ParseChainKeyError {}
+
+/// Returns the specification of the builtin chain with the given ID, built on first use.
+#[cfg(feature = "std")]
+fn builtin(id: u64) -> Option<(&'static ChainKey, &'static Chain)> {
+    use std::sync::OnceLock;
+    use strum::EnumCount;
+
+    static CACHE: [OnceLock<(ChainKey, Chain)>; NamedChain::COUNT] =
+        [const { OnceLock::new() }; NamedChain::COUNT];
+
+    let named = NamedChain::try_from(id).ok()?;
+    let index = NamedChain::all_ids().binary_search(&id).ok()?;
+    let (key, chain) = CACHE[index].get_or_init(|| (ChainKey::Eip155(id), Chain::new(named)));
+    Some((key, chain))
+}
+
+/// Returns the builtin chain whose [`Chain::new`] specification has the given normalized name or
+/// internal ID, looked up in an index built on first use.
+#[cfg(feature = "std")]
+fn builtin_by_name(name: &str) -> Option<NamedChain> {
+    use std::sync::OnceLock;
+
+    static INDEX: OnceLock<HashMap<String, NamedChain>> = OnceLock::new();
+
+    let index = INDEX.get_or_init(|| {
+        NamedChain::iter()
+            .flat_map(|chain| {
+                [
+                    (normalize_name(&format!("{chain:?}")), chain),
+                    (normalize_name(chain.as_str()), chain),
+                ]
+            })
+            .collect()
+    });
+    index.get(name).copied()
+}
+
+/// Normalizes a chain name for lookups in [`Chains`].
+fn normalize_name(name: &str) -> String {
+    name.chars().map(|c| if c == '_' { '-' } else { c.to_ascii_lowercase() }).collect()
+}
+
+/// A change made to a [`ChainRegistry`], passed to the callbacks registered with
+/// [`ChainRegistry::subscribe`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum RegistryEvent<'a> {
+    /// A chain was registered under a new key.
+    Added {
+        /// The chain's key.
+        key: &'a ChainKey,
+        /// The registered chain.
+        chain: &'a Chain,
+    },
+    /// A chain was registered under an existing key, replacing the previous chain.
+    Updated {
+        /// The chain's key.
+        key: &'a ChainKey,
+        /// The replaced chain.
+        old: &'a Chain,
+        /// The registered chain.
+        new: &'a Chain,
+    },
+    /// A chain was removed.
+    Removed {
+        /// The chain's key.
+        key: &'a ChainKey,
+        /// The removed chain.
+        chain: &'a Chain,
+    },
+}
+
+/// The handle of a callback registered with [`ChainRegistry::subscribe`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+type Callback = Arc<dyn Fn(&RegistryEvent<'_>) + Send + Sync>;
+
+#[derive(Default)]
+struct Subscribers {
+    callbacks: Vec<(SubscriptionId, Callback)>,
+    next_id: u64,
+}
+
+impl Subscribers {
+    fn notify(&self, event: &RegistryEvent<'_>) {
+        for (_, callback) in &self.callbacks {
+            callback(event);
+        }
+    }
+}
+
+impl Clone for Subscribers {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for Subscribers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscribers").field("len", &self.callbacks.len()).finish()
+    }
+}
+
+/// The registered EIP-155 chain IDs that are not [`NamedChain`]s, which the
+/// [unknown chain observer](crate::Chain::set_unknown_chain_observer) skips while the registry is
+/// alive.
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+struct CustomIds(alloy_primitives::map::HashSet<u64>);
+
+#[cfg(feature = "std")]
+impl CustomIds {
+    fn insert(&mut self, id: u64) {
+        if NamedChain::try_from(id).is_err() && self.0.insert(id) {
+            crate::chain::unknown_chain_observer::register(id);
+        }
+    }
+
+    fn remove(&mut self, id: u64) {
+        if self.0.remove(&id) {
+            crate::chain::unknown_chain_observer::unregister(id);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clone for CustomIds {
+    fn clone(&self) -> Self {
+        for &id in &self.0 {
+            crate::chain::unknown_chain_observer::register(id);
+        }
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for CustomIds {
+    fn drop(&mut self) {
+        for &id in &self.0 {
+            crate::chain::unknown_chain_observer::unregister(id);
+        }
     }
 }
 
 /// Specification for a single chain.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
@@ -59,12 +1087,17 @@ pub struct Chain {
     pub is_testnet: bool,
     /// The chain's native currency symbol (e.g. `ETH`).
     pub native_currency_symbol: Option<String>,
+    /// The number of decimals of the chain's native currency.
+    pub native_currency_decimals: Option<u8>,
     /// The chain's base block explorer API URL (e.g. `https://api.etherscan.io/`).
     pub etherscan_api_url: Option<String>,
     /// The chain's base block explorer base URL (e.g. `https://etherscan.io/`).
     pub etherscan_base_url: Option<String>,
     /// The name of the environment variable that contains the Etherscan API key.
     pub etherscan_api_key_name: Option<String>,
+    /// The chain's RPC URLs. Only set for chains registered at runtime.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub rpc_urls: Vec<String>,
 }
 
 impl Chain {
@@ -85,21 +1118,217 @@ impl Chain {
             supports_shanghai: c.supports_shanghai(),
             is_testnet: c.is_testnet(),
             native_currency_symbol: c.native_currency_symbol().map(Into::into),
+            native_currency_decimals: c.native_currency_decimals(),
             etherscan_api_url: etherscan_api_url.map(Into::into),
             etherscan_base_url: etherscan_base_url.map(Into::into),
             etherscan_api_key_name: c.etherscan_api_key_name().map(Into::into),
+            rpc_urls: Vec::new(),
+        }
+    }
+
+    /// Constructs a new specification for a custom chain with the given name.
+    ///
+    /// The chain is assumed to support EIP-1559 and Shanghai; all other metadata is unset.
+    pub fn custom(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            internal_id: name.clone(),
+            name,
+            average_blocktime_hint: None,
+            is_legacy: false,
+            supports_shanghai: true,
+            is_testnet: false,
+            native_currency_symbol: None,
+            native_currency_decimals: None,
+            etherscan_api_url: None,
+            etherscan_base_url: None,
+            etherscan_api_key_name: None,
+            rpc_urls: Vec::new(),
         }
     }
+
+    /// Returns a builder for a custom EIP-155 chain with the given ID and name, which validates
+    /// the metadata before it is registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::spec::{Chain, ChainBuilderError, ChainRegistry};
+    ///
+    /// let (id, chain) = Chain::builder(31338, "devnet")
+    ///     .testnet(true)
+    ///     .native_currency("DEV", 18)
+    ///     .rpc_url("http://localhost:8545")
+    ///     .build()
+    ///     .unwrap();
+    /// ChainRegistry::new().register(id, chain);
+    ///
+    /// assert_eq!(Chain::builder(0, "zero").build(), Err(ChainBuilderError::ZeroId));
+    /// assert_eq!(
+    ///     Chain::builder(31338, "devnet").rpc_url("localhost:8545").build(),
+    ///     Err(ChainBuilderError::InvalidUrl("localhost:8545".into()))
+    /// );
+    /// ```
+    #[inline]
+    pub fn builder(id: u64, name: impl Into<String>) -> ChainBuilder {
+        ChainBuilder { id, chain: Self::custom(name) }
+    }
 }
 
-#[cfg(all(test, feature = "std", feature = "serde", feature = "schema"))]
+/// Builder for a custom [`Chain`], created with [`Chain::builder`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct ChainBuilder {
+    id: u64,
+    chain: Chain,
+}
+
+impl ChainBuilder {
+    /// The largest number of decimals accepted for a native currency.
+    pub const MAX_DECIMALS: u8 = 36;
+
+    /// Sets the chain's internal ID. Defaults to the chain's name.
+    pub fn internal_id(mut self, internal_id: impl Into<String>) -> Self {
+        self.chain.internal_id = internal_id.into();
+        self
+    }
+
+    /// Sets the chain's average block time hint, in milliseconds.
+    pub const fn average_blocktime_hint(mut self, ms: u64) -> Self {
+        self.chain.average_blocktime_hint = Some(ms);
+        self
+    }
+
+    /// Sets whether the chain is a legacy chain, which does not support EIP-1559. Defaults to
+    /// `false`.
+    pub const fn legacy(mut self, is_legacy: bool) -> Self {
+        self.chain.is_legacy = is_legacy;
+        self
+    }
+
+    /// Sets whether the chain supports the Shanghai hardfork. Defaults to `true`.
+    pub const fn supports_shanghai(mut self, supports_shanghai: bool) -> Self {
+        self.chain.supports_shanghai = supports_shanghai;
+        self
+    }
+
+    /// Sets whether the chain is a testnet. Defaults to `false`.
+    pub const fn testnet(mut self, is_testnet: bool) -> Self {
+        self.chain.is_testnet = is_testnet;
+        self
+    }
+
+    /// Sets the chain's native currency symbol and decimals.
+    pub fn native_currency(mut self, symbol: impl Into<String>, decimals: u8) -> Self {
+        self.chain.native_currency_symbol = Some(symbol.into());
+        self.chain.native_currency_decimals = Some(decimals);
+        self
+    }
+
+    /// Sets the chain's block explorer API and base URLs.
+    pub fn explorer(mut self, api_url: impl Into<String>, base_url: impl Into<String>) -> Self {
+        self.chain.etherscan_api_url = Some(api_url.into());
+        self.chain.etherscan_base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the name of the environment variable that contains the explorer API key.
+    pub fn etherscan_api_key_name(mut self, name: impl Into<String>) -> Self {
+        self.chain.etherscan_api_key_name = Some(name.into());
+        self
+    }
+
+    /// Adds an RPC URL.
+    pub fn rpc_url(mut self, url: impl Into<String>) -> Self {
+        self.chain.rpc_urls.push(url.into());
+        self
+    }
+
+    /// Validates the metadata and returns the chain ID and the chain, ready to be passed to
+    /// [`ChainRegistry::register`].
+    pub fn build(self) -> Result<(u64, Chain), ChainBuilderError> {
+        let Self { id, chain } = self;
+        if id == 0 {
+            return Err(ChainBuilderError::ZeroId);
+        }
+        if chain.name.trim().is_empty() {
+            return Err(ChainBuilderError::EmptyName);
+        }
+        if let Some(decimals) = chain.native_currency_decimals {
+            if decimals > Self::MAX_DECIMALS {
+                return Err(ChainBuilderError::InvalidDecimals(decimals));
+            }
+        }
+
+        let explorer_urls = [&chain.etherscan_api_url, &chain.etherscan_base_url];
+        for url in explorer_urls.into_iter().flatten() {
+            validate_url(url, &["http", "https"])?;
+        }
+        for url in &chain.rpc_urls {
+            validate_url(url, &["http", "https", "ws", "wss"])?;
+        }
+
+        Ok((id, chain))
+    }
+}
+
+/// Checks that `url` is an absolute URL with one of the given schemes and a host.
+fn validate_url(url: &str, schemes: &[&str]) -> Result<(), ChainBuilderError> {
+    let valid = url.split_once("://").is_some_and(|(scheme, rest)| {
+        let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        schemes.iter().any(|s| s.eq_ignore_ascii_case(scheme))
+            && !host.is_empty()
+            && !url.contains(char::is_whitespace)
+    });
+    if valid {
+        Ok(())
+    } else {
+        Err(ChainBuilderError::InvalidUrl(url.to_string()))
+    }
+}
+
+/// The error returned by [`ChainBuilder::build`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChainBuilderError {
+    /// The chain ID is `0`.
+    ZeroId,
+    /// The chain name is empty.
+    EmptyName,
+    /// The native currency has more than [`ChainBuilder::MAX_DECIMALS`] decimals.
+    InvalidDecimals(u8),
+    /// An explorer or RPC URL is not an absolute `http(s)` (or, for RPC URLs, `ws(s)`) URL.
+    InvalidUrl(String),
+}
+
+impl fmt::Display for ChainBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroId => f.write_str("chain ID must not be 0"),
+            Self::EmptyName => f.write_str("chain name must not be empty"),
+            Self::InvalidDecimals(decimals) => write!(
+                f,
+                "native currency decimals must be at most {}, got {decimals}",
+                ChainBuilder::MAX_DECIMALS
+            ),
+            Self::InvalidUrl(url) => write!(f, "invalid URL `{url}`"),
+        }
+    }
+}
+
+impl core::error::Error for ChainBuilderError {}
+
+#[cfg(all(test, feature = "std", feature = "serde", feature = "schema", not(feature = "slim")))]
 mod tests {
     use super::*;
-    use std::{fs, path::Path};
+    use crate::test_utils::ensure_file_contents;
+    use std::path::Path;
 
+    #[cfg(not(feature = "mainnets-only"))]
     const JSON_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/chains.json");
     const SCHEMA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/chains.schema.json");
 
+    #[cfg(not(feature = "mainnets-only"))]
     fn json_chains() -> String {
         serde_json::to_string_pretty(&Chains::new()).unwrap()
     }
@@ -109,41 +1338,118 @@ mod tests {
     }
 
     #[test]
-    #[cfg_attr(miri, ignore = "no fs")]
-    fn spec_up_to_date() {
-        ensure_file_contents(Path::new(JSON_PATH), &json_chains());
+    fn name_index() {
+        let mut registry = ChainRegistry::new();
+        registry.register(31338, Chain::custom("devnet"));
+        assert_eq!(registry.get_by_name("DEVNET").unwrap().0, &ChainKey::Eip155(31338));
+
+        registry.register(31338, Chain::custom("local"));
+        assert!(registry.get_by_name("devnet").is_none());
+        assert_eq!(registry.get_by_name("local").unwrap().0, &ChainKey::Eip155(31338));
+
+        registry.unregister(31338);
+        assert!(registry.get_by_name("local").is_none());
+        assert_eq!(registry.len(), NamedChain::iter().count());
     }
 
     #[test]
-    #[cfg_attr(miri, ignore = "no fs")]
-    fn schema_up_to_date() {
-        ensure_file_contents(Path::new(SCHEMA_PATH), &json_schema());
+    fn shared_names() {
+        let mut registry = ChainRegistry::new();
+        registry.register(31338, Chain::custom("x"));
+        registry.register(31339, Chain::custom("x"));
+        assert_eq!(registry.get_by_name("x").unwrap().0, &ChainKey::Eip155(31339));
+
+        registry.unregister(31339);
+        assert_eq!(registry.get_by_name("x").unwrap().0, &ChainKey::Eip155(31338));
+        registry.unregister(31338);
+        assert!(registry.get_by_name("x").is_none());
     }
 
-    /// Checks that the `file` has the specified `contents`. If that is not the
-    /// case, updates the file and then fails the test.
-    fn ensure_file_contents(file: &Path, contents: &str) {
-        if let Ok(old_contents) = fs::read_to_string(file) {
-            if normalize_newlines(&old_contents) == normalize_newlines(contents) {
-                // File is already up to date.
-                return;
-            }
-        }
+    #[test]
+    fn merge_into_empty() {
+        let mut user = ChainRegistry::empty();
+        user.register(1, Chain::custom("my-mainnet"));
 
-        eprintln!("\n\x1b[31;1merror\x1b[0m: {} was not up-to-date, updating\n", file.display());
-        if std::env::var("CI").is_ok() {
-            eprintln!(
-                "    NOTE: run `cargo test --all-features` locally and commit the updated files\n"
-            );
+        for policy in [MergePolicy::Error, MergePolicy::PreferBuiltin, MergePolicy::PreferUser] {
+            let mut registry = ChainRegistry::empty();
+            registry.merge(user.clone(), policy).unwrap();
+            assert_eq!(registry.len(), 1);
+            assert_eq!(registry.get(1).unwrap().name, "my-mainnet");
+            assert_eq!(registry.resolve("my-mainnet").unwrap().0, &ChainKey::Eip155(1));
+            // The builtin name no longer resolves to the replaced chain.
+            assert!(registry.get_by_name("mainnet").is_none());
         }
-        if let Some(parent) = file.parent() {
-            let _ = fs::create_dir_all(parent);
+    }
+
+    #[test]
+    fn save_overridden_builtin() {
+        #[derive(Default)]
+        struct MemoryStore(Option<Chains>);
+
+        impl RegistryStore for MemoryStore {
+            type Error = core::convert::Infallible;
+
+            fn load(&mut self) -> Result<Option<Chains>, Self::Error> {
+                Ok(self.0.clone())
+            }
+
+            fn save(&mut self, chains: &Chains) -> Result<(), Self::Error> {
+                self.0 = Some(chains.clone());
+                Ok(())
+            }
         }
-        fs::write(file, contents).unwrap();
-        panic!("some file was not up to date and has been updated, simply re-run the tests");
+
+        let mut mainnet = Chain::new(NamedChain::Mainnet);
+        mainnet.rpc_urls.push("http://localhost:8545".into());
+        let mut user = ChainRegistry::empty();
+        user.register(1, mainnet);
+        let mut registry = ChainRegistry::new();
+        registry.merge(user, MergePolicy::PreferUser).unwrap();
+        registry.register(31338, Chain::custom("devnet"));
+
+        let mut store = MemoryStore::default();
+        registry.save(&mut store).unwrap();
+        assert_eq!(store.0.as_ref().unwrap().len(), 2);
+
+        let loaded = ChainRegistry::load(&mut store).unwrap();
+        assert_eq!(loaded.get(1), registry.get(1));
+        assert!(loaded.get(1).unwrap().rpc_urls.iter().any(|url| url == "http://localhost:8545"));
+        assert_eq!(loaded.get(31338).unwrap().name, "devnet");
+        assert_eq!(loaded.get(8453), Some(&Chain::new(NamedChain::Base)));
+    }
+
+    #[test]
+    fn namespaced_serde_roundtrip() {
+        let mut registry = ChainRegistry::empty();
+        registry.register(1, Chain::new(NamedChain::Mainnet));
+        registry.register_key("cosmos:cosmoshub-4".parse().unwrap(), Chain::custom("cosmoshub"));
+
+        let json = serde_json::to_value(&registry).unwrap();
+        assert_eq!(json["namespacedChains"]["cosmos:cosmoshub-4"]["name"], "cosmoshub");
+
+        let registry: ChainRegistry = serde_json::from_value(json).unwrap();
+        assert_eq!(registry.get_by_name("cosmoshub").unwrap().0.namespace(), "cosmos");
+        assert_eq!(registry.get(1).unwrap().name, "mainnet");
+    }
+
+    // The dataset is generated without `mainnets-only`, which strips the testnet metadata.
+    #[test]
+    #[cfg(not(feature = "mainnets-only"))]
+    #[cfg_attr(miri, ignore = "no fs")]
+    fn spec_up_to_date() {
+        ensure_file_contents(Path::new(JSON_PATH), &json_chains());
+    }
+
+    #[test]
+    #[cfg(not(feature = "mainnets-only"))]
+    fn embedded_dataset_up_to_date() {
+        assert_eq!(crate::schema::dataset(), json_chains());
+        assert_eq!(crate::schema::json_schema(), json_schema());
     }
 
-    fn normalize_newlines(s: &str) -> String {
-        s.replace("\r\n", "\n")
+    #[test]
+    #[cfg_attr(miri, ignore = "no fs")]
+    fn schema_up_to_date() {
+        ensure_file_contents(Path::new(SCHEMA_PATH), &json_schema());
     }
 }
@@ -0,0 +1,115 @@
+//! Chains known at compile time.
+
+use crate::{Chain, NamedChain};
+use core::fmt;
+
+/// A zero-sized marker for the chain with ID `ID`, known at compile time.
+///
+/// This lets APIs encode the chain they are bound to in the type system, e.g. a client that only
+/// talks to Base, while still using [`Chain`]'s metadata at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::{Chain, NamedChain, TypedChain};
+///
+/// type Base = TypedChain<{ NamedChain::Base as u64 }>;
+///
+/// struct Client<C> {
+///     chain: C,
+/// }
+///
+/// let client = Client { chain: Base::new() };
+/// assert_eq!(client.chain.named(), Some(NamedChain::Base));
+/// assert_eq!(Chain::from(client.chain), Chain::base_mainnet());
+///
+/// assert!(Base::try_from(Chain::base_mainnet()).is_ok());
+/// assert!(Base::try_from(Chain::mainnet()).is_err());
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypedChain<const ID: u64>;
+
+impl<const ID: u64> TypedChain<ID> {
+    /// The chain's ID.
+    pub const ID: u64 = ID;
+
+    /// Creates a new marker.
+    #[inline]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// The ID of the chain.
+    #[inline]
+    pub const fn id(self) -> u64 {
+        ID
+    }
+
+    /// Returns the [`Chain`] this marker stands for.
+    #[inline]
+    pub fn chain(self) -> Chain {
+        Chain::from_id_unobserved(ID)
+    }
+
+    /// Attempts to convert the chain into a named chain.
+    #[inline]
+    pub fn named(self) -> Option<NamedChain> {
+        NamedChain::try_from(ID).ok()
+    }
+}
+
+impl<const ID: u64> fmt::Debug for TypedChain<ID> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TypedChain::")?;
+        self.chain().kind().fmt(f)
+    }
+}
+
+impl<const ID: u64> fmt::Display for TypedChain<ID> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chain().fmt(f)
+    }
+}
+
+impl<const ID: u64> From<TypedChain<ID>> for Chain {
+    #[inline]
+    fn from(chain: TypedChain<ID>) -> Self {
+        chain.chain()
+    }
+}
+
+impl<const ID: u64> From<TypedChain<ID>> for u64 {
+    #[inline]
+    fn from(_: TypedChain<ID>) -> Self {
+        ID
+    }
+}
+
+impl<const ID: u64> TryFrom<Chain> for TypedChain<ID> {
+    /// The chain is returned if its ID does not match.
+    type Error = Chain;
+
+    #[inline]
+    fn try_from(chain: Chain) -> Result<Self, Self::Error> {
+        if chain.id() == ID {
+            Ok(Self)
+        } else {
+            Err(chain)
+        }
+    }
+}
+
+impl<const ID: u64> PartialEq<Chain> for TypedChain<ID> {
+    #[inline]
+    fn eq(&self, other: &Chain) -> bool {
+        other.id() == ID
+    }
+}
+
+impl<const ID: u64> PartialEq<TypedChain<ID>> for Chain {
+    #[inline]
+    fn eq(&self, _: &TypedChain<ID>) -> bool {
+        self.id() == ID
+    }
+}
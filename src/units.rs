@@ -0,0 +1,152 @@
+//! Conversions between the units of a chain's native currency, for printing gas prices and fees
+//! consistently.
+//!
+//! The decimals and symbol of the native currency come from
+//! [`NamedChain::native_currency`](crate::NamedChain::native_currency). Chains without currency
+//! metadata are assumed to use 18 decimals, and their amounts are printed without a symbol.
+
+use crate::Chain;
+use alloc::string::String;
+use alloy_primitives::U256;
+use core::fmt::Write;
+
+/// A unit of a chain's native currency.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChainGasUnit {
+    /// The currency's base unit, e.g. wei.
+    Wei,
+    /// 10<sup>9</sup> base units, e.g. gwei.
+    Gwei,
+    /// One whole native token, e.g. ether.
+    Native,
+}
+
+impl ChainGasUnit {
+    /// Returns the number of decimals of the unit on the given chain, i.e. the power of ten of base
+    /// units in one unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_chains::{units::ChainGasUnit, Chain};
+    ///
+    /// assert_eq!(ChainGasUnit::Gwei.decimals(Chain::mainnet()), 9);
+    /// assert_eq!(ChainGasUnit::Native.decimals(Chain::mainnet()), 18);
+    /// ```
+    pub const fn decimals(self, chain: Chain) -> u8 {
+        match self {
+            Self::Wei => 0,
+            Self::Gwei => 9,
+            Self::Native => native_decimals(chain),
+        }
+    }
+
+    /// Returns the symbol of the unit on the given chain, if known.
+    pub const fn symbol(self, chain: Chain) -> Option<&'static str> {
+        match self {
+            Self::Wei => Some("wei"),
+            Self::Gwei => Some("gwei"),
+            Self::Native => match chain.named() {
+                Some(named) => named.native_currency_symbol(),
+                None => None,
+            },
+        }
+    }
+}
+
+/// Converts an amount in `unit` to base units, returning `None` on overflow.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::{units::{to_wei, ChainGasUnit}, Chain};
+/// use alloy_primitives::U256;
+///
+/// let wei = to_wei(Chain::mainnet(), U256::from(2), ChainGasUnit::Gwei);
+/// assert_eq!(wei, Some(U256::from(2_000_000_000u64)));
+/// ```
+pub fn to_wei(chain: Chain, amount: U256, unit: ChainGasUnit) -> Option<U256> {
+    amount.checked_mul(pow10(unit.decimals(chain)))
+}
+
+/// Formats an amount of base units in `unit`, followed by the unit's symbol if known.
+///
+/// The amount is printed exactly, without trailing zeros in the fractional part.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::{units::{format_units, ChainGasUnit}, Chain};
+/// use alloy_primitives::U256;
+///
+/// let wei = U256::from(1_500_000_000_000_000_000u128);
+/// # #[cfg(not(feature = "slim"))]
+/// assert_eq!(format_units(Chain::mainnet(), wei, ChainGasUnit::Native), "1.5 ETH");
+/// assert_eq!(format_units(Chain::from_id(1234567), wei, ChainGasUnit::Native), "1.5");
+/// ```
+pub fn format_units(chain: Chain, wei: U256, unit: ChainGasUnit) -> String {
+    let (integer, fraction) = wei.div_rem(pow10(unit.decimals(chain)));
+    let mut out = String::new();
+    let _ = write!(out, "{integer}");
+    if !fraction.is_zero() {
+        let fraction = format!("{fraction:0>width$}", width = unit.decimals(chain) as usize);
+        out.push('.');
+        out.push_str(fraction.trim_end_matches('0'));
+    }
+    if let Some(symbol) = unit.symbol(chain) {
+        out.push(' ');
+        out.push_str(symbol);
+    }
+    out
+}
+
+/// Formats a gas price given in base units, e.g. `"1.5 gwei"`.
+///
+/// Gas prices are printed in [gwei](ChainGasUnit::Gwei), unless the chain's native currency has
+/// fewer than 9 decimals, in which case they are printed in base units.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::{units::format_gas_price, Chain};
+///
+/// assert_eq!(format_gas_price(Chain::mainnet(), 1_500_000_000), "1.5 gwei");
+/// assert_eq!(format_gas_price(Chain::base_mainnet(), 5_000_000), "0.005 gwei");
+/// ```
+pub fn format_gas_price(chain: Chain, wei: u128) -> String {
+    let unit = if native_decimals(chain) < 9 { ChainGasUnit::Wei } else { ChainGasUnit::Gwei };
+    format_units(chain, U256::from(wei), unit)
+}
+
+const fn native_decimals(chain: Chain) -> u8 {
+    match chain.named() {
+        Some(named) => match named.native_currency_decimals() {
+            Some(decimals) => decimals,
+            None => 18,
+        },
+        None => 18,
+    }
+}
+
+fn pow10(exp: u8) -> U256 {
+    U256::from(10).pow(U256::from(exp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format() {
+        let chain = Chain::from_id(1234567);
+        assert_eq!(format_units(chain, U256::ZERO, ChainGasUnit::Gwei), "0 gwei");
+        assert_eq!(
+            format_units(chain, U256::from(1), ChainGasUnit::Native),
+            "0.000000000000000001"
+        );
+        assert_eq!(format_units(chain, U256::from(42), ChainGasUnit::Wei), "42 wei");
+        assert_eq!(format_gas_price(chain, 30_000_000_000), "30 gwei");
+        assert_eq!(to_wei(chain, U256::MAX, ChainGasUnit::Gwei), None);
+    }
+}
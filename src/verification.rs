@@ -0,0 +1,161 @@
+//! Contract verification endpoints.
+
+use crate::{Chain, ExplorerKind};
+
+/// The Sourcify server API URL. Sourcify is chain-agnostic.
+const SOURCIFY_API_URL: &str = "https://sourcify.dev/server";
+
+/// A contract verification service.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum VerifierType {
+    /// An Etherscan, or Etherscan white-label, explorer.
+    Etherscan,
+    /// A Blockscout explorer.
+    Blockscout,
+    /// A Routescan explorer.
+    Routescan,
+    /// The [Sourcify](https://sourcify.dev) service.
+    Sourcify,
+    /// Any other explorer with an Etherscan-like verification API.
+    Custom,
+}
+
+impl From<ExplorerKind> for VerifierType {
+    #[inline]
+    fn from(kind: ExplorerKind) -> Self {
+        match kind {
+            ExplorerKind::Etherscan => Self::Etherscan,
+            ExplorerKind::Blockscout => Self::Blockscout,
+            ExplorerKind::Routescan => Self::Routescan,
+            ExplorerKind::OkLink | ExplorerKind::Custom => Self::Custom,
+        }
+    }
+}
+
+/// The format in which a verifier expects the contract sources.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SourceFormat {
+    /// The solc [standard JSON input](https://docs.soliditylang.org/en/latest/using-the-compiler.html#compiler-input-and-output-json-description).
+    StandardJsonInput,
+    /// A single flattened source file.
+    Flattened,
+}
+
+/// Everything needed to submit a verification request for a chain to a verifier.
+///
+/// Built with [`VerificationTarget::builder`].
+///
+/// # Examples
+///
+/// ```
+/// use alloy_chains::{Chain, SourceFormat, VerificationTarget, VerifierType};
+/// # #[cfg(not(feature = "slim"))]
+/// # {
+/// let target = VerificationTarget::builder(Chain::mainnet()).build().unwrap();
+/// assert_eq!(target.verifier, VerifierType::Etherscan);
+/// assert_eq!(target.api_url, "https://api.etherscan.io/api");
+/// assert_eq!(target.api_key_env, Some("ETHERSCAN_API_KEY"));
+/// assert_eq!(target.source_format, SourceFormat::StandardJsonInput);
+///
+/// let target = VerificationTarget::builder(Chain::base_mainnet())
+///     .verifier(VerifierType::Blockscout)
+///     .build()
+///     .unwrap();
+/// assert_eq!(target.api_url, "https://base.blockscout.com/api");
+/// assert_eq!(target.api_key_env, None);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct VerificationTarget {
+    /// The chain the contract is deployed on.
+    pub chain: Chain,
+    /// The verifier.
+    pub verifier: VerifierType,
+    /// The verifier's API URL.
+    pub api_url: &'static str,
+    /// The name of the environment variable holding the API key, if the verifier requires one.
+    pub api_key_env: Option<&'static str>,
+    /// The format in which the verifier expects the contract sources.
+    pub source_format: SourceFormat,
+}
+
+impl VerificationTarget {
+    /// Returns a builder for the verification target of the given chain.
+    #[inline]
+    pub const fn builder(chain: Chain) -> VerificationTargetBuilder {
+        VerificationTargetBuilder { chain, verifier: None }
+    }
+}
+
+/// Builder for [`VerificationTarget`].
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct VerificationTargetBuilder {
+    chain: Chain,
+    verifier: Option<VerifierType>,
+}
+
+impl VerificationTargetBuilder {
+    /// Sets the verifier. Defaults to the family of the chain's primary explorer.
+    #[inline]
+    pub const fn verifier(mut self, verifier: VerifierType) -> Self {
+        self.verifier = Some(verifier);
+        self
+    }
+
+    /// Builds the verification target.
+    ///
+    /// Returns `None` if the chain has no known instance of the requested verifier.
+    pub fn build(self) -> Option<VerificationTarget> {
+        let Self { chain, verifier } = self;
+        let explorers = chain.explorers();
+        let verifier = match verifier {
+            Some(verifier) => verifier,
+            None => explorers.first()?.kind.into(),
+        };
+
+        let api_url = match verifier {
+            VerifierType::Sourcify => SOURCIFY_API_URL,
+            _ => explorers.iter().find(|e| VerifierType::from(e.kind) == verifier)?.api_url,
+        };
+        let api_key_env = match verifier {
+            VerifierType::Etherscan | VerifierType::Custom => chain.etherscan_api_key_name(),
+            VerifierType::Blockscout | VerifierType::Routescan | VerifierType::Sourcify => None,
+        };
+        let source_format = match verifier {
+            VerifierType::Custom => SourceFormat::Flattened,
+            _ => SourceFormat::StandardJsonInput,
+        };
+
+        Some(VerificationTarget { chain, verifier, api_url, api_key_env, source_format })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NamedChain;
+
+    #[test]
+    fn sourcify_is_chain_agnostic() {
+        let target = VerificationTarget::builder(Chain::from_id(123456789))
+            .verifier(VerifierType::Sourcify)
+            .build()
+            .unwrap();
+        assert_eq!(target.api_url, SOURCIFY_API_URL);
+        assert_eq!(target.api_key_env, None);
+    }
+
+    #[test]
+    fn unknown_verifier_instance() {
+        assert_eq!(VerificationTarget::builder(Chain::from_id(123456789)).build(), None);
+        assert_eq!(
+            VerificationTarget::builder(NamedChain::Mainnet.into())
+                .verifier(VerifierType::Routescan)
+                .build(),
+            None
+        );
+    }
+}